@@ -24,6 +24,12 @@ fn add_benchmark(c: &mut Criterion) {
             b.iter(|| bench_min(&arr_a))
         });
 
+        let arr_a = create_primitive_array::<f64>(size, 0.0);
+
+        c.bench_function(&format!("sum 2^{log2_size} f64"), |b| {
+            b.iter(|| bench_sum(&arr_a))
+        });
+
         let arr_a = create_primitive_array::<i32>(size, 0.0);
 
         c.bench_function(&format!("sum 2^{log2_size} i32"), |b| {
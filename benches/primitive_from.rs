@@ -0,0 +1,24 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::PrimitiveArray;
+
+fn add_benchmark(c: &mut Criterion) {
+    let size = 10_000_000i32;
+    let values = (0..size).collect::<Vec<_>>();
+    let options = values.iter().copied().map(Some).collect::<Vec<_>>();
+
+    c.bench_function("from_slice i32 10M", |b| {
+        b.iter(|| PrimitiveArray::<i32>::from_slice(&values))
+    });
+
+    c.bench_function("from_values i32 10M", |b| {
+        b.iter(|| PrimitiveArray::<i32>::from_values(values.iter().copied()))
+    });
+
+    c.bench_function("from options i32 10M", |b| {
+        b.iter(|| PrimitiveArray::<i32>::from(&options))
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
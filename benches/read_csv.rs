@@ -0,0 +1,70 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use std::io::Cursor;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::error::Result;
+use arrow2::io::csv::{read, write};
+use arrow2::util::bench_util::*;
+
+fn write_csv(size: usize) -> Vec<u8> {
+    let primitive = create_primitive_array::<i32>(size, 0.1).boxed();
+    let string = create_string_array::<i32>(size, 100, 0.1, 42).boxed();
+    let float = create_primitive_array::<f64>(size, 0.1).boxed();
+    let chunk = Chunk::new(vec![primitive, string, float]);
+
+    let mut data = vec![];
+    let options = write::SerializeOptions::default();
+    write::write_header(&mut data, &["a", "b", "c"], &options).unwrap();
+    write::write_chunk(&mut data, &chunk, &options).unwrap();
+    data
+}
+
+fn read_serial(data: &[u8], size: usize) -> Result<Chunk<Box<dyn Array>>> {
+    let mut reader = read::ReaderBuilder::new().from_reader(Cursor::new(data));
+    let (fields, _) = read::infer_schema(&mut reader, Some(100), true, &read::infer)?;
+
+    let mut rows = vec![read::ByteRecord::default(); size];
+    let rows_read = read::read_rows(&mut reader, 0, &mut rows)?;
+    read::deserialize_batch(
+        &rows[..rows_read],
+        &fields,
+        None,
+        0,
+        read::deserialize_column,
+    )
+}
+
+fn read_parallel(data: &[u8]) -> Result<Chunk<Box<dyn Array>>> {
+    let mut reader = read::ReaderBuilder::new().from_reader(Cursor::new(data));
+    let (fields, _) = read::infer_schema(&mut reader, Some(100), true, &read::infer)?;
+    let start = reader.position().byte() as usize;
+
+    read::read_parallel(
+        &data[start..],
+        &fields,
+        None,
+        std::thread::available_parallelism().map_or(1, |x| x.get()),
+        b'"',
+        read::ReaderBuilder::new,
+    )
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    (10..=18).step_by(4).for_each(|log2_size| {
+        let size = 2usize.pow(log2_size);
+        let data = write_csv(size);
+
+        c.bench_function(&format!("csv read serial 2^{log2_size}"), |b| {
+            b.iter(|| read_serial(&data, size))
+        });
+
+        c.bench_function(&format!("csv read parallel 2^{log2_size}"), |b| {
+            b.iter(|| read_parallel(&data))
+        });
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
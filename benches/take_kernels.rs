@@ -20,6 +20,10 @@ fn create_random_index(size: usize, null_density: f32) -> PrimitiveArray<i32> {
         .collect::<PrimitiveArray<i32>>()
 }
 
+fn create_sequential_index(size: usize) -> PrimitiveArray<i32> {
+    PrimitiveArray::from_vec((0..size as i32).collect())
+}
+
 fn bench_take(values: &dyn Array, indices: &PrimitiveArray<i32>) {
     criterion::black_box(take::take(values, indices).unwrap());
 }
@@ -36,6 +40,11 @@ fn add_benchmark(c: &mut Criterion) {
             b.iter(|| bench_take(&values, &indices))
         });
 
+        let sequential_indices = create_sequential_index(size);
+        c.bench_function(&format!("take i32 sequential 2^{log2_size}"), |b| {
+            b.iter(|| bench_take(&values, &sequential_indices))
+        });
+
         c.bench_function(&format!("take i32 nulls 2^{log2_size}"), |b| {
             b.iter(|| bench_take(&values, &indices_nulls))
         });
@@ -73,6 +82,18 @@ fn add_benchmark(c: &mut Criterion) {
         });
     });
 
+    let size = 1_000_000;
+    let values = create_string_array::<i32>(size, 4, 0.0, 42);
+    let indices = create_random_index(size, 0.0);
+    c.bench_function("take str random 1M", |b| {
+        b.iter(|| bench_take(&values, &indices))
+    });
+
+    let indices = create_sequential_index(size);
+    c.bench_function("take str sequential 1M", |b| {
+        b.iter(|| bench_take(&values, &indices))
+    });
+
     let values = create_string_array::<i32>(512, 4, 0.0, 42);
     let indices = create_random_index(512, 0.5);
     c.bench_function("take str null indices 512", |b| {
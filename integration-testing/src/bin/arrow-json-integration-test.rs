@@ -89,7 +89,7 @@ fn arrow_to_json(arrow_name: &str, json_name: &str, verbose: bool) -> Result<()>
         .map(|f| &f.name)
         .collect::<Vec<_>>();
 
-    let schema = json_write::serialize_schema(&metadata.schema, &metadata.ipc_schema.fields);
+    let schema = json_write::serialize_schema(&metadata.schema, &metadata.ipc_schema.fields)?;
 
     let batches = reader
         .map(|batch| Ok(json_write::serialize_chunk(&batch?, &names)))
@@ -106,7 +106,7 @@ fn new_stream(
 ) -> BoxStream<'static, FlightData> {
     let options = write::WriteOptions { compression: None };
 
-    let mut schema = flight::serialize_schema(schema, Some(&fields));
+    let mut schema = flight::serialize_schema(schema, Some(&fields)).unwrap();
     schema.flight_descriptor = Some(descriptor);
 
     // iterator of [dictionaries0, chunk0, dictionaries1, chunk1, ...]
@@ -109,7 +109,8 @@ impl FlightService for Service {
 
         let options = ipc::write::WriteOptions { compression: None };
 
-        let schema = serialize_schema(&flight.schema, Some(&flight.ipc_schema.fields));
+        let schema = serialize_schema(&flight.schema, Some(&flight.ipc_schema.fields))
+            .map_err(|e| Status::internal(e.to_string()))?;
 
         let batches = flight
             .chunks
@@ -380,6 +380,9 @@ impl<O: Offset> BinaryArray<O> {
     /// # Safety
     /// The iterator must be [`TrustedLen`](https://doc.rust-lang.org/std/iter/trait.TrustedLen.html).
     /// I.e. that `size_hint().1` correctly reports its length.
+    /// # Panics
+    /// This function panics iff the total length of the values does not fit in `O`.
+    /// Use `i64` offsets (`LargeBinary`) to store more than `i32::MAX` bytes.
     #[inline]
     pub unsafe fn try_from_trusted_len_iter_unchecked<E, I, P>(iterator: I) -> Result<Self, E>
     where
@@ -390,6 +393,9 @@ impl<O: Offset> BinaryArray<O> {
     }
 
     /// Creates a [`BinaryArray`] from an fallible iterator of trusted length.
+    /// # Panics
+    /// This function panics iff the total length of the values does not fit in `O`.
+    /// Use `i64` offsets (`LargeBinary`) to store more than `i32::MAX` bytes.
     #[inline]
     pub fn try_from_trusted_len_iter<E, I, P>(iter: I) -> Result<Self, E>
     where
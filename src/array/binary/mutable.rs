@@ -130,7 +130,14 @@ impl<O: Offset> MutableBinaryArray<O> {
             .map(|_| value)
     }
 
-    fn try_from_iter<P: AsRef<[u8]>, I: IntoIterator<Item = Option<P>>>(iter: I) -> Result<Self> {
+    /// Creates a new [`MutableBinaryArray`] from an iterator.
+    /// # Error
+    /// This operation errors with [`Error::Overflow`] iff the total length in bytes on the iterator
+    /// exceeds `O`'s maximum value (`i32::MAX` or `i64::MAX` respectively).
+    /// Use `i64` offsets (`LargeBinary`) to store more than `i32::MAX` bytes.
+    pub fn try_from_iter<P: AsRef<[u8]>, I: IntoIterator<Item = Option<P>>>(
+        iter: I,
+    ) -> Result<Self> {
         let iterator = iter.into_iter();
         let (lower, _) = iterator.size_hint();
         let mut primitive = Self::with_capacity(lower);
@@ -289,6 +296,9 @@ impl<O: Offset> MutableBinaryArray<O> {
     /// # Safety
     /// The iterator must be [`TrustedLen`](https://doc.rust-lang.org/std/iter/trait.TrustedLen.html).
     /// I.e. that `size_hint().1` correctly reports its length.
+    /// # Panics
+    /// This function panics iff the total length of the values does not fit in `O`.
+    /// Use `i64` offsets (`LargeBinary`) to store more than `i32::MAX` bytes.
     #[inline]
     pub unsafe fn try_from_trusted_len_iter_unchecked<E, I, P>(
         iterator: I,
@@ -310,6 +320,9 @@ impl<O: Offset> MutableBinaryArray<O> {
     }
 
     /// Creates a [`MutableBinaryArray`] from an falible iterator of trusted length.
+    /// # Panics
+    /// This function panics iff the total length of the values does not fit in `O`.
+    /// Use `i64` offsets (`LargeBinary`) to store more than `i32::MAX` bytes.
     #[inline]
     pub fn try_from_trusted_len_iter<E, I, P>(iterator: I) -> std::result::Result<Self, E>
     where
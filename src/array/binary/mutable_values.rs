@@ -334,8 +334,9 @@ impl<O: Offset, T: AsRef<[u8]>> TryPush<T> for MutableBinaryValuesArray<O> {
     #[inline]
     fn try_push(&mut self, value: T) -> Result<()> {
         let bytes = value.as_ref();
+        self.offsets.try_push_usize(bytes.len())?;
         self.values.extend_from_slice(bytes);
-        self.offsets.try_push_usize(bytes.len())
+        Ok(())
     }
 }
 
@@ -218,6 +218,29 @@ impl MutableBooleanArray {
     pub fn values(&self) -> &MutableBitmap {
         &self.values
     }
+
+    /// Returns the value at position `index`, ignoring the array's validity.
+    /// # Panic
+    /// Panics iff `index >= self.len()`.
+    #[inline]
+    pub fn value(&self, index: usize) -> bool {
+        assert!(index < self.len());
+        self.values.get(index)
+    }
+
+    /// Returns the element at position `index`, or `None` if it is null.
+    /// # Panic
+    /// Panics iff `index >= self.len()`.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<bool> {
+        assert!(index < self.len());
+        let is_valid = self
+            .validity
+            .as_ref()
+            .map(|validity| validity.get(index))
+            .unwrap_or(true);
+        is_valid.then(|| self.value(index))
+    }
 }
 
 /// Setters
@@ -226,7 +249,7 @@ impl MutableBooleanArray {
     /// Note that if it is the first time a null appears in this array,
     /// this initializes the validity bitmap (`O(N)`).
     /// # Panic
-    /// Panics iff index is larger than `self.len()`.
+    /// Panics iff `index >= self.len()`.
     pub fn set(&mut self, index: usize, value: Option<bool>) {
         self.values.set(index, value.unwrap_or_default());
 
@@ -258,11 +258,17 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         Ok(unsafe { DictionaryValuesIterTyped::new(keys, values) })
     }
 
-    /// Returns an iterator over the the optional values of  [`Option<V::IterValue>`].
+    /// Returns an iterator over the the optional values of  [`Option<V::IterValue>`],
+    /// resolving each key through `values` without allocating.
+    /// Items are `None` where the key is null.
+    ///
+    /// # Errors
+    ///
+    /// This function errors if `values` is not of type `V`.
     ///
     /// # Panics
     ///
-    /// This function panics if the `values` array
+    /// This function panics if the `values` array has nulls.
     pub fn iter_typed<V: DictValue>(
         &self,
     ) -> Result<ZipValidity<V::IterValue<'_>, DictionaryValuesIterTyped<K, V>, BitmapIter>, Error>
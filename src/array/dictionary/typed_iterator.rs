@@ -1,7 +1,7 @@
 use crate::array::{Array, PrimitiveArray, Utf8Array};
 use crate::error::{Error, Result};
 use crate::trusted_len::TrustedLen;
-use crate::types::Offset;
+use crate::types::{NativeType, Offset};
 
 use super::DictionaryKey;
 
@@ -48,6 +48,34 @@ impl<O: Offset> DictValue for Utf8Array<O> {
     }
 }
 
+impl<T: NativeType> DictValue for PrimitiveArray<T> {
+    type IterValue<'a> = T;
+
+    unsafe fn get_unchecked(&self, item: usize) -> Self::IterValue<'_> {
+        self.value_unchecked(item)
+    }
+
+    fn downcast_values(array: &dyn Array) -> Result<&Self>
+    where
+        Self: Sized,
+    {
+        array
+            .as_any()
+            .downcast_ref::<Self>()
+            .ok_or(Error::InvalidArgumentError(
+                "could not convert array to dictionary value".into(),
+            ))
+            .map(|arr| {
+                assert_eq!(
+                    arr.null_count(),
+                    0,
+                    "null values in values not supported in iteration"
+                );
+                arr
+            })
+    }
+}
+
 /// Iterator of values of an `ListArray`.
 pub struct DictionaryValuesIterTyped<'a, K: DictionaryKey, V: DictValue> {
     keys: &'a PrimitiveArray<K>,
@@ -0,0 +1,90 @@
+use std::any::type_name;
+
+use crate::{
+    array::*,
+    error::{Error, Result},
+    offset::Offset,
+    types::NativeType,
+};
+
+fn downcast<A: Array>(array: &dyn Array) -> Result<&A> {
+    array.as_any().downcast_ref::<A>().ok_or_else(|| {
+        Error::InvalidArgumentError(format!(
+            "An array of data type {:?} cannot be downcast to {}",
+            array.data_type(),
+            type_name::<A>()
+        ))
+    })
+}
+
+macro_rules! downcast_fns {
+    ($as:ident, $try_as:ident, $ty:ty, $name:literal $(, $generic:ident: $bound:path)?) => {
+        #[doc = concat!("Downcasts this array to a [`", $name, "`], returning `None` if it is not one.")]
+        #[inline]
+        pub fn $as$(<$generic: $bound>)?(&self) -> Option<&$ty> {
+            self.as_any().downcast_ref::<$ty>()
+        }
+
+        #[doc = concat!("Downcasts this array to a [`", $name, "`].")]
+        /// # Errors
+        #[doc = concat!("This function errors iff this array is not a [`", $name, "`].")]
+        #[inline]
+        pub fn $try_as$(<$generic: $bound>)?(&self) -> Result<&$ty> {
+            downcast(self)
+        }
+    };
+}
+
+/// Helpers to downcast a `dyn Array` to a concrete array, as an alternative to
+/// `array.as_any().downcast_ref::<A>()`.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+///
+/// let array: Box<dyn Array> = Int32Array::from_slice([1, 2]).boxed();
+///
+/// // the optional form
+/// assert_eq!(array.as_primitive::<i32>().unwrap().value(1), 2);
+/// assert!(array.as_primitive::<i64>().is_none());
+/// assert!(array.as_utf8::<i32>().is_none());
+///
+/// // the erroring form
+/// assert_eq!(array.try_as_primitive::<i32>().unwrap().value(0), 1);
+/// assert!(array.try_as_boolean().is_err());
+/// ```
+impl dyn Array {
+    downcast_fns!(as_null, try_as_null, NullArray, "NullArray");
+    downcast_fns!(as_boolean, try_as_boolean, BooleanArray, "BooleanArray");
+    downcast_fns!(
+        as_primitive,
+        try_as_primitive,
+        PrimitiveArray<T>,
+        "PrimitiveArray",
+        T: NativeType
+    );
+    downcast_fns!(as_binary, try_as_binary, BinaryArray<O>, "BinaryArray", O: Offset);
+    downcast_fns!(as_utf8, try_as_utf8, Utf8Array<O>, "Utf8Array", O: Offset);
+    downcast_fns!(
+        as_fixed_size_binary,
+        try_as_fixed_size_binary,
+        FixedSizeBinaryArray,
+        "FixedSizeBinaryArray"
+    );
+    downcast_fns!(as_list, try_as_list, ListArray<O>, "ListArray", O: Offset);
+    downcast_fns!(
+        as_fixed_size_list,
+        try_as_fixed_size_list,
+        FixedSizeListArray,
+        "FixedSizeListArray"
+    );
+    downcast_fns!(as_struct, try_as_struct, StructArray, "StructArray");
+    downcast_fns!(as_union, try_as_union, UnionArray, "UnionArray");
+    downcast_fns!(as_map, try_as_map, MapArray, "MapArray");
+    downcast_fns!(
+        as_dictionary,
+        try_as_dictionary,
+        DictionaryArray<K>,
+        "DictionaryArray",
+        K: DictionaryKey
+    );
+}
@@ -1,5 +1,5 @@
 use crate::offset::Offset;
-use crate::types::NativeType;
+use crate::types::{Index, NativeType};
 
 use super::*;
 
@@ -12,6 +12,7 @@ mod list;
 mod map;
 mod null;
 mod primitive;
+mod run_end;
 mod struct_;
 mod union;
 mod utf8;
@@ -172,6 +173,18 @@ impl<K: DictionaryKey> PartialEq<&dyn Array> for DictionaryArray<K> {
     }
 }
 
+impl<R: Index> PartialEq<RunEndEncodedArray<R>> for RunEndEncodedArray<R> {
+    fn eq(&self, other: &Self) -> bool {
+        run_end::equal(self, other)
+    }
+}
+
+impl<R: Index> PartialEq<&dyn Array> for RunEndEncodedArray<R> {
+    fn eq(&self, other: &&dyn Array) -> bool {
+        equal(self, *other)
+    }
+}
+
 impl PartialEq<UnionArray> for UnionArray {
     fn eq(&self, other: &Self) -> bool {
         union::equal(self, other)
@@ -284,5 +297,12 @@ pub fn equal(lhs: &dyn Array, rhs: &dyn Array) -> bool {
             let rhs = rhs.as_any().downcast_ref().unwrap();
             map::equal(lhs, rhs)
         }
+        RunEndEncoded(run_ends_type) => {
+            match_integer_type!(run_ends_type, |$T| {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                run_end::equal::<$T>(lhs, rhs)
+            })
+        }
     }
 }
@@ -0,0 +1,8 @@
+use crate::array::{Array, RunEndEncodedArray};
+use crate::types::Index;
+
+pub(super) fn equal<R: Index>(lhs: &RunEndEncodedArray<R>, rhs: &RunEndEncodedArray<R>) -> bool {
+    lhs.data_type() == rhs.data_type()
+        && lhs.len() == rhs.len()
+        && lhs.decode().as_ref() == rhs.decode().as_ref()
+}
@@ -82,5 +82,10 @@ pub fn offset_buffers_children_dictionary(array: &dyn Array) -> BuffersChildren
                 )
             })
         }
+        RunEndEncoded(run_ends_type) => {
+            match_integer_type!(run_ends_type, |$T| {
+                ffi_dyn!(array, RunEndEncodedArray<$T>)
+            })
+        }
     }
 }
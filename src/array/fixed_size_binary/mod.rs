@@ -195,7 +195,7 @@ impl FixedSizeBinaryArray {
         }
     }
 
-    /// Returns the size
+    /// Returns the size, in bytes, of every item.
     pub fn size(&self) -> usize {
         self.size
     }
@@ -236,6 +236,8 @@ impl Array for FixedSizeBinaryArray {
 
 impl FixedSizeBinaryArray {
     /// Creates a [`FixedSizeBinaryArray`] from an fallible iterator of optional `[u8]`.
+    /// # Errors
+    /// This function errors iff any of the non-null items is not `size` bytes long.
     pub fn try_from_iter<P: AsRef<[u8]>, I: IntoIterator<Item = Option<P>>>(
         iter: I,
         size: usize,
@@ -244,6 +246,8 @@ impl FixedSizeBinaryArray {
     }
 
     /// Creates a [`FixedSizeBinaryArray`] from an iterator of optional `[u8]`.
+    /// # Panics
+    /// This function panics iff any of the non-null items is not `size` bytes long.
     pub fn from_iter<P: AsRef<[u8]>, I: IntoIterator<Item = Option<P>>>(
         iter: I,
         size: usize,
@@ -113,9 +113,11 @@ impl MutableFixedSizeBinaryArray {
             Some(bytes) => {
                 let bytes = bytes.as_ref();
                 if self.size != bytes.len() {
-                    return Err(Error::InvalidArgumentError(
-                        "FixedSizeBinaryArray requires every item to be of its length".to_string(),
-                    ));
+                    return Err(Error::InvalidArgumentError(format!(
+                        "FixedSizeBinaryArray requires every item to be of its length ({}), but an item has length {}",
+                        self.size,
+                        bytes.len()
+                    )));
                 }
                 self.values.extend_from_slice(bytes);
 
@@ -96,6 +96,11 @@ pub fn get_value_display<'a, F: Write + 'a>(
                 super::dictionary::fmt::write_value::<$T,_>(array.as_any().downcast_ref().unwrap(), index, null, f)
             })
         }),
+        RunEndEncoded(run_ends_type) => match_integer_type!(run_ends_type, |$T| {
+            Box::new(move |f, index| {
+                super::run_end::fmt::write_value::<$T,_>(array.as_any().downcast_ref().unwrap(), index, null, f)
+            })
+        }),
     }
 }
 
@@ -1,7 +1,7 @@
-use std::sync::Arc;
+use std::{cmp::Ordering, sync::Arc};
 
 use crate::{
-    array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray},
+    array::{ord::build_compare, Array, DictionaryArray, DictionaryKey, PrimitiveArray},
     bitmap::MutableBitmap,
     datatypes::DataType,
 };
@@ -16,6 +16,8 @@ use super::{
 /// # Implementation
 /// This growable does not perform collision checks and instead concatenates
 /// the values of each [`DictionaryArray`] one after the other.
+/// When the dictionaries are ordered, the result is only marked as ordered
+/// if the concatenated values remain sorted.
 pub struct GrowableDictionary<'a, K: DictionaryKey> {
     data_type: DataType,
     keys_values: Vec<&'a [K]>,
@@ -41,6 +43,17 @@ fn concatenate_values<K: DictionaryKey>(
     (mutable.as_box(), offsets)
 }
 
+// whether `values` is sorted in ascending order. Values with nulls or without
+// a natural order are considered unsorted.
+fn is_sorted(values: &dyn Array) -> bool {
+    if values.null_count() > 0 {
+        return false;
+    }
+    build_compare(values, values)
+        .map(|cmp| (1..values.len()).all(|i| cmp(i - 1, i) != Ordering::Greater))
+        .unwrap_or(false)
+}
+
 impl<'a, T: DictionaryKey> GrowableDictionary<'a, T> {
     /// Creates a new [`GrowableDictionary`] bound to `arrays` with a pre-allocated `capacity`.
     /// # Panics
@@ -72,6 +85,13 @@ impl<'a, T: DictionaryKey> GrowableDictionary<'a, T> {
 
         let (values, offsets) = concatenate_values(&arrays_keys, &arrays_values, capacity);
 
+        let data_type = match data_type {
+            DataType::Dictionary(key, inner, true) if arrays.len() > 1 => {
+                DataType::Dictionary(key, inner, is_sorted(values.as_ref()))
+            }
+            data_type => data_type,
+        };
+
         Self {
             data_type,
             offsets,
@@ -29,6 +29,8 @@ mod utf8;
 pub use utf8::GrowableUtf8;
 mod dictionary;
 pub use dictionary::GrowableDictionary;
+mod run_end;
+pub use run_end::GrowableRunEndEncoded;
 
 mod utils;
 
@@ -39,7 +41,7 @@ pub trait Growable<'a> {
     /// Extends this [`Growable`] with elements from the bounded [`Array`] at index `index` from
     /// a slice starting at `start` and length `len`.
     /// # Panic
-    /// This function panics if the range is out of bounds, i.e. if `start + len >= array.len()`.
+    /// This function panics if the range is out of bounds, i.e. if `start + len > array.len()`.
     fn extend(&mut self, index: usize, start: usize, len: usize);
 
     /// Extends this [`Growable`] with null elements, disregarding the bound arrays
@@ -144,5 +146,19 @@ pub fn make_growable<'a>(
                 ))
             })
         }
+        RunEndEncoded(run_ends_type) => {
+            match_integer_type!(run_ends_type, |$T| {
+                let arrays = arrays
+                    .iter()
+                    .map(|array| {
+                        array
+                            .as_any()
+                            .downcast_ref::<RunEndEncodedArray<$T>>()
+                            .unwrap()
+                    })
+                    .collect::<Vec<_>>();
+                Box::new(run_end::GrowableRunEndEncoded::<$T>::new(arrays, capacity))
+            })
+        }
     }
 }
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, PrimitiveArray, RunEndEncodedArray},
+    datatypes::DataType,
+    types::Index,
+};
+
+use super::{make_growable, Growable};
+
+/// Concrete [`Growable`] for the [`RunEndEncodedArray`].
+/// # Implementation
+/// Every run overlapping an extended slice is copied as a run, i.e. runs are not merged with
+/// their equal neighbours.
+pub struct GrowableRunEndEncoded<'a, R: Index> {
+    arrays: Vec<&'a RunEndEncodedArray<R>>,
+    data_type: DataType,
+    run_ends: Vec<R>,
+    values: Box<dyn Growable<'a> + 'a>,
+    length: usize,
+}
+
+impl<'a, R: Index> GrowableRunEndEncoded<'a, R> {
+    /// Creates a new [`GrowableRunEndEncoded`] bound to `arrays` with a pre-allocated `capacity`.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub fn new(arrays: Vec<&'a RunEndEncodedArray<R>>, capacity: usize) -> Self {
+        let data_type = arrays[0].data_type().clone();
+        // the nulls of a run-end encoded array are null values
+        let values = make_growable(
+            &arrays
+                .iter()
+                .map(|array| array.values().as_ref())
+                .collect::<Vec<_>>(),
+            true,
+            capacity,
+        );
+
+        Self {
+            arrays,
+            data_type,
+            run_ends: Vec::with_capacity(capacity),
+            values,
+            length: 0,
+        }
+    }
+
+    fn push_run_end(&mut self, end: usize) {
+        self.run_ends
+            .push(R::from_usize(end).expect("The length to fit in the run ends"));
+    }
+
+    fn to(&mut self) -> RunEndEncodedArray<R> {
+        let run_ends = std::mem::take(&mut self.run_ends);
+        self.length = 0;
+
+        RunEndEncodedArray::try_new(
+            self.data_type.clone(),
+            PrimitiveArray::from_vec(run_ends),
+            self.values.as_box(),
+        )
+        .unwrap()
+    }
+}
+
+impl<'a, R: Index> Growable<'a> for GrowableRunEndEncoded<'a, R> {
+    fn extend(&mut self, index: usize, start: usize, len: usize) {
+        if len == 0 {
+            return;
+        }
+        let array = self.arrays[index];
+        let end = start + len;
+        let first = array.physical_index(start);
+        let last = array.physical_index(end - 1);
+        for run in first..=last {
+            let run_end = array.run_ends().value(run).to_usize().min(end);
+            self.values.extend(index, run, 1);
+            self.push_run_end(self.length + run_end - start);
+        }
+        self.length += len;
+    }
+
+    fn extend_validity(&mut self, additional: usize) {
+        if additional == 0 {
+            return;
+        }
+        self.values.extend_validity(1);
+        self.length += additional;
+        self.push_run_end(self.length);
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.to().arced()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.to().boxed()
+    }
+}
+
+impl<'a, R: Index> From<GrowableRunEndEncoded<'a, R>> for RunEndEncodedArray<R> {
+    fn from(mut val: GrowableRunEndEncoded<'a, R>) -> Self {
+        val.to()
+    }
+}
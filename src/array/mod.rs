@@ -19,7 +19,7 @@
 use std::any::Any;
 use std::sync::Arc;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::{
     bitmap::{Bitmap, MutableBitmap},
     datatypes::DataType,
@@ -68,6 +68,26 @@ pub trait Array: Send + Sync + dyn_clone::DynClone + 'static {
             .unwrap_or(0)
     }
 
+    /// The fraction of null slots on this [`Array`], `0.0` when it is empty.
+    /// # Implementation
+    /// This is `O(1)` since the number of null elements is pre-computed.
+    #[inline]
+    fn null_ratio(&self) -> f64 {
+        if self.is_empty() {
+            0.0
+        } else {
+            self.null_count() as f64 / self.len() as f64
+        }
+    }
+
+    /// Whether every slot of this [`Array`] is null. This is `true` when it is empty.
+    /// # Implementation
+    /// This is `O(1)` since the number of null elements is pre-computed.
+    #[inline]
+    fn is_all_null(&self) -> bool {
+        self.null_count() == self.len()
+    }
+
     /// Returns whether slot `i` is null.
     /// # Panic
     /// Panics iff `i >= self.len()`.
@@ -132,7 +152,24 @@ pub trait Array: Send + Sync + dyn_clone::DynClone + 'static {
     fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array>;
 
     /// Clone a `&dyn Array` to an owned `Box<dyn Array>`.
+    ///
+    /// This is a shallow clone: the buffers are reference counted and shared with `self`,
+    /// as when cloning a `Box<dyn Array>`. Use [`Array::deep_clone`] to copy them.
     fn to_boxed(&self) -> Box<dyn Array>;
+
+    /// Returns a deep clone of this [`Array`]: an equal array whose buffers (including those of
+    /// its children) are copied into new allocations that are not shared with `self`.
+    ///
+    /// This is needed e.g. before mutating the buffers in place via FFI.
+    /// # Implementation
+    /// This operation is `O(N)`, and only the sliced region of the buffers is copied.
+    #[must_use]
+    fn deep_clone(&self) -> Box<dyn Array> {
+        let array = self.to_boxed();
+        let mut growable = growable::make_growable(&[array.as_ref()], false, self.len());
+        growable.extend(0, 0, self.len());
+        growable.as_box()
+    }
 }
 
 dyn_clone::clone_trait_object!(Array);
@@ -327,6 +364,11 @@ impl std::fmt::Debug for dyn Array + '_ {
                 })
             }
             Map => fmt_dyn!(self, MapArray, f),
+            RunEndEncoded(run_ends_type) => {
+                match_integer_type!(run_ends_type, |$T| {
+                    fmt_dyn!(self, RunEndEncodedArray::<$T>, f)
+                })
+            }
         }
     }
 }
@@ -356,6 +398,11 @@ pub fn new_empty_array(data_type: DataType) -> Box<dyn Array> {
                 Box::new(DictionaryArray::<$T>::new_empty(data_type))
             })
         }
+        RunEndEncoded(run_ends_type) => {
+            match_integer_type!(run_ends_type, |$T| {
+                Box::new(RunEndEncodedArray::<$T>::new_empty(data_type))
+            })
+        }
     }
 }
 
@@ -386,6 +433,11 @@ pub fn new_null_array(data_type: DataType, length: usize) -> Box<dyn Array> {
                 Box::new(DictionaryArray::<$T>::new_null(data_type, length))
             })
         }
+        RunEndEncoded(run_ends_type) => {
+            match_integer_type!(run_ends_type, |$T| {
+                Box::new(RunEndEncodedArray::<$T>::new_null(data_type, length))
+            })
+        }
     }
 }
 
@@ -463,6 +515,11 @@ pub fn to_data(array: &dyn Array) -> arrow_data::ArrayData {
             })
         }
         Map => to_data_dyn!(array, MapArray),
+        RunEndEncoded(run_ends_type) => {
+            match_integer_type!(run_ends_type, |$T| {
+                to_data_dyn!(array, RunEndEncodedArray::<$T>)
+            })
+        }
     }
 }
 
@@ -493,6 +550,11 @@ pub fn from_data(data: &arrow_data::ArrayData) -> Box<dyn Array> {
             })
         }
         Map => Box::new(MapArray::from_data(data)),
+        RunEndEncoded(run_ends_type) => {
+            match_integer_type!(run_ends_type, |$T| {
+                Box::new(RunEndEncodedArray::<$T>::from_data(data))
+            })
+        }
     }
 }
 
@@ -655,7 +717,8 @@ macro_rules! impl_common_array {
     };
 }
 
-/// Clones a dynamic [`Array`].
+/// Clones a dynamic [`Array`]. Like [`Array::to_boxed`], this is a shallow clone that shares
+/// the buffers with `array`; see [`Array::deep_clone`] to copy them.
 /// # Implementation
 /// This operation is `O(1)` over `len`, as it amounts to increase two ref counts
 /// and moving the concrete struct under a `Box`.
@@ -683,6 +746,36 @@ pub fn clone(array: &dyn Array) -> Box<dyn Array> {
                 clone_dyn!(array, DictionaryArray::<$T>)
             })
         }
+        RunEndEncoded(run_ends_type) => {
+            match_integer_type!(run_ends_type, |$T| {
+                clone_dyn!(array, RunEndEncodedArray::<$T>)
+            })
+        }
+    }
+}
+
+/// Returns `array` with its validity replaced by `validity`, for any [`Array`].
+/// # Errors
+/// This function errors iff `validity.len() != array.len()` or `validity` is `Some` and
+/// the array has no validity (arrays of [`DataType::Null`] and [`DataType::Union`]).
+pub fn with_validity(array: Box<dyn Array>, validity: Option<Bitmap>) -> Result<Box<dyn Array>> {
+    use crate::datatypes::PhysicalType::*;
+    if let Some(validity) = &validity {
+        if validity.len() != array.len() {
+            return Err(Error::InvalidArgumentError(format!(
+                "The validity's length ({}) must be equal to the array's length ({})",
+                validity.len(),
+                array.len()
+            )));
+        }
+    }
+    match array.data_type().to_physical_type() {
+        Null | Union if validity.is_none() => Ok(array),
+        Null | Union => Err(Error::InvalidArgumentError(format!(
+            "Arrays of type {:?} have no validity",
+            array.data_type()
+        ))),
+        _ => Ok(array.with_validity(validity)),
     }
 }
 
@@ -703,11 +796,13 @@ mod list;
 mod map;
 mod null;
 mod primitive;
+mod run_end;
 mod specification;
 mod struct_;
 mod union;
 mod utf8;
 
+mod downcast;
 mod equal;
 mod ffi;
 mod fmt;
@@ -730,9 +825,12 @@ pub use list::{ListArray, ListValuesIter, MutableListArray};
 pub use map::MapArray;
 pub use null::{MutableNullArray, NullArray};
 pub use primitive::*;
+pub use run_end::RunEndEncodedArray;
 pub use struct_::{MutableStructArray, StructArray};
 pub use union::UnionArray;
-pub use utf8::{MutableUtf8Array, MutableUtf8ValuesArray, Utf8Array, Utf8ValuesIter};
+pub use utf8::{
+    MutableInternedUtf8Array, MutableUtf8Array, MutableUtf8ValuesArray, Utf8Array, Utf8ValuesIter,
+};
 
 pub(crate) use self::ffi::offset_buffers_children_dictionary;
 pub(crate) use self::ffi::FromFfi;
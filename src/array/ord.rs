@@ -24,6 +24,20 @@ pub fn total_cmp_f32(l: &f32, r: &f32) -> std::cmp::Ordering {
     left.cmp(&right)
 }
 
+/// implements comparison using IEEE 754 total ordering for f32, except that all NaNs
+/// (of any sign and payload) are equal and greater than any other value.
+///
+/// In particular, `-0.0 < 0.0` and NaNs are sorted last in ascending order.
+#[inline]
+pub fn total_cmp_nans_last_f32(l: &f32, r: &f32) -> std::cmp::Ordering {
+    match (l.is_nan(), r.is_nan()) {
+        (true, true) => std::cmp::Ordering::Equal,
+        (true, false) => std::cmp::Ordering::Greater,
+        (false, true) => std::cmp::Ordering::Less,
+        (false, false) => total_cmp_f32(l, r),
+    }
+}
+
 /// implements comparison using IEEE 754 total ordering for f64
 // Original implementation from https://doc.rust-lang.org/std/primitive.f64.html#method.total_cmp
 // TODO to change to use std when it becomes stable
@@ -38,6 +52,20 @@ pub fn total_cmp_f64(l: &f64, r: &f64) -> std::cmp::Ordering {
     left.cmp(&right)
 }
 
+/// implements comparison using IEEE 754 total ordering for f64, except that all NaNs
+/// (of any sign and payload) are equal and greater than any other value.
+///
+/// In particular, `-0.0 < 0.0` and NaNs are sorted last in ascending order.
+#[inline]
+pub fn total_cmp_nans_last_f64(l: &f64, r: &f64) -> std::cmp::Ordering {
+    match (l.is_nan(), r.is_nan()) {
+        (true, true) => std::cmp::Ordering::Equal,
+        (true, false) => std::cmp::Ordering::Greater,
+        (false, true) => std::cmp::Ordering::Less,
+        (false, false) => total_cmp_f64(l, r),
+    }
+}
+
 /// Total order of all native types whose Rust implementation
 /// that support total order.
 #[inline]
@@ -48,6 +76,40 @@ where
     l.cmp(r)
 }
 
+/// Native types with the total order used to sort them: [`total_cmp`] for integers and
+/// [`total_cmp_nans_last_f32`] and [`total_cmp_nans_last_f64`] for floats.
+pub trait SortOrd: NativeType {
+    /// Compares `self` and `other` as sorting does.
+    fn sort_cmp(&self, other: &Self) -> Ordering;
+}
+
+macro_rules! impl_sort_ord {
+    ($($type:ty),*) => {$(
+        impl SortOrd for $type {
+            #[inline]
+            fn sort_cmp(&self, other: &Self) -> Ordering {
+                total_cmp(self, other)
+            }
+        }
+    )*};
+}
+
+impl_sort_ord!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl SortOrd for f32 {
+    #[inline]
+    fn sort_cmp(&self, other: &Self) -> Ordering {
+        total_cmp_nans_last_f32(self, other)
+    }
+}
+
+impl SortOrd for f64 {
+    #[inline]
+    fn sort_cmp(&self, other: &Self) -> Ordering {
+        total_cmp_nans_last_f64(self, other)
+    }
+}
+
 fn compare_primitives<T: NativeType + Ord>(left: &dyn Array, right: &dyn Array) -> DynComparator {
     let left = left
         .as_any()
@@ -87,7 +149,7 @@ fn compare_f32(left: &dyn Array, right: &dyn Array) -> DynComparator {
         .downcast_ref::<PrimitiveArray<f32>>()
         .unwrap()
         .clone();
-    Box::new(move |i, j| total_cmp_f32(&left.value(i), &right.value(j)))
+    Box::new(move |i, j| total_cmp_nans_last_f32(&left.value(i), &right.value(j)))
 }
 
 fn compare_f64(left: &dyn Array, right: &dyn Array) -> DynComparator {
@@ -101,7 +163,7 @@ fn compare_f64(left: &dyn Array, right: &dyn Array) -> DynComparator {
         .downcast_ref::<PrimitiveArray<f64>>()
         .unwrap()
         .clone();
-    Box::new(move |i, j| total_cmp_f64(&left.value(i), &right.value(j)))
+    Box::new(move |i, j| total_cmp_nans_last_f64(&left.value(i), &right.value(j)))
 }
 
 fn compare_string<O: Offset>(left: &dyn Array, right: &dyn Array) -> DynComparator {
@@ -3,6 +3,8 @@ use crate::offset::{Offset, Offsets};
 
 /// # Safety
 /// The caller must ensure that `iterator` is `TrustedLen`.
+/// # Panics
+/// This function panics iff the total length of the values does not fit in `O`.
 #[inline]
 #[allow(clippy::type_complexity)]
 pub(crate) unsafe fn try_trusted_len_unzip<E, I, P, O>(
@@ -28,7 +30,9 @@ where
         if let Some(item) = item? {
             null.push_unchecked(true);
             let s = item.as_ref();
-            length += O::from_usize(s.len()).unwrap();
+            length = O::from_usize(s.len())
+                .and_then(|len| length.checked_add(&len))
+                .expect("the total length of the values to fit in the offset type");
             values.extend_from_slice(s);
         } else {
             null.push_unchecked(false);
@@ -142,6 +142,14 @@ impl<T: NativeType> PrimitiveArray<T> {
         Self::new(T::PRIMITIVE.into(), values.into(), None)
     }
 
+    /// Creates a [`PrimitiveArray`] from a vector of values and an optional validity.
+    /// This function is `O(1)`: the allocation of `values` is adopted, not copied.
+    /// # Panics
+    /// This function panics iff `validity.len() != values.len()`.
+    pub fn from_vec_with_validity(values: Vec<T>, validity: Option<Bitmap>) -> Self {
+        Self::new(T::PRIMITIVE.into(), values.into(), validity)
+    }
+
     /// Returns an iterator over the values and validity, `Option<&T>`.
     #[inline]
     pub fn iter(&self) -> ZipValidity<&T, std::slice::Iter<T>, BitmapIter> {
@@ -162,11 +170,31 @@ impl<T: NativeType> PrimitiveArray<T> {
 
     /// The values [`Buffer`].
     /// Values on null slots are undetermined (they can be anything).
+    ///
+    /// This buffer is sliced like this array, i.e. it starts at this array's first value.
     #[inline]
     pub fn values(&self) -> &Buffer<T> {
         &self.values
     }
 
+    /// The offset, in number of values, of this array's values in the full (unsliced)
+    /// region they share with [`PrimitiveArray::values_buffer`].
+    #[inline]
+    pub fn values_offset(&self) -> usize {
+        self.values.offset()
+    }
+
+    /// Returns the full (unsliced) [`Buffer`] backing this array's values, whose region
+    /// `self.values_offset()..self.values_offset() + self.len()` are this array's values.
+    ///
+    /// This is `O(1)`, since the data is shared.
+    pub fn values_buffer(&self) -> Buffer<T> {
+        let (data, _, _) = self.values.clone().into_inner();
+        let length = data.len();
+        // Safety: the full region of the data is initialized
+        unsafe { Buffer::from_inner_unchecked(data, 0, length) }
+    }
+
     /// Returns the optional validity.
     #[inline]
     pub fn validity(&self) -> Option<&Bitmap> {
@@ -273,6 +301,26 @@ impl<T: NativeType> PrimitiveArray<T> {
         }
     }
 
+    /// Sets as null every slot whose bit in `mask` is unset, i.e. sets the validity of
+    /// this array to the bitwise AND of its validity and `mask`.
+    ///
+    /// Slots that are null remain null. When this array has no validity, `mask` becomes its validity.
+    /// See also [`MutablePrimitiveArray::apply_validity`].
+    /// # Panics
+    /// This function panics iff `mask.len() != self.len()`.
+    pub fn and_validity(&mut self, mask: &Bitmap) {
+        assert_eq!(
+            mask.len(),
+            self.len(),
+            "mask's length must be equal to this arrays' length"
+        );
+        let validity = match std::mem::take(&mut self.validity) {
+            Some(validity) => &validity & mask,
+            None => mask.clone(),
+        };
+        self.set_validity(Some(validity))
+    }
+
     /// Returns an option of a mutable reference to the values of this [`PrimitiveArray`].
     pub fn get_mut_values(&mut self) -> Option<&mut [T]> {
         self.values.get_mut_slice()
@@ -384,6 +432,10 @@ impl<T: NativeType> PrimitiveArray<T> {
     }
 
     /// Creates a (non-null) [`PrimitiveArray`] from a slice of values.
+    ///
+    /// This is the fastest way of creating an array from borrowed data without nulls, as it
+    /// neither checks nor allocates a validity. Prefer [`PrimitiveArray::from_vec`] (`O(1)`)
+    /// when the values are already owned.
     /// # Implementation
     /// This is essentially a memcopy and is thus `O(N)`
     pub fn from_slice<P: AsRef<[T]>>(slice: P) -> Self {
@@ -432,6 +484,51 @@ impl<T: NativeType> PrimitiveArray<T> {
     }
 }
 
+#[cfg(feature = "compute_sort")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_sort")))]
+impl<T: super::ord::SortOrd> PrimitiveArray<T> {
+    /// Returns the index at which `value` would be inserted to keep this array sorted, i.e.
+    /// the first non-null slot that is not ordered before `value`, assuming the array is
+    /// sorted according to `options`.
+    ///
+    /// Values are ordered as [`sort`](crate::compute::sort::sort) orders them (see
+    /// [`SortOrd`](super::ord::SortOrd)), e.g. NaNs are greater than any other float.
+    ///
+    /// Only the non-null slots are searched: they are after the nulls when
+    /// `options.nulls_first` and before them otherwise.
+    /// # Example
+    /// ```
+    /// use arrow2::array::PrimitiveArray;
+    /// use arrow2::compute::sort::SortOptions;
+    ///
+    /// let array = PrimitiveArray::<i32>::from([Some(1), Some(3), Some(3), None]);
+    /// let options = SortOptions {
+    ///     descending: false,
+    ///     nulls_first: false,
+    /// };
+    /// assert_eq!(array.search_sorted(3, &options), 1);
+    /// assert_eq!(array.search_sorted(4, &options), 3);
+    /// ```
+    /// # Implementation
+    /// This function is `O(log(N))`. When the array is not sorted according to `options`,
+    /// the returned index is unspecified (but at most `self.len()`).
+    pub fn search_sorted(&self, value: T, options: &crate::compute::sort::SortOptions) -> usize {
+        let null_count = self.null_count();
+        let (start, end) = if options.nulls_first {
+            (null_count, self.len())
+        } else {
+            (0, self.len() - null_count)
+        };
+        let values = &self.values()[start..end];
+        start
+            + if options.descending {
+                values.partition_point(|x| x.sort_cmp(&value).is_gt())
+            } else {
+                values.partition_point(|x| x.sort_cmp(&value).is_lt())
+            }
+    }
+}
+
 impl<T: NativeType> Array for PrimitiveArray<T> {
     impl_common_array!();
 
@@ -345,6 +345,27 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
         self.validity = validity;
     }
 
+    /// Sets as null every slot whose bit in `mask` is unset, i.e. sets the validity of
+    /// this array to the bitwise AND of its validity and `mask`.
+    ///
+    /// Slots that are null remain null. When this array has no validity, `mask` becomes its validity.
+    /// See also [`PrimitiveArray::and_validity`].
+    /// # Panics
+    /// This function panics iff `mask.len() != self.len()`.
+    pub fn apply_validity(&mut self, mask: &Bitmap) {
+        assert_eq!(
+            mask.len(),
+            self.len(),
+            "mask's length must be equal to this arrays' length"
+        );
+        let validity = self.validity.take().unwrap_or_else(|| {
+            let mut validity = MutableBitmap::with_capacity(self.values.capacity());
+            validity.extend_constant(self.len(), true);
+            validity
+        });
+        self.validity = Some(validity & mask);
+    }
+
     /// Sets values.
     /// # Panic
     /// Panics iff the values' length is not equal to the existing validity's len.
@@ -0,0 +1,30 @@
+use crate::array::{from_data, to_data, Arrow2Arrow, PrimitiveArray, RunEndEncodedArray};
+use crate::datatypes::DataType;
+use crate::types::Index;
+use arrow_data::{ArrayData, ArrayDataBuilder};
+
+impl<R: Index> Arrow2Arrow for RunEndEncodedArray<R> {
+    fn to_data(&self) -> ArrayData {
+        let builder = ArrayDataBuilder::new(self.data_type.clone().into())
+            .len(self.len())
+            .child_data(vec![self.run_ends.to_data(), to_data(self.values.as_ref())]);
+
+        // Safety: RunEndEncodedArray is valid
+        unsafe { builder.build_unchecked() }
+    }
+
+    fn from_data(data: &ArrayData) -> Self {
+        let data_type = DataType::from(data.data_type().clone());
+        let run_ends = PrimitiveArray::from_data(&data.child_data()[0]);
+        let values = from_data(&data.child_data()[1]);
+
+        let mut array = Self {
+            data_type,
+            run_ends,
+            values,
+        };
+        // arrow slices run-end encoded arrays by their (logical) offset and length
+        array.slice(data.offset(), data.len());
+        array
+    }
+}
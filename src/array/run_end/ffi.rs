@@ -0,0 +1,60 @@
+use crate::{
+    array::{FromFfi, PrimitiveArray},
+    error::{Error, Result},
+    ffi,
+    types::Index,
+};
+
+use super::super::{ffi::ToFfi, Array};
+use super::RunEndEncodedArray;
+
+unsafe impl<R: Index> ToFfi for RunEndEncodedArray<R> {
+    fn buffers(&self) -> Vec<Option<*const u8>> {
+        vec![]
+    }
+
+    fn children(&self) -> Vec<Box<dyn Array>> {
+        vec![self.run_ends.clone().boxed(), self.values.clone()]
+    }
+
+    fn offset(&self) -> Option<usize> {
+        // the run ends are rebased on slicing
+        Some(0)
+    }
+
+    fn to_ffi_aligned(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<R: Index, A: ffi::ArrowArrayRef> FromFfi<A> for RunEndEncodedArray<R> {
+    unsafe fn try_from_ffi(array: A) -> Result<Self> {
+        let data_type = array.data_type().clone();
+
+        let arrow_array = array.array();
+        let len = arrow_array.len();
+        let offset = arrow_array.offset();
+
+        let run_ends = ffi::try_from(array.child(0)?)?;
+        let run_ends = run_ends
+            .as_any()
+            .downcast_ref::<PrimitiveArray<R>>()
+            .ok_or_else(|| {
+                Error::oos("The run ends of a RunEndEncodedArray must be a PrimitiveArray of R")
+            })?
+            .clone();
+        let values = ffi::try_from(array.child(1)?)?;
+
+        let mut array = Self::try_new(data_type, run_ends, values)?;
+        // the C data interface slices run-end encoded arrays via the parent's offset and length
+        if offset + len > array.len() {
+            return Err(Error::oos(
+                "The offset and length of a RunEndEncodedArray must be within its run ends",
+            ));
+        }
+        if offset != 0 || len != array.len() {
+            array.slice(offset, len);
+        }
+        Ok(array)
+    }
+}
@@ -0,0 +1,24 @@
+use std::fmt::{Debug, Formatter, Result, Write};
+
+use crate::types::Index;
+
+use super::super::fmt::{get_display, write_vec};
+use super::RunEndEncodedArray;
+
+pub fn write_value<R: Index, W: Write>(
+    array: &RunEndEncodedArray<R>,
+    index: usize,
+    null: &'static str,
+    f: &mut W,
+) -> Result {
+    get_display(array.values().as_ref(), null)(f, array.physical_index(index))
+}
+
+impl<R: Index> Debug for RunEndEncodedArray<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let writer = |f: &mut Formatter, index| write_value(self, index, "None", f);
+
+        write!(f, "RunEndEncodedArray")?;
+        write_vec(f, writer, None, self.len(), "None", false)
+    }
+}
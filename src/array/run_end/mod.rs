@@ -0,0 +1,405 @@
+use crate::{
+    array::{growable::make_growable, ord::build_compare, Array, BooleanArray, PrimitiveArray},
+    bitmap::Bitmap,
+    datatypes::{DataType, Field, IntegerType, PrimitiveType},
+    error::{Error, Result},
+    scalar::{new_scalar, Scalar},
+    types::Index,
+};
+
+use super::{new_empty_array, new_null_array};
+
+#[cfg(feature = "arrow")]
+mod data;
+mod ffi;
+pub(super) mod fmt;
+
+/// A run-end encoded array: an array whose consecutive equal values (runs) are stored once,
+/// together with the (exclusive) logical index at which each run ends.
+///
+/// For example, the array `[a, a, a, null, b, b]` is represented by the run ends `[3, 4, 6]`
+/// and the values `[a, null, b]`. This mirrors the layout of Arrow's run-end encoded arrays.
+///
+/// Like [`UnionArray`](crate::array::UnionArray) and Arrow's specification, this array has no
+/// validity of its own: [`Array::validity`] is `None`, [`Array::null_count`] is `0` and
+/// [`Array::is_null`] is `false` for every slot. Its (logical) nulls are the nulls of its
+/// values, available via [`RunEndEncodedArray::value`]. Use [`RunEndEncodedArray::decode`] to obtain the equivalent plain
+/// array, e.g. to use it in kernels that do not support [`DataType::RunEndEncoded`]. IPC, JSON
+/// and parquet do not support run-end encoded arrays yet.
+/// # Safety
+/// This struct guarantees that the run ends are strictly increasing, positive and as many as
+/// the values.
+#[derive(Clone)]
+pub struct RunEndEncodedArray<R: Index> {
+    data_type: DataType,
+    run_ends: PrimitiveArray<R>,
+    values: Box<dyn Array>,
+}
+
+/// Returns the [`IntegerType`] of the run ends `R`.
+fn run_ends_type<R: Index>() -> IntegerType {
+    match R::PRIMITIVE {
+        PrimitiveType::Int8 => IntegerType::Int8,
+        PrimitiveType::Int16 => IntegerType::Int16,
+        PrimitiveType::Int32 => IntegerType::Int32,
+        PrimitiveType::Int64 => IntegerType::Int64,
+        PrimitiveType::UInt8 => IntegerType::UInt8,
+        PrimitiveType::UInt16 => IntegerType::UInt16,
+        PrimitiveType::UInt32 => IntegerType::UInt32,
+        PrimitiveType::UInt64 => IntegerType::UInt64,
+        _ => unreachable!("Index is only implemented for integers"),
+    }
+}
+
+impl<R: Index> RunEndEncodedArray<R> {
+    /// Returns a new [`RunEndEncodedArray`].
+    /// # Errors
+    /// This function errors iff
+    /// * the `data_type`'s logical type is not [`DataType::RunEndEncoded`]
+    /// * the `data_type`'s run ends type is not `R` or its values field's data type is not equal
+    ///   to `values.data_type()`
+    /// * `run_ends` has nulls
+    /// * `run_ends` and `values` have different lengths
+    /// * `run_ends` is not strictly increasing or its first value is not positive
+    pub fn try_new(
+        data_type: DataType,
+        run_ends: PrimitiveArray<R>,
+        values: Box<dyn Array>,
+    ) -> Result<Self> {
+        let field = Self::try_get_field(&data_type)?;
+        if field.data_type().to_logical_type() != values.data_type().to_logical_type() {
+            return Err(Error::oos(
+                "RunEndEncodedArray must be initialized with a DataType::RunEndEncoded whose values field is equal to its values",
+            ));
+        }
+        if run_ends.null_count() != 0 {
+            return Err(Error::oos(
+                "The run ends of a RunEndEncodedArray cannot be null",
+            ));
+        }
+        if run_ends.len() != values.len() {
+            return Err(Error::oos(
+                "A RunEndEncodedArray must have as many run ends as values",
+            ));
+        }
+        let mut previous = R::default();
+        for end in run_ends.values().iter() {
+            if *end <= previous {
+                return Err(Error::oos(
+                    "The run ends of a RunEndEncodedArray must be positive and strictly increasing",
+                ));
+            }
+            previous = *end;
+        }
+        Ok(Self {
+            data_type,
+            run_ends,
+            values,
+        })
+    }
+
+    /// Returns a new [`RunEndEncodedArray`] whose [`DataType`] is inferred from `R` and `values`.
+    /// # Errors
+    /// This function errors iff
+    /// * `run_ends` has nulls
+    /// * `run_ends` and `values` have different lengths
+    /// * `run_ends` is not strictly increasing or its first value is not positive
+    pub fn try_from_run_ends(run_ends: PrimitiveArray<R>, values: Box<dyn Array>) -> Result<Self> {
+        let data_type = Self::default_data_type(values.data_type().clone());
+        Self::try_new(data_type, run_ends, values)
+    }
+
+    /// Returns a new empty [`RunEndEncodedArray`].
+    /// # Panics
+    /// This function panics iff `data_type` is not a valid [`DataType::RunEndEncoded`] of `R`.
+    pub fn new_empty(data_type: DataType) -> Self {
+        let values = Self::try_get_field(&data_type).unwrap().data_type().clone();
+        let values = new_empty_array(values);
+        Self::try_new(data_type, PrimitiveArray::from_vec(vec![]), values).unwrap()
+    }
+
+    /// Returns a new [`RunEndEncodedArray`] whose all slots are null.
+    /// # Panics
+    /// This function panics iff
+    /// * `data_type` is not a valid [`DataType::RunEndEncoded`] of `R`
+    /// * `length` does not fit in `R`
+    pub fn new_null(data_type: DataType, length: usize) -> Self {
+        if length == 0 {
+            return Self::new_empty(data_type);
+        }
+        let values = Self::try_get_field(&data_type).unwrap().data_type().clone();
+        let values = new_null_array(values, 1);
+        let run_ends = vec![R::from_usize(length).expect("The length to fit in the run ends")];
+        Self::try_new(data_type, PrimitiveArray::from_vec(run_ends), values).unwrap()
+    }
+
+    /// Returns the default [`DataType`] of a [`RunEndEncodedArray`] of `R` with values of
+    /// `values_data_type`.
+    pub fn default_data_type(values_data_type: DataType) -> DataType {
+        DataType::RunEndEncoded(
+            run_ends_type::<R>(),
+            Box::new(Field::new("values", values_data_type, true)),
+        )
+    }
+
+    fn try_get_field(data_type: &DataType) -> Result<&Field> {
+        match data_type.to_logical_type() {
+            DataType::RunEndEncoded(run_ends, field) if *run_ends == run_ends_type::<R>() => {
+                Ok(field.as_ref())
+            }
+            DataType::RunEndEncoded(_, _) => Err(Error::oos(
+                "RunEndEncodedArray must be initialized with a DataType::RunEndEncoded whose run ends are compatible to its run ends",
+            )),
+            _ => Err(Error::oos(
+                "RunEndEncodedArray must be initialized with logical DataType::RunEndEncoded",
+            )),
+        }
+    }
+
+    /// Returns a new [`RunEndEncodedArray`] by grouping consecutive equal values of `array`
+    /// (including nulls) into runs.
+    /// # Errors
+    /// This function errors iff
+    /// * the data type of `array` has no natural order (see [`build_compare`])
+    /// * the length of `array` does not fit in `R`
+    pub fn encode(array: &dyn Array) -> Result<Self> {
+        let compare = build_compare(array, array)?;
+        let equal = |i: usize, j: usize| match (array.is_valid(i), array.is_valid(j)) {
+            (true, true) => compare(i, j).is_eq(),
+            (false, false) => true,
+            _ => false,
+        };
+
+        let mut starts = vec![];
+        let mut run_ends = vec![];
+        for i in 0..array.len() {
+            if i == 0 || !equal(i - 1, i) {
+                if i > 0 {
+                    run_ends.push(R::from_usize(i).ok_or(Error::Overflow)?);
+                }
+                starts.push(i);
+            }
+        }
+        if !array.is_empty() {
+            run_ends.push(R::from_usize(array.len()).ok_or(Error::Overflow)?);
+        }
+
+        let values = gather(array, starts.into_iter().map(Some), run_ends.len());
+        Self::try_from_run_ends(PrimitiveArray::from_vec(run_ends), values)
+    }
+
+    /// Returns the (logical) length of this [`RunEndEncodedArray`].
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.run_ends
+            .values()
+            .last()
+            .map(|end| end.to_usize())
+            .unwrap_or(0)
+    }
+
+    /// Returns whether this [`RunEndEncodedArray`] is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the run ends of this [`RunEndEncodedArray`].
+    pub fn run_ends(&self) -> &PrimitiveArray<R> {
+        &self.run_ends
+    }
+
+    /// Returns the values of this [`RunEndEncodedArray`], one per run.
+    pub fn values(&self) -> &Box<dyn Array> {
+        &self.values
+    }
+
+    /// Slices this [`RunEndEncodedArray`].
+    /// # Implementation
+    /// This operation is `O(R)` where `R` is the number of runs, as the run ends are rebased.
+    /// # Panics
+    /// This function panics iff `offset + length > self.len()`.
+    pub fn slice(&mut self, offset: usize, length: usize) {
+        assert!(
+            offset + length <= self.len(),
+            "the offset of the new array cannot exceed the existing length"
+        );
+        unsafe { self.slice_unchecked(offset, length) }
+    }
+
+    /// Slices this [`RunEndEncodedArray`].
+    /// # Implementation
+    /// This operation is `O(R)` where `R` is the number of runs, as the run ends are rebased.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    pub unsafe fn slice_unchecked(&mut self, offset: usize, length: usize) {
+        if length == 0 {
+            self.run_ends = PrimitiveArray::from_vec(vec![]);
+            self.values.slice_unchecked(0, 0);
+            return;
+        }
+        let start = self.physical_index(offset);
+        let end = self.physical_index(offset + length - 1) + 1;
+        // the ends of the runs relative to `offset`, where the last run ends at `length`
+        let run_ends = self.run_ends.values()[start..end]
+            .iter()
+            .map(|end| R::from_usize(end.to_usize().min(offset + length) - offset).unwrap())
+            .collect::<Vec<_>>();
+        self.run_ends = PrimitiveArray::from_vec(run_ends);
+        self.values.slice_unchecked(start, end - start);
+    }
+
+    impl_sliced!();
+    impl_into_array!();
+
+    /// Returns the index in [`Self::values`] of the run containing the (logical) index `i`.
+    /// # Implementation
+    /// This function is `O(log(R))` where `R` is the number of runs.
+    /// # Panics
+    /// This function panics iff `i >= self.len()`.
+    #[inline]
+    pub fn physical_index(&self, i: usize) -> usize {
+        assert!(i < self.len());
+        self.run_ends
+            .values()
+            .partition_point(|end| end.to_usize() <= i)
+    }
+
+    /// Returns the value at the (logical) index `i`.
+    /// # Panics
+    /// This function panics iff `i >= self.len()`.
+    pub fn value(&self, i: usize) -> Box<dyn Scalar> {
+        new_scalar(self.values.as_ref(), self.physical_index(i))
+    }
+
+    /// Returns the equivalent plain array of this [`RunEndEncodedArray`].
+    /// # Implementation
+    /// This function is `O(N)` where `N` is the (logical) length, as each slot is extended
+    /// individually from its run's value.
+    pub fn decode(&self) -> Box<dyn Array> {
+        let mut growable = make_growable(&[self.values.as_ref()], false, self.len());
+        let mut start = 0;
+        self.run_ends
+            .values()
+            .iter()
+            .enumerate()
+            .for_each(|(index, end)| {
+                let end = end.to_usize();
+                (start..end).for_each(|_| growable.extend(0, index, 1));
+                start = end;
+            });
+        growable.as_box()
+    }
+
+    /// Returns a new [`RunEndEncodedArray`] with the (logical) values at `indices`.
+    /// Null indices are taken as nulls. Consecutive indices of the same run are taken as
+    /// a single run.
+    /// # Errors
+    /// This function errors iff
+    /// * any non-null index is out of range
+    /// * the length of `indices` does not fit in `R`
+    pub fn take<I: Index>(&self, indices: &PrimitiveArray<I>) -> Result<Self> {
+        let len = self.len();
+        if let Some(index) = indices
+            .iter()
+            .flatten()
+            .find(|index| index.to_usize() >= len)
+        {
+            return Err(Error::InvalidArgumentError(format!(
+                "The index {} is out of range of a RunEndEncodedArray of length {len}",
+                index.to_usize()
+            )));
+        }
+
+        let mut runs: Vec<Option<usize>> = vec![];
+        let mut run_ends = vec![];
+        for (i, index) in indices.iter().enumerate() {
+            let physical = index.map(|index| self.physical_index(index.to_usize()));
+            if i > 0 && runs.last() == Some(&physical) {
+                *run_ends.last_mut().unwrap() = R::from_usize(i + 1).ok_or(Error::Overflow)?;
+            } else {
+                runs.push(physical);
+                run_ends.push(R::from_usize(i + 1).ok_or(Error::Overflow)?);
+            }
+        }
+
+        let values = gather(self.values.as_ref(), runs.iter().copied(), runs.len());
+        Self::try_new(
+            self.data_type.clone(),
+            PrimitiveArray::from_vec(run_ends),
+            values,
+        )
+    }
+
+    /// Returns a new [`RunEndEncodedArray`] with the (logical) values where `mask` is `true`.
+    /// Nulls of `mask` are interpreted as `false`.
+    /// # Implementation
+    /// This function is `O(R + N / 64)` where `R` is the number of runs and `N` is the length
+    /// of `mask`, as the selected slots are counted per run.
+    /// # Errors
+    /// This function errors iff `mask` has a different length than `self`.
+    pub fn filter(&self, mask: &BooleanArray) -> Result<Self> {
+        if mask.len() != self.len() {
+            return Err(Error::InvalidArgumentError(format!(
+                "The mask must have the same length as the RunEndEncodedArray ({} != {})",
+                mask.len(),
+                self.len()
+            )));
+        }
+        let mask: Bitmap = match mask.validity() {
+            Some(validity) => mask.values() & validity,
+            None => mask.values().clone(),
+        };
+
+        let mut runs = vec![];
+        let mut run_ends = vec![];
+        let mut start = 0;
+        let mut length = 0;
+        for (index, end) in self.run_ends.values().iter().enumerate() {
+            let end = end.to_usize();
+            let run = mask.clone().sliced(start, end - start);
+            let selected = run.len() - run.unset_bits();
+            if selected > 0 {
+                length += selected;
+                runs.push(Some(index));
+                run_ends.push(R::from_usize(length).ok_or(Error::Overflow)?);
+            }
+            start = end;
+        }
+
+        let values = gather(self.values.as_ref(), runs.into_iter(), run_ends.len());
+        Self::try_new(
+            self.data_type.clone(),
+            PrimitiveArray::from_vec(run_ends),
+            values,
+        )
+    }
+}
+
+impl<R: Index> Array for RunEndEncodedArray<R> {
+    impl_common_array!();
+
+    fn validity(&self) -> Option<&Bitmap> {
+        None
+    }
+
+    /// # Panics
+    /// This function panics: a run-end encoded array has no validity of its own.
+    fn with_validity(&self, _: Option<Bitmap>) -> Box<dyn Array> {
+        panic!("cannot set validity of a run-end encoded array")
+    }
+}
+
+/// Returns a new array with the values of `array` at `indices`, where `None` is taken as null.
+fn gather<I: Iterator<Item = Option<usize>>>(
+    array: &dyn Array,
+    indices: I,
+    capacity: usize,
+) -> Box<dyn Array> {
+    let mut growable = make_growable(&[array], true, capacity);
+    indices.for_each(|index| match index {
+        Some(index) => growable.extend(0, index, 1),
+        None => growable.extend_validity(1),
+    });
+    growable.as_box()
+}
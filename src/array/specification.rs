@@ -119,7 +119,7 @@ pub(crate) unsafe fn check_indexes_unchecked<K: DictionaryKey>(
 
     // this loop is auto-vectorized
     keys.iter().for_each(|k| {
-        if k.as_usize() > len {
+        if k.as_usize() >= len {
             invalid = true;
         }
     });
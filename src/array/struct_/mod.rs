@@ -167,6 +167,81 @@ impl StructArray {
         (fields, values, validity)
     }
 
+    /// Returns a new [`StructArray`] whose child at `index` is replaced by the result of
+    /// applying `op` to it. The validity and the other children are preserved.
+    ///
+    /// If the new child has a different data type, the data type of the field is updated.
+    /// # Example
+    /// ```
+    /// use arrow2::array::{Array, BooleanArray, Int32Array, StructArray};
+    /// use arrow2::datatypes::{DataType, Field};
+    ///
+    /// let fields = vec![
+    ///     Field::new("b", DataType::Boolean, false),
+    ///     Field::new("c", DataType::Int32, false),
+    /// ];
+    /// let array = StructArray::new(
+    ///     DataType::Struct(fields),
+    ///     vec![
+    ///         BooleanArray::from_slice([true, false]).boxed(),
+    ///         Int32Array::from_slice([1, 2]).boxed(),
+    ///     ],
+    ///     None,
+    /// );
+    ///
+    /// let array = array
+    ///     .map_field(1, |c| {
+    ///         let c = c.as_any().downcast_ref::<Int32Array>().unwrap();
+    ///         Ok(Int32Array::from_iter(c.iter().map(|x| x.map(|x| x * 2))).boxed())
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(array.values()[1].as_ref(), &Int32Array::from_slice([2, 4]) as &dyn Array);
+    /// ```
+    /// # Errors
+    /// This function errors iff
+    /// * `index` is not smaller than the number of fields
+    /// * `op` errors
+    /// * the new child has a different length than `self`
+    pub fn map_field<F>(&self, index: usize, op: F) -> Result<Self, Error>
+    where
+        F: FnOnce(&dyn Array) -> Result<Box<dyn Array>, Error>,
+    {
+        let child = self.values.get(index).ok_or_else(|| {
+            Error::InvalidArgumentError(format!(
+                "The field index {index} is out of range of a StructArray with {} fields",
+                self.values.len()
+            ))
+        })?;
+        let new_child = op(child.as_ref())?;
+        if new_child.len() != self.len() {
+            return Err(Error::InvalidArgumentError(format!(
+                "The new child must have the same length as the StructArray ({} != {})",
+                new_child.len(),
+                self.len()
+            )));
+        }
+
+        let mut data_type = self.data_type.clone();
+        if new_child.data_type() != child.data_type() {
+            // keep the (possibly nested) extension types around the struct
+            let mut logical = &mut data_type;
+            while let DataType::Extension(_, inner, _) = logical {
+                logical = inner.as_mut();
+            }
+            if let DataType::Struct(fields) = logical {
+                fields[index].data_type = new_child.data_type().clone();
+            }
+        }
+        let mut values = self.values.clone();
+        values[index] = new_child;
+
+        Ok(Self {
+            data_type,
+            values,
+            validity: self.validity.clone(),
+        })
+    }
+
     /// Slices this [`StructArray`].
     /// # Panics
     /// * `offset + length` must be smaller than `self.len()`.
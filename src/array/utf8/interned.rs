@@ -0,0 +1,159 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use hash_hasher::HashedMap;
+
+use crate::{
+    array::{
+        Array, DictionaryArray, DictionaryKey, MutableArray, MutablePrimitiveArray,
+        MutableUtf8Array, TryPush, Utf8Array,
+    },
+    datatypes::DataType,
+    error::{Error, Result},
+    offset::Offset,
+};
+
+use super::MutableUtf8ValuesArray;
+
+/// A builder of utf8 arrays that interns its values: each distinct string is stored once and
+/// rows refer to it by key, as in a [`DictionaryArray`].
+///
+/// Depending on the observed cardinality, [`MutableInternedUtf8Array::finish`] produces either
+/// a [`DictionaryArray<i32>`] or a plain [`Utf8Array`].
+/// # Example
+/// ```
+/// use arrow2::array::{Array, MutableUtf8Array};
+/// use arrow2::datatypes::DataType;
+///
+/// let mut array = MutableUtf8Array::<i32>::with_interning();
+/// array.push(Some("a"));
+/// array.push(Some("b"));
+/// array.push(Some("a"));
+/// array.push::<&str>(None);
+/// assert_eq!(array.cardinality(), 2);
+///
+/// let array = array.finish(0.5).unwrap();
+/// assert!(matches!(array.data_type(), DataType::Dictionary(..)));
+/// ```
+#[derive(Debug, Default)]
+pub struct MutableInternedUtf8Array<O: Offset> {
+    keys: MutablePrimitiveArray<i32>,
+    // invariant: `keys` are smaller than `values.len()`
+    values: MutableUtf8ValuesArray<O>,
+    // hash of a value to the keys of the values with that hash
+    map: HashedMap<u64, Vec<i32>>,
+}
+
+impl<O: Offset> MutableUtf8Array<O> {
+    /// Returns an empty [`MutableInternedUtf8Array`], a builder of utf8 arrays that stores
+    /// each distinct value once.
+    pub fn with_interning() -> MutableInternedUtf8Array<O> {
+        MutableInternedUtf8Array::new()
+    }
+}
+
+impl<O: Offset> MutableInternedUtf8Array<O> {
+    /// Returns an empty [`MutableInternedUtf8Array`].
+    pub fn new() -> Self {
+        Self {
+            keys: MutablePrimitiveArray::new(),
+            values: MutableUtf8ValuesArray::new(),
+            map: HashedMap::default(),
+        }
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns whether there are no rows.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of distinct non-null values.
+    #[inline]
+    pub fn cardinality(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Pushes a new row.
+    /// # Panic
+    /// This operation panics iff the number of distinct values exceeds `i32::MAX` or
+    /// their length (in bytes) exceeds `O` maximum value.
+    #[inline]
+    pub fn push<T: AsRef<str>>(&mut self, value: Option<T>) {
+        self.try_push(value).unwrap()
+    }
+
+    fn key(&mut self, value: &str) -> Result<i32> {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        let keys = self.map.entry(hasher.finish()).or_default();
+
+        let values = &self.values;
+        if let Some(key) = keys
+            .iter()
+            .find(|key| values.value(**key as usize) == value)
+        {
+            return Ok(*key);
+        }
+        let key = i32::try_from(self.values.len()).map_err(|_| Error::Overflow)?;
+        self.values.try_push(value)?;
+        keys.push(key);
+        Ok(key)
+    }
+
+    /// Returns the [`DictionaryArray`] with the rows and the distinct values.
+    pub fn into_dictionary(self) -> DictionaryArray<i32> {
+        let values: Utf8Array<O> = self.values.into();
+        let data_type =
+            DataType::Dictionary(i32::KEY_TYPE, Box::new(values.data_type().clone()), false);
+        // Safety: the invariant of this struct makes every key valid
+        unsafe { DictionaryArray::try_new_unchecked(data_type, self.keys.into(), values.boxed()) }
+            .unwrap()
+    }
+
+    /// Returns the [`Utf8Array`] with the rows, where repeated values are copied.
+    /// # Errors
+    /// This function errors iff the length (in bytes) of the rows exceeds `O` maximum value.
+    pub fn into_utf8(self) -> Result<Utf8Array<O>> {
+        let values = &self.values;
+        let array = MutableUtf8Array::<O>::try_from_iter(
+            self.keys
+                .iter()
+                .map(|key| key.map(|key| values.value(*key as usize))),
+        )?;
+        Ok(array.into())
+    }
+
+    /// Returns the rows as a [`DictionaryArray<i32>`] if the ratio between the number of
+    /// distinct values and rows is at most `max_cardinality_ratio`, and as a [`Utf8Array`]
+    /// otherwise.
+    /// # Errors
+    /// This function errors iff a [`Utf8Array`] is returned and the length (in bytes) of
+    /// its rows exceeds `O` maximum value.
+    pub fn finish(self, max_cardinality_ratio: f64) -> Result<Box<dyn Array>> {
+        let ratio = if self.is_empty() {
+            1.0
+        } else {
+            self.cardinality() as f64 / self.len() as f64
+        };
+        if ratio <= max_cardinality_ratio {
+            Ok(self.into_dictionary().boxed())
+        } else {
+            self.into_utf8().map(|array| array.boxed())
+        }
+    }
+}
+
+impl<O: Offset, T: AsRef<str>> TryPush<Option<T>> for MutableInternedUtf8Array<O> {
+    fn try_push(&mut self, value: Option<T>) -> Result<()> {
+        let key = value.map(|value| self.key(value.as_ref())).transpose()?;
+        self.keys.push(key);
+        Ok(())
+    }
+}
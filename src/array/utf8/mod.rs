@@ -14,7 +14,7 @@ use either::Either;
 
 use super::{
     specification::{try_check_offsets_bounds, try_check_utf8},
-    Array, GenericBinaryArray,
+    Array, GenericBinaryArray, TryPush,
 };
 
 #[cfg(feature = "arrow")]
@@ -22,9 +22,11 @@ mod data;
 mod ffi;
 pub(super) mod fmt;
 mod from;
+mod interned;
 mod iterator;
 mod mutable;
 mod mutable_values;
+pub use interned::MutableInternedUtf8Array;
 pub use iterator::*;
 pub use mutable::*;
 pub use mutable_values::MutableUtf8ValuesArray;
@@ -440,6 +442,31 @@ impl<O: Offset> Utf8Array<O> {
         Self::try_new_unchecked(data_type, offsets, values, validity).unwrap()
     }
 
+    /// Creates a new [`Utf8Array`] of [`Self::default_data_type`] from its raw buffers,
+    /// without performing any check.
+    ///
+    /// Use [`Self::try_new`] to validate the offsets and utf8 of the values.
+    /// # Safety
+    /// This function is unsound iff:
+    /// * `offsets` is empty or its offsets are not monotonically increasing
+    /// * the last offset is larger than the values' length
+    /// * the validity's length is not equal to `offsets.len() - 1`
+    /// * The `values` between two consecutive `offsets` are not valid utf8
+    /// # Implementation
+    /// This function is `O(1)`
+    pub unsafe fn from_data_unchecked(
+        offsets: Buffer<O>,
+        values: Buffer<u8>,
+        validity: Option<Bitmap>,
+    ) -> Self {
+        Self {
+            data_type: Self::default_data_type(),
+            offsets: OffsetsBuffer::new_unchecked(offsets),
+            values,
+            validity,
+        }
+    }
+
     /// Returns a (non-null) [`Utf8Array`] created from a [`TrustedLen`] of `&str`.
     /// # Implementation
     /// This function is `O(N)`
@@ -482,6 +509,9 @@ impl<O: Offset> Utf8Array<O> {
     /// # Safety
     /// The iterator must be [`TrustedLen`](https://doc.rust-lang.org/std/iter/trait.TrustedLen.html).
     /// I.e. that `size_hint().1` correctly reports its length.
+    /// # Panics
+    /// This function panics iff the total length of the values does not fit in `O`.
+    /// Use `i64` offsets (`LargeUtf8`) to store more than `i32::MAX` bytes.
     #[inline]
     pub unsafe fn try_from_trusted_len_iter_unchecked<E, I, P>(
         iterator: I,
@@ -494,6 +524,9 @@ impl<O: Offset> Utf8Array<O> {
     }
 
     /// Creates a [`Utf8Array`] from an fallible iterator of trusted length.
+    /// # Panics
+    /// This function panics iff the total length of the values does not fit in `O`.
+    /// Use `i64` offsets (`LargeUtf8`) to store more than `i32::MAX` bytes.
     #[inline]
     pub fn try_from_trusted_len_iter<E, I, P>(iter: I) -> std::result::Result<Self, E>
     where
@@ -503,6 +536,29 @@ impl<O: Offset> Utf8Array<O> {
         MutableUtf8Array::<O>::try_from_trusted_len_iter(iter).map(|x| x.into())
     }
 
+    /// Creates a new [`Utf8Array`] from an [`Iterator`] of optional byte slices,
+    /// validating that each slice is valid utf8.
+    /// # Errors
+    /// This function errors iff a slice is not valid utf8, in which case the error
+    /// contains the index of the offending slice, or if the offsets overflow `O`.
+    pub fn try_from_iter<'a, I: Iterator<Item = Option<&'a [u8]>>>(iter: I) -> Result<Self> {
+        let (lower, _) = iter.size_hint();
+        let mut array = MutableUtf8Array::<O>::with_capacity(lower);
+        for (index, value) in iter.enumerate() {
+            let value = value
+                .map(|bytes| {
+                    simdutf8::basic::from_utf8(bytes).map_err(|_| {
+                        Error::InvalidArgumentError(format!(
+                            "The value at index {index} is not valid utf8"
+                        ))
+                    })
+                })
+                .transpose()?;
+            array.try_push(value)?;
+        }
+        Ok(array.into())
+    }
+
     /// Applies a function `f` to the validity of this array.
     ///
     /// This is an API to leverage clone-on-write
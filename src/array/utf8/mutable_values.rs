@@ -383,8 +383,9 @@ impl<O: Offset, T: AsRef<str>> TryPush<T> for MutableUtf8ValuesArray<O> {
     #[inline]
     fn try_push(&mut self, value: T) -> Result<()> {
         let bytes = value.as_ref().as_bytes();
+        self.offsets.try_push_usize(bytes.len())?;
         self.values.extend_from_slice(bytes);
-        self.offsets.try_push_usize(bytes.len())
+        Ok(())
     }
 }
 
@@ -2,7 +2,11 @@ use std::{iter::FromIterator, ops::Deref, sync::Arc};
 
 use either::Either;
 
-use crate::{buffer::Bytes, error::Error, trusted_len::TrustedLen};
+use crate::{
+    buffer::{Buffer, Bytes},
+    error::Error,
+    trusted_len::TrustedLen,
+};
 
 use super::{
     chunk_iter_to_vec,
@@ -98,6 +102,24 @@ impl Bitmap {
         })
     }
 
+    /// Initializes a new [`Bitmap`] from a [`Buffer`] of bytes and a length, without copying the bytes.
+    /// # Errors
+    /// This function errors iff `length > bytes.len() * 8`
+    /// # Implementation
+    /// This function is `O(length)`: it counts the unset bits of the bitmap.
+    #[inline]
+    pub fn from_bytes(bytes: Buffer<u8>, length: usize) -> Result<Self, Error> {
+        check(&bytes, 0, length)?;
+        let unset_bits = count_zeros(&bytes, 0, length);
+        let (bytes, offset, _) = bytes.into_inner();
+        Ok(Self {
+            bytes,
+            offset: offset * 8,
+            length,
+            unset_bits,
+        })
+    }
+
     /// Returns the length of the [`Bitmap`].
     #[inline]
     pub fn len(&self) -> usize {
@@ -115,6 +137,22 @@ impl Bitmap {
         BitmapIter::new(&self.bytes, self.offset, self.length)
     }
 
+    /// Returns the bits of this bitmap as a [`Vec<bool>`], unpacking them 64 at a time.
+    ///
+    /// This is the inverse of [`Bitmap::from_bool_slice`].
+    pub fn to_bool_vec(&self) -> Vec<bool> {
+        let mut vec = Vec::with_capacity(self.length);
+
+        let chunks = self.chunks::<u64>();
+        let remainder = chunks.remainder();
+        let remainder_len = chunks.remainder_len();
+        for chunk in chunks {
+            vec.extend((0..64).map(|i| chunk & (1 << i) != 0));
+        }
+        vec.extend((0..remainder_len).map(|i| remainder & (1 << i) != 0));
+        vec
+    }
+
     /// Returns an iterator over bits in bit chunks [`BitChunk`].
     ///
     /// This iterator is useful to operate over multiple bits via e.g. bitwise.
@@ -311,6 +349,22 @@ impl Bitmap {
         Bitmap::try_new(vec, length).unwrap()
     }
 
+    /// Creates a new [`Bitmap`] from a slice of `bool`, packing them 8 at a time.
+    ///
+    /// This is the inverse of [`Bitmap::to_bool_vec`].
+    pub fn from_bool_slice(slice: &[bool]) -> Self {
+        let bytes = slice
+            .chunks(8)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0u8, |byte, (i, bit)| byte | ((*bit as u8) << i))
+            })
+            .collect();
+        Bitmap::from_u8_vec(bytes, slice.len())
+    }
+
     /// Returns whether the bit at position `i` is set.
     #[inline]
     pub fn get(&self, i: usize) -> Option<bool> {
@@ -74,6 +74,12 @@ impl<T> Buffer<T> {
         Self::default()
     }
 
+    /// Creates a [`Buffer`] from a [`Vec`], adopting its allocation (i.e. without copying).
+    #[inline]
+    pub fn from_vec(data: Vec<T>) -> Self {
+        data.into()
+    }
+
     /// Auxiliary method to create a new Buffer
     pub(crate) fn from_bytes(bytes: Bytes<T>) -> Self {
         let length = bytes.len();
@@ -197,13 +203,22 @@ impl<T> Buffer<T> {
     /// This operation returns [`Either::Right`] iff this [`Buffer`]:
     /// * has not been cloned (i.e. [`Arc`]`::get_mut` yields [`Some`])
     /// * has not been imported from the c data interface (FFI)
+    /// * has not been sliced with a non-zero offset
+    ///
+    /// The returned [`Vec`] has the length of this [`Buffer`].
     #[inline]
     pub fn into_mut(mut self) -> Either<Self, Vec<T>> {
+        if self.offset != 0 {
+            return Either::Left(self);
+        }
         match Arc::get_mut(&mut self.data)
             .and_then(|b| b.get_vec())
             .map(std::mem::take)
         {
-            Some(inner) => Either::Right(inner),
+            Some(mut inner) => {
+                inner.truncate(self.length);
+                Either::Right(inner)
+            }
             None => Either::Left(self),
         }
     }
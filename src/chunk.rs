@@ -1,7 +1,8 @@
 //! Contains [`Chunk`], a container of [`Array`] where every array has the
 //! same length.
 
-use crate::array::Array;
+use crate::array::{equal, Array};
+use crate::datatypes::Schema;
 use crate::error::{Error, Result};
 
 /// A vector of trait objects of [`Array`] where every item has
@@ -68,6 +69,142 @@ impl<A: AsRef<dyn Array>> Chunk<A> {
     }
 }
 
+impl<A: AsRef<dyn Array> + Clone> Chunk<A> {
+    /// Returns a new [`Chunk`] with the columns at `indices`, in the order of `indices`.
+    /// Indices may be repeated. The arrays are cloned, which is `O(1)` for `Box<dyn Array>`
+    /// and `Arc<dyn Array>`.
+    /// # Error
+    /// Iff an index is larger or equal than the number of columns
+    pub fn project(&self, indices: &[usize]) -> Result<Self> {
+        let arrays = indices
+            .iter()
+            .map(|&index| {
+                self.arrays.get(index).cloned().ok_or_else(|| {
+                    Error::InvalidArgumentError(format!(
+                        "Column {index} is out of bounds for a chunk with {} columns",
+                        self.arrays.len()
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { arrays })
+    }
+
+    /// Returns a new [`Chunk`] with the columns named `names` in `schema`, in the order of `names`.
+    /// # Error
+    /// Iff the number of fields in `schema` differs from the number of columns or
+    /// a name is not a field of `schema`
+    pub fn project_by_name(&self, schema: &Schema, names: &[&str]) -> Result<Self> {
+        if schema.fields.len() != self.arrays.len() {
+            return Err(Error::InvalidArgumentError(format!(
+                "The schema has {} fields but the chunk has {} columns",
+                schema.fields.len(),
+                self.arrays.len()
+            )));
+        }
+        let indices = names
+            .iter()
+            .map(|name| {
+                schema
+                    .fields
+                    .iter()
+                    .position(|field| field.name == *name)
+                    .ok_or_else(|| {
+                        Error::InvalidArgumentError(format!(
+                            "The schema has no field named \"{name}\""
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.project(&indices)
+    }
+}
+
+/// Checks that the columns of `lhs` are equal to the columns of `rhs` with the same name,
+/// irrespectively of their order.
+///
+/// Columns are named by the fields of `lhs_schema` and `rhs_schema` and compared with [`equal`].
+/// # Errors
+/// Errors with a message naming the columns of `lhs` missing in `rhs`, the extra columns of `rhs`
+/// and the columns that are not equal, or when a schema's number of fields differs from its
+/// chunk's number of columns.
+pub fn chunk_equal_by_name<A: AsRef<dyn Array>, B: AsRef<dyn Array>>(
+    lhs: &Chunk<A>,
+    lhs_schema: &Schema,
+    rhs: &Chunk<B>,
+    rhs_schema: &Schema,
+) -> std::result::Result<(), String> {
+    if lhs_schema.fields.len() != lhs.arrays().len() {
+        return Err(format!(
+            "The left schema has {} fields but its chunk has {} columns",
+            lhs_schema.fields.len(),
+            lhs.arrays().len()
+        ));
+    }
+    if rhs_schema.fields.len() != rhs.arrays().len() {
+        return Err(format!(
+            "The right schema has {} fields but its chunk has {} columns",
+            rhs_schema.fields.len(),
+            rhs.arrays().len()
+        ));
+    }
+    let position =
+        |schema: &Schema, name: &str| schema.fields.iter().position(|field| field.name == name);
+
+    let mut missing = vec![];
+    let mut unequal = vec![];
+    for (field, lhs) in lhs_schema.fields.iter().zip(lhs.arrays()) {
+        match position(rhs_schema, &field.name) {
+            Some(index) if !equal(lhs.as_ref(), rhs.arrays()[index].as_ref()) => {
+                unequal.push(field.name.as_str())
+            }
+            Some(_) => {}
+            None => missing.push(field.name.as_str()),
+        }
+    }
+    let extra = rhs_schema
+        .fields
+        .iter()
+        .filter(|field| position(lhs_schema, &field.name).is_none())
+        .map(|field| field.name.as_str())
+        .collect::<Vec<_>>();
+
+    let errors = [("missing", missing), ("extra", extra), ("unequal", unequal)]
+        .into_iter()
+        .filter(|(_, names)| !names.is_empty())
+        .map(|(kind, names)| format!("{kind} columns {names:?}"))
+        .collect::<Vec<_>>();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("The chunks differ: {}", errors.join(", ")))
+    }
+}
+
+/// Applies `op` to each of `chunks` in parallel (via `rayon`) and returns the results in the
+/// order of `chunks`.
+/// # Error
+/// Iff `op` errors on any chunk, in which case the error of the first such chunk is returned
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub fn par_map_chunks<A, B, F>(chunks: &[Chunk<A>], op: F) -> Result<Vec<Chunk<B>>>
+where
+    A: AsRef<dyn Array> + Sync,
+    B: AsRef<dyn Array> + Send,
+    F: Fn(&Chunk<A>) -> Result<Chunk<B>> + Sync,
+{
+    use rayon::prelude::*;
+
+    // `&F` is `Send` since `F` is `Sync`
+    let op = &op;
+    chunks
+        .par_iter()
+        .map(op)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .collect()
+}
+
 impl<A: AsRef<dyn Array>> From<Chunk<A>> for Vec<A> {
     fn from(c: Chunk<A>) -> Self {
         c.into_arrays()
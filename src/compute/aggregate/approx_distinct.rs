@@ -0,0 +1,86 @@
+use crate::array::Array;
+use crate::compute::hash::hash;
+use crate::error::{Error, Result};
+
+/// A [HyperLogLog](https://en.wikipedia.org/wiki/HyperLogLog) sketch with `2^precision` registers.
+struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    fn new(precision: u8) -> Self {
+        Self {
+            precision,
+            registers: vec![0; 1 << precision],
+        }
+    }
+
+    #[inline]
+    fn insert(&mut self, hash: u64) {
+        let index = (hash >> (64 - self.precision)) as usize;
+        // the remaining bits, with a sentinel bit so that the rank is bounded
+        let remaining = (hash << self.precision) | (1 << (self.precision - 1));
+        let rank = remaining.leading_zeros() as u8 + 1;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum = self
+            .registers
+            .iter()
+            .map(|register| 2f64.powi(-(*register as i32)))
+            .sum::<f64>();
+        let estimate = alpha * m * m / sum;
+
+        let zeros = self.registers.iter().filter(|x| **x == 0).count();
+        let estimate = if estimate <= 2.5 * m && zeros > 0 {
+            // small range correction (linear counting)
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        };
+        estimate.round() as u64
+    }
+}
+
+/// Returns an estimate of the number of distinct non-null values of `array`, computed
+/// with a HyperLogLog sketch of `2^precision` registers over the [`hash`] of each value.
+///
+/// The memory used is `2^precision` bytes regardless of the cardinality of `array` and the
+/// typical relative error of the estimate is `1.04 / sqrt(2^precision)`
+/// (e.g. ~1.6% for a precision of 12).
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::approx_count_distinct;
+///
+/// let array = Int32Array::from([Some(1), Some(2), None, Some(1)]);
+/// assert_eq!(approx_count_distinct(&array, 12).unwrap(), 2);
+/// ```
+/// # Errors
+/// This function errors iff
+/// * `precision` is not between 4 and 18 (inclusive)
+/// * the data type of `array` is not supported by [`hash`]
+pub fn approx_count_distinct(array: &dyn Array, precision: u8) -> Result<u64> {
+    if !(4..=18).contains(&precision) {
+        return Err(Error::InvalidArgumentError(format!(
+            "The precision of approx_count_distinct must be between 4 and 18, but it is {precision}"
+        )));
+    }
+    let hashes = hash(array)?;
+
+    let mut sketch = HyperLogLog::new(precision);
+    hashes
+        .iter()
+        .flatten()
+        .for_each(|hash| sketch.insert(*hash));
+    Ok(sketch.estimate())
+}
@@ -0,0 +1,255 @@
+use std::ops::Add;
+
+use crate::array::{growable::make_growable, ord::SortOrd, Array, ListArray, PrimitiveArray};
+use crate::bitmap::MutableBitmap;
+use crate::error::{Error, Result};
+use crate::offset::OffsetsBuffer;
+use crate::types::NativeType;
+
+/// Checks that `group_ids` has the same length as `values` and that all its values
+/// are smaller than `num_groups`.
+fn check_groups(len: usize, group_ids: &PrimitiveArray<u32>, num_groups: usize) -> Result<()> {
+    if group_ids.len() != len {
+        return Err(Error::InvalidArgumentError(format!(
+            "The group ids must have the same length as the values ({} != {len})",
+            group_ids.len()
+        )));
+    }
+    if let Some(group) = group_ids
+        .iter()
+        .flatten()
+        .find(|group| **group as usize >= num_groups)
+    {
+        return Err(Error::InvalidArgumentError(format!(
+            "The group id {group} must be smaller than the number of groups ({num_groups})"
+        )));
+    }
+    Ok(())
+}
+
+/// Returns a [`ListArray`] of length `num_groups` whose `i`-th sublist contains the values of
+/// `values` whose group id is `i`, in the order they appear in `values`.
+///
+/// Rows whose group id is null are ignored. Null values are collected as null elements
+/// unless `skip_nulls` is `true`. Groups without values are empty sublists.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, UInt32Array};
+/// use arrow2::compute::aggregate::collect;
+///
+/// let values = Int32Array::from([Some(1), Some(2), None, Some(4)]);
+/// let group_ids = UInt32Array::from_slice([1, 0, 1, 1]);
+///
+/// let result = collect(&values, &group_ids, 2, false).unwrap();
+/// assert_eq!(result.value(0).as_ref(), &Int32Array::from_slice([2]) as &dyn Array);
+/// assert_eq!(result.value(1).as_ref(), &Int32Array::from([Some(1), None, Some(4)]) as &dyn Array);
+/// ```
+/// # Errors
+/// This function errors iff
+/// * `group_ids` and `values` have different lengths
+/// * any of the `group_ids` is `>= num_groups`
+/// * the number of collected values does not fit in an `i32`
+pub fn collect(
+    values: &dyn Array,
+    group_ids: &PrimitiveArray<u32>,
+    num_groups: usize,
+    skip_nulls: bool,
+) -> Result<ListArray<i32>> {
+    check_groups(values.len(), group_ids, num_groups)?;
+
+    let rows = || {
+        group_ids
+            .iter()
+            .enumerate()
+            .filter_map(|(row, group)| group.map(|group| (row, *group as usize)))
+            .filter(|(row, _)| !(skip_nulls && values.is_null(*row)))
+    };
+
+    let mut offsets = vec![0usize; num_groups + 1];
+    rows().for_each(|(_, group)| offsets[group + 1] += 1);
+    (0..num_groups).for_each(|group| offsets[group + 1] += offsets[group]);
+    let length = offsets[num_groups];
+    if length > i32::MAX as usize {
+        return Err(Error::Overflow);
+    }
+
+    // a stable counting sort of the rows by group
+    let mut positions = offsets[..num_groups].to_vec();
+    let mut order = vec![0usize; length];
+    rows().for_each(|(row, group)| {
+        order[positions[group]] = row;
+        positions[group] += 1;
+    });
+
+    let mut growable = make_growable(&[values], false, length);
+    let mut iter = order.into_iter().peekable();
+    while let Some(start) = iter.next() {
+        // extend consecutive rows at once
+        let mut len = 1;
+        while iter.next_if_eq(&(start + len)).is_some() {
+            len += 1;
+        }
+        growable.extend(0, start, len);
+    }
+
+    let offsets = offsets.into_iter().map(|x| x as i32).collect::<Vec<_>>();
+    // safety: offsets are monotonically increasing and start at 0
+    let offsets: OffsetsBuffer<i32> = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+
+    ListArray::try_new(
+        ListArray::<i32>::default_datatype(values.data_type().clone()),
+        offsets,
+        growable.as_box(),
+        None,
+    )
+}
+
+/// Reduces the non-null `values` of each group with `op`. Groups without non-null values are null.
+fn grouped_reduce<T, F>(
+    values: &PrimitiveArray<T>,
+    group_ids: &PrimitiveArray<u32>,
+    num_groups: usize,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType,
+    F: Fn(T, T) -> T,
+{
+    check_groups(values.len(), group_ids, num_groups)?;
+
+    let mut out = vec![T::default(); num_groups];
+    let mut validity = MutableBitmap::from_len_zeroed(num_groups);
+    values
+        .iter()
+        .zip(group_ids.iter())
+        .for_each(|(value, group)| {
+            if let (Some(value), Some(group)) = (value, group) {
+                let group = *group as usize;
+                out[group] = if validity.get(group) {
+                    op(out[group], *value)
+                } else {
+                    validity.set(group, true);
+                    *value
+                };
+            }
+        });
+
+    Ok(PrimitiveArray::new(
+        values.data_type().clone(),
+        out.into(),
+        validity.into(),
+    ))
+}
+
+/// Returns the sum of the non-null `values` of each group, where `group_ids` assigns a group to
+/// each value. The result has length `num_groups`; groups without non-null values are null.
+///
+/// Rows whose group id is null are ignored.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt32Array};
+/// use arrow2::compute::aggregate::grouped_sum;
+///
+/// let values = Int32Array::from([Some(1), Some(2), None, Some(4)]);
+/// let group_ids = UInt32Array::from_slice([0, 1, 2, 0]);
+///
+/// let result = grouped_sum(&values, &group_ids, 3).unwrap();
+/// assert_eq!(result, Int32Array::from([Some(5), Some(2), None]));
+/// ```
+/// # Errors
+/// This function errors iff
+/// * `group_ids` and `values` have different lengths
+/// * any of the `group_ids` is `>= num_groups`
+/// # Panics
+/// Like [`sum_primitive`](super::sum_primitive), this uses `T`'s `Add`: an integer overflow
+/// panics in debug builds and wraps in release builds.
+pub fn grouped_sum<T>(
+    values: &PrimitiveArray<T>,
+    group_ids: &PrimitiveArray<u32>,
+    num_groups: usize,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + Add<Output = T>,
+{
+    grouped_reduce(values, group_ids, num_groups, |acc, value| acc + value)
+}
+
+/// Returns the minimum of the non-null `values` of each group, where `group_ids` assigns a group
+/// to each value. The result has length `num_groups`; groups without non-null values are null.
+///
+/// Rows whose group id is null are ignored. Values are compared with the total order used by
+/// [`sort`](crate::compute::sort::sort) (see [`SortOrd`]): NaNs are greater than any other float,
+/// so the minimum of a group is NaN iff all its values are NaN, regardless of the order of the rows.
+/// # Errors
+/// This function errors iff
+/// * `group_ids` and `values` have different lengths
+/// * any of the `group_ids` is `>= num_groups`
+pub fn grouped_min<T>(
+    values: &PrimitiveArray<T>,
+    group_ids: &PrimitiveArray<u32>,
+    num_groups: usize,
+) -> Result<PrimitiveArray<T>>
+where
+    T: SortOrd,
+{
+    grouped_reduce(values, group_ids, num_groups, |acc, value| {
+        if value.sort_cmp(&acc).is_lt() {
+            value
+        } else {
+            acc
+        }
+    })
+}
+
+/// Returns the maximum of the non-null `values` of each group, where `group_ids` assigns a group
+/// to each value. The result has length `num_groups`; groups without non-null values are null.
+///
+/// Rows whose group id is null are ignored. Values are compared with the total order used by
+/// [`sort`](crate::compute::sort::sort) (see [`SortOrd`]): NaNs are greater than any other float,
+/// so the maximum of a group is NaN iff any of its values is NaN, regardless of the order of the rows.
+/// # Errors
+/// This function errors iff
+/// * `group_ids` and `values` have different lengths
+/// * any of the `group_ids` is `>= num_groups`
+pub fn grouped_max<T>(
+    values: &PrimitiveArray<T>,
+    group_ids: &PrimitiveArray<u32>,
+    num_groups: usize,
+) -> Result<PrimitiveArray<T>>
+where
+    T: SortOrd,
+{
+    grouped_reduce(values, group_ids, num_groups, |acc, value| {
+        if value.sort_cmp(&acc).is_gt() {
+            value
+        } else {
+            acc
+        }
+    })
+}
+
+/// Returns the number of non-null `values` of each group, where `group_ids` assigns a group to
+/// each value. The result has length `num_groups` and no nulls.
+///
+/// Rows whose group id is null are ignored.
+/// # Errors
+/// This function errors iff
+/// * `group_ids` and `values` have different lengths
+/// * any of the `group_ids` is `>= num_groups`
+pub fn grouped_count(
+    values: &dyn Array,
+    group_ids: &PrimitiveArray<u32>,
+    num_groups: usize,
+) -> Result<PrimitiveArray<u64>> {
+    check_groups(values.len(), group_ids, num_groups)?;
+
+    let mut out = vec![0u64; num_groups];
+    group_ids.iter().enumerate().for_each(|(row, group)| {
+        if let Some(group) = group {
+            if values.is_valid(row) {
+                out[*group as usize] += 1;
+            }
+        }
+    });
+    Ok(PrimitiveArray::from_vec(out))
+}
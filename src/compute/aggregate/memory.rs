@@ -1,6 +1,7 @@
 use crate::array::*;
 use crate::bitmap::Bitmap;
 use crate::datatypes::PhysicalType;
+use crate::types::Index;
 
 fn validity_size(validity: Option<&Bitmap>) -> usize {
     validity.as_ref().map(|b| b.as_slice().0.len()).unwrap_or(0)
@@ -11,7 +12,7 @@ macro_rules! dyn_binary {
         let array = $array.as_any().downcast_ref::<$ty>().unwrap();
         let offsets = array.offsets().buffer();
 
-        // in case of Binary/Utf8/List the offsets are sliced,
+        // in case of Binary/Utf8 the offsets are sliced,
         // not the values buffer
         let values_start = offsets[0] as usize;
         let values_end = offsets[offsets.len() - 1] as usize;
@@ -22,6 +23,25 @@ macro_rules! dyn_binary {
     }};
 }
 
+macro_rules! dyn_list {
+    ($array:expr, $ty:ty, $o:ty) => {{
+        let array = $array.as_any().downcast_ref::<$ty>().unwrap();
+        let offsets = array.offsets();
+
+        // in case of List the offsets are sliced, not the values; only
+        // the values referenced by the offsets are visible
+        let values_start = offsets.first().to_usize();
+        let values_end = offsets.last().to_usize();
+        let values = array
+            .values()
+            .sliced(values_start, values_end - values_start);
+
+        estimated_bytes_size(values.as_ref())
+            + offsets.buffer().len() * std::mem::size_of::<$o>()
+            + validity_size(array.validity())
+    }};
+}
+
 /// Returns the total (heap) allocated size of the array in bytes.
 /// # Implementation
 /// This estimation is the sum of the size of its buffers, validity, including nested arrays.
@@ -60,22 +80,12 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
         LargeBinary => dyn_binary!(array, BinaryArray<i64>, i64),
         Utf8 => dyn_binary!(array, Utf8Array<i32>, i32),
         LargeUtf8 => dyn_binary!(array, Utf8Array<i64>, i64),
-        List => {
-            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-            estimated_bytes_size(array.values().as_ref())
-                + array.offsets().len_proxy() * std::mem::size_of::<i32>()
-                + validity_size(array.validity())
-        }
+        List => dyn_list!(array, ListArray<i32>, i32),
         FixedSizeList => {
             let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
             estimated_bytes_size(array.values().as_ref()) + validity_size(array.validity())
         }
-        LargeList => {
-            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-            estimated_bytes_size(array.values().as_ref())
-                + array.offsets().len_proxy() * std::mem::size_of::<i64>()
-                + validity_size(array.validity())
-        }
+        LargeList => dyn_list!(array, ListArray<i64>, i64),
         Struct => {
             let array = array.as_any().downcast_ref::<StructArray>().unwrap();
             array
@@ -111,8 +121,23 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
         }),
         Map => {
             let array = array.as_any().downcast_ref::<MapArray>().unwrap();
-            let offsets = array.offsets().len_proxy() * std::mem::size_of::<i32>();
-            offsets + estimated_bytes_size(array.field().as_ref()) + validity_size(array.validity())
+            let offsets = array.offsets();
+            let values_start = offsets.first().to_usize();
+            let values_end = offsets.last().to_usize();
+            let field = array
+                .field()
+                .sliced(values_start, values_end - values_start);
+
+            offsets.buffer().len() * std::mem::size_of::<i32>()
+                + estimated_bytes_size(field.as_ref())
+                + validity_size(array.validity())
         }
+        RunEndEncoded(run_ends_type) => match_integer_type!(run_ends_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<RunEndEncodedArray<$T>>()
+                .unwrap();
+            estimated_bytes_size(array.run_ends()) + estimated_bytes_size(array.values().as_ref())
+        }),
     }
 }
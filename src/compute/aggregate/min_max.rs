@@ -204,6 +204,50 @@ where
     })
 }
 
+/// Returns the index of the first value of `array` for which `replace(value, selected)` is
+/// false against every other valid value.
+fn arg_min_max_primitive<T, F>(array: &PrimitiveArray<T>, replace: F) -> Option<usize>
+where
+    T: NativeType + PartialOrd,
+    F: Fn(T, T) -> bool,
+{
+    // a value that is not comparable to itself (NaN) is replaced by any other value
+    let is_nan = |value: T| value.partial_cmp(&value).is_none();
+    let replace =
+        |value: T, selected: T| replace(value, selected) || (is_nan(selected) && !is_nan(value));
+    let mut iter = array
+        .iter()
+        .enumerate()
+        .filter_map(|(index, value)| value.map(|value| (index, *value)));
+    let first = iter.next()?;
+    Some(
+        iter.fold(first, |selected, current| {
+            if replace(current.1, selected.1) {
+                current
+            } else {
+                selected
+            }
+        })
+        .0,
+    )
+}
+
+/// Returns the index of the minimum non-null value in the array, or `None` if all values are null.
+///
+/// On ties, the index of the first minimum is returned.
+/// NaN values are only returned when all other values are null.
+pub fn arg_min<T: NativeType + PartialOrd>(array: &PrimitiveArray<T>) -> Option<usize> {
+    arg_min_max_primitive(array, |value, selected| value < selected)
+}
+
+/// Returns the index of the maximum non-null value in the array, or `None` if all values are null.
+///
+/// On ties, the index of the first maximum is returned.
+/// NaN values are only returned when all other values are null.
+pub fn arg_max<T: NativeType + PartialOrd>(array: &PrimitiveArray<T>) -> Option<usize> {
+    arg_min_max_primitive(array, |value, selected| value > selected)
+}
+
 /// Helper to compute min/max of [`BinaryArray`] and [`Utf8Array`]
 macro_rules! min_max_binary_utf8 {
     ($array: expr, $cmp: expr) => {
@@ -9,6 +9,16 @@ mod min_max;
 #[cfg(feature = "compute_aggregate")]
 pub use min_max::*;
 
+#[cfg(feature = "compute_aggregate")]
+mod approx_distinct;
+#[cfg(feature = "compute_aggregate")]
+pub use approx_distinct::*;
+
+#[cfg(feature = "compute_aggregate")]
+mod grouped;
+#[cfg(feature = "compute_aggregate")]
+pub use grouped::*;
+
 mod memory;
 pub use memory::*;
 #[cfg(feature = "compute_aggregate")]
@@ -22,19 +22,23 @@ pub trait Sum<T> {
 
 #[multiversion(targets = "simd")]
 /// Compute the sum of a slice
+///
+/// Values are accumulated in `T::Simd::LANES` lanes from the start of the slice, the lanes
+/// are then reduced in order and the remainder is added last. The summation order therefore
+/// does not depend on the alignment of `values` nor on the feature `"simd"`, so floating
+/// point sums are bit-identical with and without it.
 pub fn sum_slice<T>(values: &[T]) -> T
 where
     T: NativeType + Simd + Add<Output = T> + std::iter::Sum<T>,
     T::Simd: Sum<T> + Add<Output = T::Simd>,
 {
-    let (head, simd_vals, tail) = T::Simd::align(values);
+    let mut chunks = values.chunks_exact(T::Simd::LANES);
 
-    let mut reduced = T::Simd::from_incomplete_chunk(&[], T::default());
-    for chunk in simd_vals {
-        reduced = reduced + *chunk;
-    }
+    let reduced = chunks.by_ref().fold(T::Simd::default(), |acc, chunk| {
+        acc + T::Simd::from_chunk(chunk)
+    });
 
-    reduced.simd_sum() + head.iter().copied().sum() + tail.iter().copied().sum()
+    reduced.simd_sum() + chunks.remainder().iter().copied().sum()
 }
 
 /// # Panics
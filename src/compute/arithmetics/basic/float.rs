@@ -0,0 +1,143 @@
+//! Definition of floating point functions (square root, logarithms and exponential)
+//! with primitive arrays.
+//!
+//! Values outside of a function's domain are `NaN` in the usual variants and `None`
+//! in the `checked_*` variants.
+use num_traits::Float;
+
+use crate::{
+    array::PrimitiveArray,
+    compute::arity::{unary, unary_checked},
+    types::NativeType,
+};
+
+/// Square root of values from array. Negative values are `NaN`.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::sqrt;
+/// use arrow2::array::{Array, Float64Array};
+///
+/// let a = Float64Array::from(&[Some(4.0), None, Some(-1.0)]);
+/// let result = sqrt(&a);
+/// assert_eq!(result.value(0), 2.0);
+/// assert!(result.is_null(1));
+/// assert!(result.value(2).is_nan());
+/// ```
+pub fn sqrt<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Float,
+{
+    unary(array, |a| a.sqrt(), array.data_type().clone())
+}
+
+/// Checked square root of values from array, where negative values are converted to `None`.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::checked_sqrt;
+/// use arrow2::array::Float64Array;
+///
+/// let a = Float64Array::from(&[Some(4.0), None, Some(-1.0)]);
+/// let result = checked_sqrt(&a);
+/// assert_eq!(result, Float64Array::from(&[Some(2.0), None, None]));
+/// ```
+pub fn checked_sqrt<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Float,
+{
+    let op = |a: T| (a >= T::zero()).then(|| a.sqrt());
+    unary_checked(array, op, array.data_type().clone())
+}
+
+/// Natural logarithm of values from array. Negative values are `NaN` and `0` is `-inf`.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::ln;
+/// use arrow2::array::Float64Array;
+///
+/// let a = Float64Array::from(&[Some(1.0), None, Some(0.0)]);
+/// let result = ln(&a);
+/// assert_eq!(result, Float64Array::from(&[Some(0.0), None, Some(f64::NEG_INFINITY)]));
+/// ```
+pub fn ln<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Float,
+{
+    unary(array, |a| a.ln(), array.data_type().clone())
+}
+
+/// Checked natural logarithm of values from array, where non-positive values are converted to `None`.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::checked_ln;
+/// use arrow2::array::Float64Array;
+///
+/// let a = Float64Array::from(&[Some(1.0), None, Some(0.0)]);
+/// let result = checked_ln(&a);
+/// assert_eq!(result, Float64Array::from(&[Some(0.0), None, None]));
+/// ```
+pub fn checked_ln<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Float,
+{
+    let op = |a: T| (a > T::zero()).then(|| a.ln());
+    unary_checked(array, op, array.data_type().clone())
+}
+
+/// Base 10 logarithm of values from array. Negative values are `NaN` and `0` is `-inf`.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::log10;
+/// use arrow2::array::Float64Array;
+///
+/// let a = Float64Array::from(&[Some(100.0), None]);
+/// let result = log10(&a);
+/// assert_eq!(result, Float64Array::from(&[Some(2.0), None]));
+/// ```
+pub fn log10<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Float,
+{
+    unary(array, |a| a.log10(), array.data_type().clone())
+}
+
+/// Checked base 10 logarithm of values from array, where non-positive values are converted to `None`.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::checked_log10;
+/// use arrow2::array::Float64Array;
+///
+/// let a = Float64Array::from(&[Some(100.0), None, Some(-1.0)]);
+/// let result = checked_log10(&a);
+/// assert_eq!(result, Float64Array::from(&[Some(2.0), None, None]));
+/// ```
+pub fn checked_log10<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Float,
+{
+    let op = |a: T| (a > T::zero()).then(|| a.log10());
+    unary_checked(array, op, array.data_type().clone())
+}
+
+/// Exponential (`e^x`) of values from array.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::exp;
+/// use arrow2::array::Float64Array;
+///
+/// let a = Float64Array::from(&[Some(0.0), None, Some(f64::NEG_INFINITY)]);
+/// let result = exp(&a);
+/// assert_eq!(result, Float64Array::from(&[Some(1.0), None, Some(0.0)]));
+/// ```
+pub fn exp<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Float,
+{
+    unary(array, |a| a.exp(), array.data_type().clone())
+}
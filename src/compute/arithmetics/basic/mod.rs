@@ -9,6 +9,8 @@ mod add;
 pub use add::*;
 mod div;
 pub use div::*;
+mod float;
+pub use float::*;
 mod mul;
 pub use mul::*;
 mod pow;
@@ -20,7 +22,7 @@ pub use sub::*;
 
 use std::ops::Neg;
 
-use num_traits::{CheckedNeg, WrappingNeg};
+use num_traits::{CheckedNeg, Signed, WrappingNeg};
 
 use crate::{array::PrimitiveArray, types::NativeType};
 
@@ -99,3 +101,98 @@ where
 {
     unary(array, |a| a.wrapping_neg(), array.data_type().clone())
 }
+
+/// Absolute values from array.
+/// # Panic
+/// This function panics (in debug mode) on overflow, i.e. on the minimum of a signed integer.
+/// Use [`wrapping_abs`] or [`checked_abs`] to handle it.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::abs;
+/// use arrow2::array::PrimitiveArray;
+///
+/// let a = PrimitiveArray::from([None, Some(-6.0), Some(-0.0), Some(7.0)]);
+/// let result = abs(&a);
+/// let expected = PrimitiveArray::from([None, Some(6.0), Some(0.0), Some(7.0)]);
+/// assert_eq!(result, expected)
+/// ```
+pub fn abs<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Signed,
+{
+    unary(array, |a| a.abs(), array.data_type().clone())
+}
+
+/// Checked absolute values from array, where the overflowing values (i.e. the minimum
+/// of a signed integer) are converted to `None`.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::checked_abs;
+/// use arrow2::array::PrimitiveArray;
+///
+/// let a = PrimitiveArray::from([None, Some(-6), Some(i8::MIN), Some(7)]);
+/// let result = checked_abs(&a);
+/// let expected = PrimitiveArray::from([None, Some(6), None, Some(7)]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn checked_abs<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Signed + CheckedNeg,
+{
+    unary_checked(
+        array,
+        |a| {
+            if a.is_negative() {
+                a.checked_neg()
+            } else {
+                Some(a)
+            }
+        },
+        array.data_type().clone(),
+    )
+}
+
+/// Wrapping absolute values from array, where the minimum of a signed integer is
+/// returned unchanged.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::wrapping_abs;
+/// use arrow2::array::PrimitiveArray;
+///
+/// let a = PrimitiveArray::from([None, Some(-6), Some(i8::MIN), Some(7)]);
+/// let result = wrapping_abs(&a);
+/// let expected = PrimitiveArray::from([None, Some(6), Some(i8::MIN), Some(7)]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn wrapping_abs<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Signed + WrappingNeg,
+{
+    unary(
+        array,
+        |a| if a.is_negative() { a.wrapping_neg() } else { a },
+        array.data_type().clone(),
+    )
+}
+
+/// Signum of values from array: `-1`, `0` or `1` for integers and `-1.0`, `1.0` or `NaN`
+/// for floats, where (like in Rust) `0.0` and `-0.0` are mapped to `1.0` and `-1.0`.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::signum;
+/// use arrow2::array::PrimitiveArray;
+///
+/// let a = PrimitiveArray::from([None, Some(-6), Some(0), Some(7)]);
+/// let result = signum(&a);
+/// let expected = PrimitiveArray::from([None, Some(-1), Some(0), Some(1)]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn signum<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Signed,
+{
+    unary(array, |a| a.signum(), array.data_type().clone())
+}
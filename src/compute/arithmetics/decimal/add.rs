@@ -12,7 +12,7 @@ use crate::{
     error::{Error, Result},
 };
 
-use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
+use super::{adjusted_precision_scale, checked_or_null, get_parameters, max_value, number_digits};
 
 /// Adds two decimal [`PrimitiveArray`] with the same precision and scale.
 /// # Error
@@ -167,6 +167,9 @@ impl ArraySaturatingAdd<PrimitiveArray<i128>> for PrimitiveArray<i128> {
 /// ------------------
 /// 22222.221  -> 8, 3
 /// ```
+/// # Errors
+/// Errors if the arrays are not decimals, have different lengths or if any
+/// non-null result overflows `i128`.
 /// # Examples
 /// ```
 /// use arrow2::compute::arithmetics::decimal::adaptive_add;
@@ -204,18 +207,22 @@ pub fn adaptive_add(
     let shift = 10i128.pow(diff as u32);
     let mut max = max_value(res_p);
 
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
     let values = lhs
         .values()
         .iter()
         .zip(rhs.values().iter())
-        .map(|(l, r)| {
+        .enumerate()
+        .map(|(index, (l, r))| {
             // Based on the array's scales one of the arguments in the sum has to be shifted
             // to the left to match the final scale
             let res = if lhs_s > rhs_s {
-                l + r * shift
+                r.checked_mul(shift).and_then(|r| l.checked_add(r))
             } else {
-                l * shift + r
+                l.checked_mul(shift).and_then(|l| l.checked_add(*r))
             };
+            let res = checked_or_null(res, validity.as_ref(), index)?;
 
             // The precision of the resulting array will change if one of the
             // sums during the iteration produces a value bigger than the
@@ -229,11 +236,9 @@ pub fn adaptive_add(
                 res_p = number_digits(res);
                 max = max_value(res_p);
             }
-            res
+            Ok(res)
         })
-        .collect::<Vec<_>>();
-
-    let validity = combine_validities(lhs.validity(), rhs.validity());
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(PrimitiveArray::<i128>::new(
         DataType::Decimal(res_p, res_s),
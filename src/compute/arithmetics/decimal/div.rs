@@ -13,7 +13,7 @@ use crate::{
     scalar::{PrimitiveScalar, Scalar},
 };
 
-use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
+use super::{adjusted_precision_scale, checked_or_null, get_parameters, max_value, number_digits};
 
 /// Divide two decimal primitive arrays with the same precision and scale. If
 /// the precision and scale is different, then an InvalidArgumentError is
@@ -226,6 +226,9 @@ impl ArrayCheckedDiv<PrimitiveArray<i128>> for PrimitiveArray<i128> {
 /// -----------------
 ///   100.0000 -> 9, 4
 /// ```
+/// # Errors
+/// Errors if the arrays are not decimals, have different lengths or if any
+/// non-null result overflows `i128`.
 /// # Examples
 /// ```
 /// use arrow2::compute::arithmetics::decimal::adaptive_div;
@@ -264,21 +267,33 @@ pub fn adaptive_div(
     let shift_1 = 10i128.pow(res_s as u32);
     let mut max = max_value(res_p);
 
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
     let values = lhs
         .values()
         .iter()
         .zip(rhs.values().iter())
-        .map(|(l, r)| {
-            let numeral: i128 = l * shift_1;
+        .enumerate()
+        .map(|(index, (l, r))| {
+            let numeral = l.checked_mul(shift_1);
 
             // Based on the array's scales one of the arguments in the sum has to be shifted
             // to the left to match the final scale
-            let res = if lhs_s > rhs_s {
-                numeral.checked_div(r * shift)
+            let (numeral, divisor) = if lhs_s > rhs_s {
+                (numeral, r.checked_mul(shift))
             } else {
-                (numeral * shift).checked_div(*r)
+                (
+                    numeral.and_then(|numeral| numeral.checked_mul(shift)),
+                    Some(*r),
+                )
+            };
+            if divisor == Some(0) && validity.as_ref().map_or(true, |v| v.get_bit(index)) {
+                panic!("Found division by zero")
             }
-            .expect("Found division by zero");
+            let res = numeral
+                .zip(divisor)
+                .and_then(|(numeral, divisor)| numeral.checked_div(divisor));
+            let res = checked_or_null(res, validity.as_ref(), index)?;
 
             // The precision of the resulting array will change if one of the
             // multiplications during the iteration produces a value bigger
@@ -292,12 +307,9 @@ pub fn adaptive_div(
                 res_p = number_digits(res);
                 max = max_value(res_p);
             }
-
-            res
+            Ok(res)
         })
-        .collect::<Vec<_>>();
-
-    let validity = combine_validities(lhs.validity(), rhs.validity());
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(PrimitiveArray::<i128>::new(
         DataType::Decimal(res_p, res_s),
@@ -12,13 +12,16 @@ pub use mul::*;
 mod sub;
 pub use sub::*;
 
+use crate::bitmap::Bitmap;
 use crate::datatypes::DataType;
 use crate::error::{Error, Result};
 
 /// Maximum value that can exist with a selected precision
 #[inline]
 fn max_value(precision: usize) -> i128 {
-    10i128.pow(precision as u32) - 1
+    10i128
+        .checked_pow(precision as u32)
+        .map_or(i128::MAX, |value| value - 1)
 }
 
 // Calculates the number of digits in a i128 number
@@ -35,6 +38,17 @@ fn number_digits(num: i128) -> usize {
     digit as usize
 }
 
+/// Returns the result of a checked operation on the slot `index`, erroring on overflow
+/// unless the slot is null, since null slots may hold any value.
+#[inline]
+fn checked_or_null(res: Option<i128>, validity: Option<&Bitmap>, index: usize) -> Result<i128> {
+    match res {
+        Some(res) => Ok(res),
+        None if validity.map_or(true, |v| v.get_bit(index)) => Err(Error::Overflow),
+        None => Ok(0),
+    }
+}
+
 fn get_parameters(lhs: &DataType, rhs: &DataType) -> Result<(usize, usize)> {
     if let (DataType::Decimal(lhs_p, lhs_s), DataType::Decimal(rhs_p, rhs_s)) =
         (lhs.to_logical_type(), rhs.to_logical_type())
@@ -81,7 +95,8 @@ fn adjusted_precision_scale(
         (rhs_s, rhs_s - lhs_s)
     };
 
-    let res_p = res_digits_before + res_s;
+    // 38 is the maximum number of digits of a `Decimal`
+    let res_p = (res_digits_before + res_s).min(38);
 
     (res_p, res_s, diff)
 }
@@ -13,7 +13,7 @@ use crate::{
     scalar::{PrimitiveScalar, Scalar},
 };
 
-use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
+use super::{adjusted_precision_scale, checked_or_null, get_parameters, max_value, number_digits};
 
 /// Multiply two decimal primitive arrays with the same precision and scale. If
 /// the precision and scale is different, then an InvalidArgumentError is
@@ -238,6 +238,9 @@ impl ArraySaturatingMul<PrimitiveArray<i128>> for PrimitiveArray<i128> {
 /// -----------------
 ///  111132.222  -> 9, 3
 /// ```
+/// # Errors
+/// Errors if the arrays are not decimals, have different lengths or if any
+/// non-null result overflows `i128`.
 /// # Examples
 /// ```
 /// use arrow2::compute::arithmetics::decimal::adaptive_mul;
@@ -276,19 +279,22 @@ pub fn adaptive_mul(
     let shift_1 = 10i128.pow(res_s as u32);
     let mut max = max_value(res_p);
 
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
     let values = lhs
         .values()
         .iter()
         .zip(rhs.values().iter())
-        .map(|(l, r)| {
+        .enumerate()
+        .map(|(index, (l, r))| {
             // Based on the array's scales one of the arguments in the sum has to be shifted
             // to the left to match the final scale
             let res = if lhs_s > rhs_s {
-                l.checked_mul(r * shift)
+                r.checked_mul(shift).and_then(|r| l.checked_mul(r))
             } else {
-                (l * shift).checked_mul(*r)
-            }
-            .expect("Mayor overflow for multiplication");
+                l.checked_mul(shift).and_then(|l| l.checked_mul(*r))
+            };
+            let res = checked_or_null(res, validity.as_ref(), index)?;
 
             let res = res / shift_1;
 
@@ -304,12 +310,9 @@ pub fn adaptive_mul(
                 res_p = number_digits(res);
                 max = max_value(res_p);
             }
-
-            res
+            Ok(res)
         })
-        .collect::<Vec<_>>();
-
-    let validity = combine_validities(lhs.validity(), rhs.validity());
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(PrimitiveArray::<i128>::new(
         DataType::Decimal(res_p, res_s),
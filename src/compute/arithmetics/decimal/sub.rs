@@ -11,7 +11,7 @@ use crate::{
     error::{Error, Result},
 };
 
-use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
+use super::{adjusted_precision_scale, checked_or_null, get_parameters, max_value, number_digits};
 
 /// Subtract two decimal primitive arrays with the same precision and scale. If
 /// the precision and scale is different, then an InvalidArgumentError is
@@ -166,6 +166,9 @@ pub fn checked_sub(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> Pr
 /// -----------------
 /// 100.0000 -> 7, 4
 /// ```
+/// # Errors
+/// Errors if the arrays are not decimals, have different lengths or if any
+/// non-null result overflows `i128`.
 /// # Examples
 /// ```
 /// use arrow2::compute::arithmetics::decimal::adaptive_sub;
@@ -203,18 +206,22 @@ pub fn adaptive_sub(
     let shift = 10i128.pow(diff as u32);
     let mut max = max_value(res_p);
 
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
     let values = lhs
         .values()
         .iter()
         .zip(rhs.values().iter())
-        .map(|(l, r)| {
+        .enumerate()
+        .map(|(index, (l, r))| {
             // Based on the array's scales one of the arguments in the sum has to be shifted
             // to the left to match the final scale
-            let res: i128 = if lhs_s > rhs_s {
-                l - r * shift
+            let res = if lhs_s > rhs_s {
+                r.checked_mul(shift).and_then(|r| l.checked_sub(r))
             } else {
-                l * shift - r
+                l.checked_mul(shift).and_then(|l| l.checked_sub(*r))
             };
+            let res = checked_or_null(res, validity.as_ref(), index)?;
 
             // The precision of the resulting array will change if one of the
             // subtraction during the iteration produces a value bigger than the
@@ -228,12 +235,9 @@ pub fn adaptive_sub(
                 res_p = number_digits(res);
                 max = max_value(res_p);
             }
-
-            res
+            Ok(res)
         })
-        .collect::<Vec<_>>();
-
-    let validity = combine_validities(lhs.validity(), rhs.validity());
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(PrimitiveArray::<i128>::new(
         DataType::Decimal(res_p, res_s),
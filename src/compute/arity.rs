@@ -18,6 +18,16 @@ use crate::{
 /// This will apply the function for all values, including those on null slots.
 /// This implies that the operation must be infallible for any value of the
 /// corresponding type or this function may panic.
+/// # Example
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::arity::unary;
+/// use arrow2::datatypes::DataType;
+///
+/// let array = PrimitiveArray::<i32>::from([Some(1), None, Some(3)]);
+/// let result = unary(&array, |x| x as f64 / 2.0, DataType::Float64);
+/// assert_eq!(result, PrimitiveArray::<f64>::from([Some(0.5), None, Some(1.5)]));
+/// ```
 #[inline]
 pub fn unary<I, F, O>(array: &PrimitiveArray<I>, op: F, data_type: DataType) -> PrimitiveArray<O>
 where
@@ -30,8 +40,51 @@ where
     PrimitiveArray::<O>::new(data_type, values.into(), array.validity().cloned())
 }
 
+/// Version of unary whose closure may fail. The slots on which `op` errors
+/// become null; use [`try_unary`] to instead return the error.
+/// # Example
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::arity::unary_null_on_error;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::error::Error;
+///
+/// let array = PrimitiveArray::<i64>::from([Some(1), None, Some(-3)]);
+/// let op = |x: i64| u32::try_from(x).map_err(|_| Error::Overflow);
+///
+/// let result = unary_null_on_error(&array, op, DataType::UInt32);
+/// assert_eq!(result, PrimitiveArray::<u32>::from([Some(1), None, None]));
+/// ```
+pub fn unary_null_on_error<I, F, O>(
+    array: &PrimitiveArray<I>,
+    op: F,
+    data_type: DataType,
+) -> PrimitiveArray<O>
+where
+    I: NativeType,
+    O: NativeType,
+    F: Fn(I) -> Result<O>,
+{
+    unary_checked(array, |x| op(x).ok(), data_type)
+}
+
 /// Version of unary that checks for errors in the closure used to create the
-/// buffer
+/// buffer. Errors on null slots are ignored. Use [`unary_null_on_error`] to
+/// instead convert the failing values to nulls.
+/// # Errors
+/// Iff `op` errors on a non-null value.
+/// # Example
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::arity::try_unary;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::error::Error;
+///
+/// let array = PrimitiveArray::<i64>::from([Some(1), None, Some(-3)]);
+/// let op = |x: i64| u32::try_from(x).map_err(|_| Error::Overflow);
+///
+/// assert!(try_unary(&array, op, DataType::UInt32).is_err());
+/// ```
 pub fn try_unary<I, F, O>(
     array: &PrimitiveArray<I>,
     op: F,
@@ -45,7 +98,13 @@ where
     let values = array
         .values()
         .iter()
-        .map(|v| op(*v))
+        .enumerate()
+        .map(|(index, v)| {
+            op(*v).or_else(|error| match array.validity() {
+                Some(validity) if !validity.get_bit(index) => Ok(O::default()),
+                _ => Err(error),
+            })
+        })
         .collect::<Result<Vec<_>>>()?
         .into();
 
@@ -131,8 +190,10 @@ where
 /// Applies a binary operations to two primitive arrays. This is the fastest
 /// way to perform an operation on two primitive array when the benefits of a
 /// vectorized operation outweighs the cost of branching nulls and non-nulls.
-/// # Errors
-/// This function errors iff the arrays have a different length.
+/// The validity of the result is the intersection of the validities of `lhs` and `rhs`.
+/// # Panics
+/// This function panics iff the arrays have a different length. Use [`try_binary`]
+/// to instead return an error.
 /// # Implementation
 /// This will apply the function for all values, including those on null slots.
 /// This implies that the operation must be infallible for any value of the
@@ -141,17 +202,29 @@ where
 /// "op" needs to handle the different types in the arrays. The datatype for the
 /// resulting array has to be selected by the implementer of the function as
 /// an argument for the function.
+/// # Example
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::arity::binary;
+/// use arrow2::datatypes::DataType;
+///
+/// let a = PrimitiveArray::<i32>::from([Some(1), Some(2), None]);
+/// let b = PrimitiveArray::<i64>::from([Some(10), None, Some(30)]);
+/// let result = binary(&a, &b, DataType::Int64, |a, b| a as i64 * 2 + b);
+/// assert_eq!(result, PrimitiveArray::<i64>::from([Some(12), None, None]));
+/// ```
 #[inline]
-pub fn binary<T, D, F>(
+pub fn binary<T, D, F, O>(
     lhs: &PrimitiveArray<T>,
     rhs: &PrimitiveArray<D>,
     data_type: DataType,
     op: F,
-) -> PrimitiveArray<T>
+) -> PrimitiveArray<O>
 where
     T: NativeType,
     D: NativeType,
-    F: Fn(T, D) -> T,
+    O: NativeType,
+    F: Fn(T, D) -> O,
 {
     check_same_len(lhs, rhs).unwrap();
 
@@ -165,21 +238,38 @@ where
         .collect::<Vec<_>>()
         .into();
 
-    PrimitiveArray::<T>::new(data_type, values, validity)
+    PrimitiveArray::<O>::new(data_type, values, validity)
 }
 
 /// Version of binary that checks for errors in the closure used to create the
 /// buffer
-pub fn try_binary<T, D, F>(
+/// # Errors
+/// This function errors iff the arrays have a different length or `op` errors.
+/// # Example
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::arity::try_binary;
+/// use arrow2::datatypes::DataType;
+///
+/// let a = PrimitiveArray::<i32>::from([Some(1), Some(2), None]);
+/// let b = PrimitiveArray::<i64>::from([Some(10), None, Some(30)]);
+/// let result = try_binary(&a, &b, DataType::Int64, |a, b| Ok(a as i64 * 2 + b));
+/// assert_eq!(result.unwrap(), PrimitiveArray::<i64>::from([Some(12), None, None]));
+///
+/// let b = PrimitiveArray::<i64>::from_slice([10]);
+/// assert!(try_binary(&a, &b, DataType::Int64, |a, b| Ok(a as i64 * 2 + b)).is_err());
+/// ```
+pub fn try_binary<T, D, F, O>(
     lhs: &PrimitiveArray<T>,
     rhs: &PrimitiveArray<D>,
     data_type: DataType,
     op: F,
-) -> Result<PrimitiveArray<T>>
+) -> Result<PrimitiveArray<O>>
 where
     T: NativeType,
     D: NativeType,
-    F: Fn(T, D) -> Result<T>,
+    O: NativeType,
+    F: Fn(T, D) -> Result<O>,
 {
     check_same_len(lhs, rhs)?;
 
@@ -193,7 +283,7 @@ where
         .collect::<Result<Vec<_>>>()?
         .into();
 
-    Ok(PrimitiveArray::<T>::new(data_type, values, validity))
+    Ok(PrimitiveArray::<O>::new(data_type, values, validity))
 }
 
 /// Version of binary that returns an array and bitmap. Used when working with
@@ -0,0 +1,308 @@
+//! Contains [`BloomFilter`] and [`bloom_filter`], to probabilistically test whether values
+//! belong to a column (e.g. to pre-filter a join).
+use siphasher::sip::SipHasher13;
+
+use crate::{
+    array::{Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array},
+    bitmap::Bitmap,
+    datatypes::{DataType, PhysicalType, PrimitiveType},
+    error::{Error, Result},
+    offset::Offset,
+    types::{days_ms, i256, months_days_ns, NativeType},
+};
+
+/// A [Bloom filter](https://en.wikipedia.org/wiki/Bloom_filter) over the hash of values.
+///
+/// Testing whether a value was inserted has no false negatives and a configurable rate of
+/// false positives.
+///
+/// Values are hashed with SipHash-1-3 keyed with zeros over their little-endian bytes, so that
+/// the filter (and its serialization) does not depend on the platform nor on the version of
+/// this crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BloomFilter {
+    words: Vec<u64>,
+    num_bits: u64,
+    num_hashes: u32,
+}
+
+impl BloomFilter {
+    /// Returns an empty [`BloomFilter`] sized for `num_values` values with a false positive rate
+    /// of at most `fp_rate`.
+    /// # Errors
+    /// This function errors iff `fp_rate` is not in the open interval `(0, 1)`.
+    pub fn try_new(num_values: usize, fp_rate: f64) -> Result<Self> {
+        if !(fp_rate > 0.0 && fp_rate < 1.0) {
+            return Err(Error::InvalidArgumentError(format!(
+                "The false positive rate of a BloomFilter must be between 0 and 1, but it is {fp_rate}"
+            )));
+        }
+        let num_values = num_values.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let num_bits = (-num_values * fp_rate.ln() / (ln2 * ln2)).ceil().max(64.0) as u64;
+        let num_hashes = ((num_bits as f64 / num_values) * ln2).round().max(1.0) as u32;
+
+        Ok(Self {
+            words: vec![0; num_words(num_bits) as usize],
+            num_bits,
+            num_hashes,
+        })
+    }
+
+    /// Inserts a value by its hash.
+    #[inline]
+    pub fn insert_hash(&mut self, hash: u64) {
+        bits(hash, self.num_hashes, self.num_bits)
+            .for_each(|bit| self.words[(bit / 64) as usize] |= 1 << (bit % 64));
+    }
+
+    /// Returns whether a value with `hash` may have been inserted.
+    #[inline]
+    pub fn contains_hash(&self, hash: u64) -> bool {
+        bits(hash, self.num_hashes, self.num_bits)
+            .all(|bit| self.words[(bit / 64) as usize] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Inserts a value.
+    #[inline]
+    pub fn insert<T: BloomHash + ?Sized>(&mut self, value: &T) {
+        self.insert_hash(value.bloom_hash())
+    }
+
+    /// Returns whether `value` may have been inserted, e.g. by [`bloom_filter`].
+    /// # Example
+    /// ```
+    /// use arrow2::array::Utf8Array;
+    /// use arrow2::compute::bloom_filter::bloom_filter;
+    ///
+    /// let filter = bloom_filter(&Utf8Array::<i32>::from_slice(["a", "b"]), 0.01).unwrap();
+    /// assert!(filter.contains("a"));
+    /// ```
+    #[inline]
+    pub fn contains<T: BloomHash + ?Sized>(&self, value: &T) -> bool {
+        self.contains_hash(value.bloom_hash())
+    }
+
+    /// Returns whether each value of `array` may have been inserted. Validity is preserved.
+    /// # Errors
+    /// This function errors iff the data type of `array` is not supported by [`bloom_filter`].
+    pub fn probe(&self, array: &dyn Array) -> Result<BooleanArray> {
+        let hashes = hash(array)?;
+        let values =
+            Bitmap::from_trusted_len_iter(hashes.into_iter().map(|hash| self.contains_hash(hash)));
+        Ok(BooleanArray::new(
+            DataType::Boolean,
+            values,
+            array.validity().cloned(),
+        ))
+    }
+
+    /// Returns the number of bits of this [`BloomFilter`].
+    pub fn num_bits(&self) -> u64 {
+        self.num_bits
+    }
+
+    /// Returns the number of hashes of this [`BloomFilter`].
+    pub fn num_hashes(&self) -> u32 {
+        self.num_hashes
+    }
+
+    /// Serializes this [`BloomFilter`]: the number of hashes (`u32`), the number of bits
+    /// (`u64`) and the bits (`u64` words), all in little endian. The bytes only depend on the
+    /// inserted values, not on the platform.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(12 + self.words.len() * 8);
+        bytes.extend_from_slice(&self.num_hashes.to_le_bytes());
+        bytes.extend_from_slice(&self.num_bits.to_le_bytes());
+        self.words
+            .iter()
+            .for_each(|word| bytes.extend_from_slice(&word.to_le_bytes()));
+        bytes
+    }
+
+    /// Deserializes a [`BloomFilter`] serialized by [`BloomFilter::to_bytes`].
+    /// # Errors
+    /// This function errors iff `bytes` is not a valid serialized [`BloomFilter`].
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < 12 {
+            return Err(Error::oos(
+                "A serialized BloomFilter must have at least 12 bytes",
+            ));
+        }
+        let num_hashes = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+        let num_bits = u64::from_le_bytes(bytes[4..12].try_into().unwrap());
+        let words = bytes[12..]
+            .chunks_exact(8)
+            .map(|word| u64::from_le_bytes(word.try_into().unwrap()))
+            .collect::<Vec<_>>();
+
+        if num_hashes == 0
+            || num_bits == 0
+            || bytes.len() != 12 + words.len() * 8
+            || words.len() as u64 != num_words(num_bits)
+        {
+            return Err(Error::oos(
+                "The serialized BloomFilter is inconsistent with its number of bits",
+            ));
+        }
+        Ok(Self {
+            words,
+            num_bits,
+            num_hashes,
+        })
+    }
+}
+
+/// The number of `u64` words holding `num_bits` bits.
+#[inline]
+fn num_words(num_bits: u64) -> u64 {
+    num_bits / 64 + (num_bits % 64 != 0) as u64
+}
+
+/// The indices of the `num_hashes` bits of `hash`, via double hashing.
+#[inline]
+fn bits(hash: u64, num_hashes: u32, num_bits: u64) -> impl Iterator<Item = u64> {
+    let h1 = hash & 0xFFFF_FFFF;
+    let h2 = (hash >> 32) | 1;
+    (0..num_hashes as u64).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % num_bits)
+}
+
+#[inline]
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    SipHasher13::new_with_keys(0, 0).hash(bytes)
+}
+
+/// A value that can be inserted in and tested against a [`BloomFilter`].
+///
+/// The hash of a value equals the hash of the same value in an array passed to
+/// [`bloom_filter`] or [`BloomFilter::probe`] (e.g. `"a"` and a [`Utf8Array`] slot `"a"`).
+pub trait BloomHash {
+    /// The hash of this value.
+    fn bloom_hash(&self) -> u64;
+}
+
+macro_rules! impl_bloom_hash {
+    ($($type:ty),*) => {$(
+        impl BloomHash for $type {
+            #[inline]
+            fn bloom_hash(&self) -> u64 {
+                hash_bytes(self.to_le_bytes().as_ref())
+            }
+        }
+    )*};
+}
+
+impl_bloom_hash!(
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    i256,
+    u8,
+    u16,
+    u32,
+    u64,
+    days_ms,
+    months_days_ns
+);
+
+impl BloomHash for bool {
+    #[inline]
+    fn bloom_hash(&self) -> u64 {
+        hash_bytes(&[*self as u8])
+    }
+}
+
+impl BloomHash for str {
+    #[inline]
+    fn bloom_hash(&self) -> u64 {
+        hash_bytes(self.as_bytes())
+    }
+}
+
+impl BloomHash for [u8] {
+    #[inline]
+    fn bloom_hash(&self) -> u64 {
+        hash_bytes(self)
+    }
+}
+
+fn hash_primitive<T: NativeType>(array: &PrimitiveArray<T>) -> Vec<u64> {
+    array
+        .values()
+        .iter()
+        .map(|x| hash_bytes(x.to_le_bytes().as_ref()))
+        .collect()
+}
+
+fn hash_utf8<O: Offset>(array: &Utf8Array<O>) -> Vec<u64> {
+    array.values_iter().map(|x| x.bloom_hash()).collect()
+}
+
+fn hash_binary<O: Offset>(array: &BinaryArray<O>) -> Vec<u64> {
+    array.values_iter().map(|x| x.bloom_hash()).collect()
+}
+
+/// The hash of every slot of `array`, including null slots.
+fn hash(array: &dyn Array) -> Result<Vec<u64>> {
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            array.values_iter().map(|x| x.bloom_hash()).collect()
+        }
+        Primitive(PrimitiveType::Float16 | PrimitiveType::Float32 | PrimitiveType::Float64) => {
+            return Err(Error::NotYetImplemented(format!(
+                "BloomFilter not implemented for type {:?}",
+                array.data_type()
+            )))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            hash_primitive::<$T>(array.as_any().downcast_ref().unwrap())
+        }),
+        Binary => hash_binary::<i32>(array.as_any().downcast_ref().unwrap()),
+        LargeBinary => hash_binary::<i64>(array.as_any().downcast_ref().unwrap()),
+        Utf8 => hash_utf8::<i32>(array.as_any().downcast_ref().unwrap()),
+        LargeUtf8 => hash_utf8::<i64>(array.as_any().downcast_ref().unwrap()),
+        t => {
+            return Err(Error::NotYetImplemented(format!(
+                "BloomFilter not implemented for type {t:?}"
+            )))
+        }
+    })
+}
+
+/// Returns a [`BloomFilter`] containing the non-null values of `array`, with a false
+/// positive rate of at most `fp_rate`.
+///
+/// Supported DataTypes:
+/// * Boolean types
+/// * All primitive types except `Float16`, `Float32` and `Float64`
+/// * `[Large]Utf8`;
+/// * `[Large]Binary`.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Utf8Array};
+/// use arrow2::compute::bloom_filter::bloom_filter;
+///
+/// let build = Utf8Array::<i32>::from_slice(["a", "b"]);
+/// let filter = bloom_filter(&build, 0.01).unwrap();
+///
+/// let probe = Utf8Array::<i32>::from([Some("b"), None]);
+/// assert_eq!(filter.probe(&probe).unwrap(), BooleanArray::from([Some(true), None]));
+/// ```
+/// # Errors
+/// This function errors iff
+/// * `fp_rate` is not in the open interval `(0, 1)`
+/// * the data type of `array` is not supported
+pub fn bloom_filter(array: &dyn Array, fp_rate: f64) -> Result<BloomFilter> {
+    let hashes = hash(array)?;
+    let mut filter = BloomFilter::try_new(array.len() - array.null_count(), fp_rate)?;
+    hashes
+        .into_iter()
+        .enumerate()
+        .filter(|(row, _)| array.is_valid(*row))
+        .for_each(|(_, hash)| filter.insert_hash(hash));
+    Ok(filter)
+}
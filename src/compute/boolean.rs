@@ -253,3 +253,32 @@ pub fn all(array: &BooleanArray) -> bool {
         vals.unset_bits() == 0
     }
 }
+
+/// Returns the number of valid slots of the array that are `true`.
+///
+/// Nulls are counted neither as `true` nor as `false`.
+pub fn true_count(array: &BooleanArray) -> usize {
+    let values = array.values();
+    match array.validity() {
+        None => values.len() - values.unset_bits(),
+        Some(validity) => {
+            let values = values.chunks::<u64>();
+            let validity = validity.chunks::<u64>();
+            // the bits past the length of the remainder are undefined
+            let remainder_mask = (1u64 << values.remainder_len()) - 1;
+            let remainder = values.remainder() & validity.remainder() & remainder_mask;
+            values
+                .zip(validity)
+                .map(|(values, validity)| (values & validity).count_ones() as usize)
+                .sum::<usize>()
+                + remainder.count_ones() as usize
+        }
+    }
+}
+
+/// Returns the number of valid slots of the array that are `false`.
+///
+/// Nulls are counted neither as `true` nor as `false`.
+pub fn false_count(array: &BooleanArray) -> usize {
+    array.len() - array.null_count() - true_count(array)
+}
@@ -1,4 +1,5 @@
-use crate::error::Result;
+use crate::bitmap::Bitmap;
+use crate::error::{Error, Result};
 use crate::offset::{Offset, Offsets};
 use crate::{array::*, datatypes::DataType, types::NativeType};
 
@@ -31,6 +32,11 @@ pub fn binary_large_to_binary(
 }
 
 /// Conversion to utf8
+/// # Errors
+/// This function errors if the values are not valid utf8. The error contains the
+/// index of the first invalid value.
+/// # Implementation
+/// When the values are valid utf8, the buffers are shared.
 pub fn binary_to_utf8<O: Offset>(
     from: &BinaryArray<O>,
     to_data_type: DataType,
@@ -41,19 +47,59 @@ pub fn binary_to_utf8<O: Offset>(
         from.values().clone(),
         from.validity().cloned(),
     )
+    .map_err(|error| {
+        from.values_iter()
+            .position(|x| simdutf8::basic::from_utf8(x).is_err())
+            .map(|index| {
+                Error::InvalidArgumentError(format!("The value at index {index} is not valid utf8"))
+            })
+            .unwrap_or(error)
+    })
+}
+
+/// Conversion to utf8, converting values that are not valid utf8 to nulls.
+/// # Implementation
+/// When all values are valid utf8, the buffers are shared.
+pub fn binary_to_utf8_or_null<O: Offset>(
+    from: &BinaryArray<O>,
+    to_data_type: DataType,
+) -> Utf8Array<O> {
+    if let Ok(array) = binary_to_utf8(from, to_data_type.clone()) {
+        return array;
+    }
+
+    let iter = from
+        .iter()
+        .map(|x| x.and_then(|x| simdutf8::basic::from_utf8(x).ok()));
+    let (_, offsets, values, validity) = Utf8Array::<O>::from_trusted_len_iter(iter).into_inner();
+
+    // Safety: the values were validated above
+    unsafe { Utf8Array::<O>::new_unchecked(to_data_type, offsets, values, validity) }
 }
 
 /// Conversion to utf8
 /// # Errors
-/// This function errors if the values are not valid utf8
+/// This function errors if the values are not valid utf8. The error contains the
+/// index of the first invalid value.
 pub fn binary_to_large_utf8(
     from: &BinaryArray<i32>,
     to_data_type: DataType,
 ) -> Result<Utf8Array<i64>> {
-    let values = from.values().clone();
-    let offsets = from.offsets().into();
+    let from = binary_to_large_binary(from, DataType::LargeBinary);
+    binary_to_utf8(&from, to_data_type)
+}
 
-    Utf8Array::<i64>::try_new(to_data_type, offsets, values, from.validity().cloned())
+pub(super) fn binary_to_utf8_dyn<O: Offset>(
+    from: &dyn Array,
+    to_data_type: DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    if options.null_on_invalid_utf8 {
+        Ok(binary_to_utf8_or_null::<O>(from, to_data_type).boxed())
+    } else {
+        binary_to_utf8::<O>(from, to_data_type).map(|x| x.boxed())
+    }
 }
 
 /// Casts a [`BinaryArray`] to a [`PrimitiveArray`] at best-effort using `lexical_core::parse_partial`, making any uncastable value as zero.
@@ -145,6 +191,52 @@ pub fn fixed_size_binary_binary<O: Offset>(
     )
 }
 
+/// Conversion of `Binary` to `FixedSizeBinary(size)`. Items that are not `size` bytes long
+/// are cast to null.
+///
+/// This is `O(1)` when every item is valid and `size` bytes long, as the values are reused.
+/// # Errors
+/// This function errors iff `size` is zero.
+pub fn binary_to_fixed_size_binary<O: Offset>(
+    from: &BinaryArray<O>,
+    size: usize,
+) -> Result<FixedSizeBinaryArray> {
+    let data_type = DataType::FixedSizeBinary(size);
+    let offsets = from.offsets();
+    let has_size = |window: &[O]| (window[1] - window[0]).to_usize() == size;
+
+    if from.null_count() == 0 && offsets.buffer().windows(2).all(has_size) {
+        let start = offsets.first().to_usize();
+        let values = from.values().clone().sliced(start, from.len() * size);
+        return FixedSizeBinaryArray::try_new(data_type, values, None);
+    }
+
+    let mut values = Vec::<u8>::with_capacity(from.len() * size);
+    let validity = from
+        .iter()
+        .map(|value| match value {
+            Some(value) if value.len() == size => {
+                values.extend_from_slice(value);
+                true
+            }
+            _ => {
+                values.extend(std::iter::repeat(0).take(size));
+                false
+            }
+        })
+        .collect::<Bitmap>();
+
+    FixedSizeBinaryArray::try_new(data_type, values.into(), validity.into())
+}
+
+pub(super) fn binary_to_fixed_size_binary_dyn<O: Offset>(
+    from: &dyn Array,
+    size: usize,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    binary_to_fixed_size_binary::<O>(from, size).map(|x| x.boxed())
+}
+
 /// Conversion of binary
 pub fn binary_to_list<O: Offset>(from: &BinaryArray<O>, to_data_type: DataType) -> ListArray<O> {
     let values = from.values().clone();
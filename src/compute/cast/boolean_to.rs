@@ -13,7 +13,8 @@ where
     Ok(Box::new(boolean_to_primitive::<T>(array)))
 }
 
-/// Casts the [`BooleanArray`] to a [`PrimitiveArray`].
+/// Casts the [`BooleanArray`] to a [`PrimitiveArray`], casting trues to `1` and falses to `0`.
+/// Validity is preserved.
 pub fn boolean_to_primitive<T>(from: &BooleanArray) -> PrimitiveArray<T>
 where
     T: NativeType + num_traits::One,
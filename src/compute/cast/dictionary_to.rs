@@ -57,7 +57,7 @@ pub fn wrapping_dictionary_to_dictionary_values<K: DictionaryKey>(
         values_type,
         CastOptions {
             wrapped: true,
-            partial: false,
+            ..Default::default()
         },
     )?;
     assert_eq!(values.len(), length); // this is guaranteed by `cast`
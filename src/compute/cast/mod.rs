@@ -21,6 +21,20 @@ use crate::{
     offset::{Offset, Offsets},
 };
 
+/// Defines what a numeric cast does with values that do not fit in the target type,
+/// e.g. `300i32` cast to `i8`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OverflowMode {
+    /// Overflowing values are converted to `None`
+    #[default]
+    SetNull,
+    /// The cast errors on the first overflowing value
+    Error,
+    /// Overflowing values are clamped to the minimum or maximum of the target type,
+    /// (e.g. `300i32` becomes `127i8`). `NaN` is converted to `None`.
+    Saturate,
+}
+
 /// options defining how Cast kernels behave
 #[derive(Clone, Copy, Debug, Default)]
 pub struct CastOptions {
@@ -31,6 +45,15 @@ pub struct CastOptions {
     /// default to false
     /// whether to cast to an integer at the best-effort
     pub partial: bool,
+    /// default to false
+    /// whether a cast from binary to utf8 should convert values that are not valid utf8 to
+    /// `None` (`true`), or error (default).
+    pub null_on_invalid_utf8: bool,
+    /// default to [`OverflowMode::SetNull`]
+    /// how integer-narrowing, float-to-integer and integer-to-decimal casts handle
+    /// overflowing values (`Saturate` behaves as `SetNull` for decimals).
+    /// Ignored when `wrapped` is `true`.
+    pub overflow: OverflowMode,
 }
 
 impl CastOptions {
@@ -123,24 +146,34 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             is_numeric(to_type)
                 || matches!(
                     to_type,
-                    LargeUtf8 | Binary | Date32 | Date64 | Timestamp(TimeUnit::Nanosecond, _)
+                    LargeUtf8
+                        | Binary
+                        | LargeBinary
+                        | Date32
+                        | Date64
+                        | Timestamp(TimeUnit::Nanosecond, _)
                 )
         }
         (LargeUtf8, to_type) => {
             is_numeric(to_type)
                 || matches!(
                     to_type,
-                    Utf8 | LargeBinary | Date32 | Date64 | Timestamp(TimeUnit::Nanosecond, _)
+                    Utf8 | Binary
+                        | LargeBinary
+                        | Date32
+                        | Date64
+                        | Timestamp(TimeUnit::Nanosecond, _)
                 )
         }
 
         (Binary, to_type) => {
-            is_numeric(to_type) || matches!(to_type, LargeBinary | Utf8 | LargeUtf8)
+            is_numeric(to_type)
+                || matches!(to_type, LargeBinary | Utf8 | LargeUtf8 | FixedSizeBinary(_))
         }
         (LargeBinary, to_type) => {
             is_numeric(to_type)
                 || match to_type {
-                    Binary | LargeUtf8 => true,
+                    Binary | Utf8 | LargeUtf8 | FixedSizeBinary(_) => true,
                     LargeList(field) => matches!(field.data_type, UInt8),
                     _ => false,
                 }
@@ -148,8 +181,8 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (FixedSizeBinary(_), to_type) => matches!(to_type, Binary | LargeBinary),
         (Timestamp(_, _), Utf8) => true,
         (Timestamp(_, _), LargeUtf8) => true,
-        (_, Utf8) => is_numeric(from_type) || from_type == &Binary,
-        (_, LargeUtf8) => is_numeric(from_type) || from_type == &LargeBinary,
+        (_, Utf8) => is_numeric(from_type) || matches!(from_type, Binary | LargeBinary),
+        (_, LargeUtf8) => is_numeric(from_type) || matches!(from_type, Binary | LargeBinary),
 
         (_, Binary) => is_numeric(from_type),
         (_, LargeBinary) => is_numeric(from_type),
@@ -389,44 +422,80 @@ fn cast_list_to_fixed_size_list<O: Offset>(
     size: usize,
     options: CastOptions,
 ) -> Result<FixedSizeListArray> {
-    let offsets = list.offsets().buffer().iter();
-    let expected = (0..list.len()).map(|ix| O::from_as_usize(ix * size));
-
-    match offsets
-        .zip(expected)
-        .find(|(actual, expected)| *actual != expected)
-    {
-        Some(_) => Err(Error::InvalidArgumentError(
-            "incompatible offsets in source list".to_string(),
-        )),
-        None => {
-            let new_values = cast(list.values().as_ref(), inner.data_type(), options)?;
-            Ok(FixedSizeListArray::new(
-                DataType::FixedSizeList(Box::new(inner.clone()), size),
-                new_values,
-                list.validity().cloned(),
-            ))
+    let lengths = list
+        .offsets()
+        .buffer()
+        .windows(2)
+        .map(|window| (window[1] - window[0]).to_usize());
+
+    // only valid lists must be of `size`: null lists of any other length are filled with nulls
+    let mut all_of_size = true;
+    for (row, length) in lengths.clone().enumerate() {
+        if length != size {
+            if list.is_valid(row) {
+                return Err(Error::InvalidArgumentError(format!(
+                    "incompatible offsets in source list: the list at position {row} has {length} elements but the fixed size is {size}"
+                )));
+            }
+            all_of_size = false;
         }
     }
+
+    let values = if all_of_size {
+        let start = list.offsets().first().to_usize();
+        list.values().sliced(start, list.len() * size)
+    } else {
+        let values = list.values().as_ref();
+        let mut growable = growable::make_growable(&[values], true, list.len() * size);
+        list.offsets()
+            .buffer()
+            .iter()
+            .zip(lengths)
+            .for_each(|(start, length)| {
+                if length == size {
+                    growable.extend(0, start.to_usize(), size)
+                } else {
+                    growable.extend_validity(size)
+                }
+            });
+        growable.as_box()
+    };
+
+    let new_values = cast(values.as_ref(), inner.data_type(), options)?;
+    FixedSizeListArray::try_new(
+        DataType::FixedSizeList(Box::new(inner.clone()), size),
+        new_values,
+        list.validity().cloned(),
+    )
 }
 
 /// Cast `array` to the provided data type and return a new [`Array`] with
 /// type `to_type`, if possible.
 ///
 /// Behavior:
+/// * Any type to itself: a shallow clone of `array`, that shares its buffers
+/// * Null to any type (including struct): an array of the same length whose slots are all null
+/// * Any type to Null: a [`NullArray`](crate::array::NullArray) of the same length
 /// * PrimitiveArray to PrimitiveArray: overflowing cast will be None
+/// * Boolean to numeric: `true` => `1`, `false` => `0`
 /// * Boolean to Utf8: `true` => '1', `false` => `0`
 /// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
 /// * List to List: the underlying data type is cast
 /// * Fixed Size List to List: the underlying data type is cast
-/// * List to Fixed Size List: the offsets are checked for valid order, then the
+/// * List to Fixed Size List: every non-null list must have the fixed size, then the
 ///   underlying type is cast.
 /// * PrimitiveArray to List: a list array with 1 value per slot is created
+/// * Binary to FixedSizeBinary: items whose length differs from the size return null
+/// * Binary to Utf8: errors on values that are not valid utf8, unless
+///   [`CastOptions::null_on_invalid_utf8`] is set. Note that casts from `Binary` to `Utf8`
+///   converted such values to null by default before this option was introduced.
 /// * Date32 and Date64: precision lost when going to higher interval
-/// * Time32 and Time64: precision lost when going to higher interval
-/// * Timestamp and Date{32|64}: precision lost when going to higher interval
+/// * Time32 and Time64: rescaled between units, with precision lost when going to higher
+///   interval. Errors when a value overflows `i32` in the target `Time32` unit.
+/// * Timestamp to Timestamp of the same unit: zero-copy, only the timezone is relabeled
+/// * Timestamp to Date{32|64}: truncated to the (local, when the timestamp has a timezone) day
 /// * Temporal to/from backing primitive: zero-copy with data type change
 /// Unsupported Casts
 /// * To or from `StructArray`
@@ -567,6 +636,11 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
                 to_type.clone(),
             )
             .boxed()),
+            LargeBinary => Ok(utf8_to_binary::<i64>(
+                &utf8_to_large_utf8(array.as_any().downcast_ref().unwrap()),
+                to_type.clone(),
+            )
+            .boxed()),
             Timestamp(TimeUnit::Nanosecond, None) => utf8_to_naive_timestamp_ns_dyn::<i32>(array),
             Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
                 utf8_to_timestamp_ns_dyn::<i32>(array, tz.clone())
@@ -589,6 +663,11 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Date32 => utf8_to_date32_dyn::<i64>(array),
             Date64 => utf8_to_date64_dyn::<i64>(array),
             Utf8 => utf8_large_to_utf8(array.as_any().downcast_ref().unwrap()).map(|x| x.boxed()),
+            Binary => Ok(utf8_to_binary::<i32>(
+                &utf8_large_to_utf8(array.as_any().downcast_ref().unwrap())?,
+                to_type.clone(),
+            )
+            .boxed()),
             LargeBinary => Ok(utf8_to_binary::<i64>(
                 array.as_any().downcast_ref().unwrap(),
                 to_type.clone(),
@@ -614,16 +693,13 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_utf8_dyn::<i64, i32>(array),
             Float32 => primitive_to_utf8_dyn::<f32, i32>(array),
             Float64 => primitive_to_utf8_dyn::<f64, i32>(array),
-            Binary => {
-                let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
-
-                // perf todo: the offsets are equal; we can speed-up this
-                let iter = array
-                    .iter()
-                    .map(|x| x.and_then(|x| simdutf8::basic::from_utf8(x).ok()));
-
-                let array = Utf8Array::<i32>::from_trusted_len_iter(iter);
-                Ok(Box::new(array))
+            Binary => binary_to_utf8_dyn::<i32>(array, to_type.clone(), options),
+            LargeBinary => {
+                let array = binary_large_to_binary(
+                    array.as_any().downcast_ref().unwrap(),
+                    DataType::Binary,
+                )?;
+                binary_to_utf8_dyn::<i32>(&array, to_type.clone(), options)
             }
             Timestamp(from_unit, Some(tz)) => {
                 let from = array.as_any().downcast_ref().unwrap();
@@ -649,12 +725,14 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_utf8_dyn::<i64, i64>(array),
             Float32 => primitive_to_utf8_dyn::<f32, i64>(array),
             Float64 => primitive_to_utf8_dyn::<f64, i64>(array),
-            Binary => binary_to_large_utf8(array.as_any().downcast_ref().unwrap(), to_type.clone())
-                .map(|x| x.boxed()),
-            LargeBinary => {
-                binary_to_utf8::<i64>(array.as_any().downcast_ref().unwrap(), to_type.clone())
-                    .map(|x| x.boxed())
+            Binary => {
+                let array = binary_to_large_binary(
+                    array.as_any().downcast_ref().unwrap(),
+                    DataType::LargeBinary,
+                );
+                binary_to_utf8_dyn::<i64>(&array, to_type.clone(), options)
             }
+            LargeBinary => binary_to_utf8_dyn::<i64>(array, to_type.clone(), options),
             Timestamp(from_unit, Some(tz)) => {
                 let from = array.as_any().downcast_ref().unwrap();
                 Ok(Box::new(timestamp_to_utf8::<i64>(from, *from_unit, tz)?))
@@ -683,9 +761,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
                 array.as_any().downcast_ref().unwrap(),
                 to_type.clone(),
             ))),
-            Utf8 => binary_to_utf8::<i32>(array.as_any().downcast_ref().unwrap(), to_type.clone())
-                .map(|x| x.boxed()),
-
+            FixedSizeBinary(size) => binary_to_fixed_size_binary_dyn::<i32>(array, *size),
             _ => Err(Error::NotYetImplemented(format!(
                 "Casting from {from_type:?} to {to_type:?} not supported",
             ))),
@@ -707,14 +783,11 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
                     binary_large_to_binary(array.as_any().downcast_ref().unwrap(), to_type.clone())
                         .map(|x| x.boxed())
                 }
-                LargeUtf8 => {
-                    binary_to_utf8::<i64>(array.as_any().downcast_ref().unwrap(), to_type.clone())
-                        .map(|x| x.boxed())
-                }
                 LargeList(inner) if matches!(inner.data_type, DataType::UInt8) => Ok(
                     binary_to_list::<i64>(array.as_any().downcast_ref().unwrap(), to_type.clone())
                         .boxed(),
                 ),
+                FixedSizeBinary(size) => binary_to_fixed_size_binary_dyn::<i64>(array, *size),
                 _ => Err(Error::NotYetImplemented(format!(
                     "Casting from {from_type:?} to {to_type:?} not supported",
                 ))),
@@ -778,7 +851,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (UInt8, Int64) => primitive_to_primitive_dyn::<u8, i64>(array, to_type, options),
         (UInt8, Float32) => primitive_to_primitive_dyn::<u8, f32>(array, to_type, as_options),
         (UInt8, Float64) => primitive_to_primitive_dyn::<u8, f64>(array, to_type, as_options),
-        (UInt8, Decimal(p, s)) => integer_to_decimal_dyn::<u8>(array, *p, *s),
+        (UInt8, Decimal(p, s)) => integer_to_decimal_dyn::<u8>(array, *p, *s, options),
 
         (UInt16, UInt8) => primitive_to_primitive_dyn::<u16, u8>(array, to_type, options),
         (UInt16, UInt32) => primitive_to_primitive_dyn::<u16, u32>(array, to_type, as_options),
@@ -789,7 +862,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (UInt16, Int64) => primitive_to_primitive_dyn::<u16, i64>(array, to_type, options),
         (UInt16, Float32) => primitive_to_primitive_dyn::<u16, f32>(array, to_type, as_options),
         (UInt16, Float64) => primitive_to_primitive_dyn::<u16, f64>(array, to_type, as_options),
-        (UInt16, Decimal(p, s)) => integer_to_decimal_dyn::<u16>(array, *p, *s),
+        (UInt16, Decimal(p, s)) => integer_to_decimal_dyn::<u16>(array, *p, *s, options),
 
         (UInt32, UInt8) => primitive_to_primitive_dyn::<u32, u8>(array, to_type, options),
         (UInt32, UInt16) => primitive_to_primitive_dyn::<u32, u16>(array, to_type, options),
@@ -800,7 +873,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (UInt32, Int64) => primitive_to_primitive_dyn::<u32, i64>(array, to_type, options),
         (UInt32, Float32) => primitive_to_primitive_dyn::<u32, f32>(array, to_type, as_options),
         (UInt32, Float64) => primitive_to_primitive_dyn::<u32, f64>(array, to_type, as_options),
-        (UInt32, Decimal(p, s)) => integer_to_decimal_dyn::<u32>(array, *p, *s),
+        (UInt32, Decimal(p, s)) => integer_to_decimal_dyn::<u32>(array, *p, *s, options),
 
         (UInt64, UInt8) => primitive_to_primitive_dyn::<u64, u8>(array, to_type, options),
         (UInt64, UInt16) => primitive_to_primitive_dyn::<u64, u16>(array, to_type, options),
@@ -811,7 +884,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (UInt64, Int64) => primitive_to_primitive_dyn::<u64, i64>(array, to_type, options),
         (UInt64, Float32) => primitive_to_primitive_dyn::<u64, f32>(array, to_type, as_options),
         (UInt64, Float64) => primitive_to_primitive_dyn::<u64, f64>(array, to_type, as_options),
-        (UInt64, Decimal(p, s)) => integer_to_decimal_dyn::<u64>(array, *p, *s),
+        (UInt64, Decimal(p, s)) => integer_to_decimal_dyn::<u64>(array, *p, *s, options),
 
         (Int8, UInt8) => primitive_to_primitive_dyn::<i8, u8>(array, to_type, options),
         (Int8, UInt16) => primitive_to_primitive_dyn::<i8, u16>(array, to_type, options),
@@ -822,7 +895,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Int8, Int64) => primitive_to_primitive_dyn::<i8, i64>(array, to_type, as_options),
         (Int8, Float32) => primitive_to_primitive_dyn::<i8, f32>(array, to_type, as_options),
         (Int8, Float64) => primitive_to_primitive_dyn::<i8, f64>(array, to_type, as_options),
-        (Int8, Decimal(p, s)) => integer_to_decimal_dyn::<i8>(array, *p, *s),
+        (Int8, Decimal(p, s)) => integer_to_decimal_dyn::<i8>(array, *p, *s, options),
 
         (Int16, UInt8) => primitive_to_primitive_dyn::<i16, u8>(array, to_type, options),
         (Int16, UInt16) => primitive_to_primitive_dyn::<i16, u16>(array, to_type, options),
@@ -833,7 +906,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Int16, Int64) => primitive_to_primitive_dyn::<i16, i64>(array, to_type, as_options),
         (Int16, Float32) => primitive_to_primitive_dyn::<i16, f32>(array, to_type, as_options),
         (Int16, Float64) => primitive_to_primitive_dyn::<i16, f64>(array, to_type, as_options),
-        (Int16, Decimal(p, s)) => integer_to_decimal_dyn::<i16>(array, *p, *s),
+        (Int16, Decimal(p, s)) => integer_to_decimal_dyn::<i16>(array, *p, *s, options),
 
         (Int32, UInt8) => primitive_to_primitive_dyn::<i32, u8>(array, to_type, options),
         (Int32, UInt16) => primitive_to_primitive_dyn::<i32, u16>(array, to_type, options),
@@ -844,7 +917,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Int32, Int64) => primitive_to_primitive_dyn::<i32, i64>(array, to_type, as_options),
         (Int32, Float32) => primitive_to_primitive_dyn::<i32, f32>(array, to_type, as_options),
         (Int32, Float64) => primitive_to_primitive_dyn::<i32, f64>(array, to_type, as_options),
-        (Int32, Decimal(p, s)) => integer_to_decimal_dyn::<i32>(array, *p, *s),
+        (Int32, Decimal(p, s)) => integer_to_decimal_dyn::<i32>(array, *p, *s, options),
 
         (Int64, UInt8) => primitive_to_primitive_dyn::<i64, u8>(array, to_type, options),
         (Int64, UInt16) => primitive_to_primitive_dyn::<i64, u16>(array, to_type, options),
@@ -855,7 +928,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Int64, Int32) => primitive_to_primitive_dyn::<i64, i32>(array, to_type, options),
         (Int64, Float32) => primitive_to_primitive_dyn::<i64, f32>(array, to_type, options),
         (Int64, Float64) => primitive_to_primitive_dyn::<i64, f64>(array, to_type, as_options),
-        (Int64, Decimal(p, s)) => integer_to_decimal_dyn::<i64>(array, *p, *s),
+        (Int64, Decimal(p, s)) => integer_to_decimal_dyn::<i64>(array, *p, *s, options),
 
         (Float16, Float32) => {
             let from = array.as_any().downcast_ref().unwrap();
@@ -922,7 +995,8 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Date32, Date64) => primitive_dyn!(array, date32_to_date64),
         (Date64, Date32) => primitive_dyn!(array, date64_to_date32),
         (Time32(TimeUnit::Second), Time32(TimeUnit::Millisecond)) => {
-            primitive_dyn!(array, time32s_to_time32ms)
+            let from = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(try_time32s_to_time32ms(from)?))
         }
         (Time32(TimeUnit::Millisecond), Time32(TimeUnit::Second)) => {
             primitive_dyn!(array, time32ms_to_time32s)
@@ -937,15 +1011,28 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             primitive_dyn!(array, time64ns_to_time64us)
         }
         (Time64(from_unit), Time32(to_unit)) => {
-            primitive_dyn!(array, time64_to_time32, *from_unit, *to_unit)
+            let from = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(try_time64_to_time32(from, *from_unit, *to_unit)?))
         }
         (Timestamp(_, _), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Int64, Timestamp(_, _)) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Timestamp(from_unit, _), Timestamp(to_unit, tz)) => {
             primitive_dyn!(array, timestamp_to_timestamp, *from_unit, *to_unit, tz)
         }
-        (Timestamp(from_unit, _), Date32) => primitive_dyn!(array, timestamp_to_date32, *from_unit),
-        (Timestamp(from_unit, _), Date64) => primitive_dyn!(array, timestamp_to_date64, *from_unit),
+        (Timestamp(from_unit, None), Date32) => {
+            primitive_dyn!(array, timestamp_to_date32, *from_unit)
+        }
+        (Timestamp(from_unit, None), Date64) => {
+            primitive_dyn!(array, timestamp_to_date64, *from_unit)
+        }
+        (Timestamp(from_unit, Some(tz)), Date32) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(timestamp_to_date32_with_tz(from, *from_unit, tz)?))
+        }
+        (Timestamp(from_unit, Some(tz)), Date64) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(timestamp_to_date64_with_tz(from, *from_unit, tz)?))
+        }
 
         (Int64, Duration(_)) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Duration(_), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
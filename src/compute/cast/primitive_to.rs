@@ -3,19 +3,19 @@ use std::hash::Hash;
 use num_traits::{AsPrimitive, Float, ToPrimitive};
 
 use crate::datatypes::IntervalUnit;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::offset::{Offset, Offsets};
 use crate::types::{days_ms, f16, months_days_ns};
 use crate::{
     array::*,
     bitmap::Bitmap,
-    compute::arity::unary,
+    compute::arity::{try_unary, unary},
     datatypes::{DataType, TimeUnit},
     temporal_conversions::*,
     types::NativeType,
 };
 
-use super::CastOptions;
+use super::{CastOptions, OverflowMode};
 
 /// Returns a [`BinaryArray`] where every element is the binary representation of the number.
 pub fn primitive_to_binary<T: NativeType + lexical_core::ToLexical, O: Offset>(
@@ -137,13 +137,18 @@ pub(super) fn primitive_to_primitive_dyn<I, O>(
 ) -> Result<Box<dyn Array>>
 where
     I: NativeType + num_traits::NumCast + num_traits::AsPrimitive<O>,
-    O: NativeType + num_traits::NumCast,
+    O: NativeType + num_traits::NumCast + num_traits::Bounded,
 {
     let from = from.as_any().downcast_ref::<PrimitiveArray<I>>().unwrap();
     if options.wrapped {
-        Ok(Box::new(primitive_as_primitive::<I, O>(from, to_type)))
-    } else {
-        Ok(Box::new(primitive_to_primitive::<I, O>(from, to_type)))
+        return Ok(Box::new(primitive_as_primitive::<I, O>(from, to_type)));
+    }
+    match options.overflow {
+        OverflowMode::SetNull => Ok(Box::new(primitive_to_primitive::<I, O>(from, to_type))),
+        OverflowMode::Error => try_primitive_to_primitive::<I, O>(from, to_type).map(|x| x.boxed()),
+        OverflowMode::Saturate => Ok(Box::new(primitive_to_primitive_saturating::<I, O>(
+            from, to_type,
+        ))),
     }
 }
 
@@ -162,45 +167,153 @@ where
     PrimitiveArray::<O>::from_trusted_len_iter(iter).to(to_type.clone())
 }
 
-/// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
-pub fn integer_to_decimal<T: NativeType + AsPrimitive<i128>>(
-    from: &PrimitiveArray<T>,
-    to_precision: usize,
-    to_scale: usize,
-) -> PrimitiveArray<i128> {
-    let multiplier = 10_i128.pow(to_scale as u32);
-
-    let min_for_precision = 9_i128
-        .saturating_pow(1 + to_precision as u32)
-        .saturating_neg();
-    let max_for_precision = 9_i128.saturating_pow(1 + to_precision as u32);
+/// Cast [`PrimitiveArray`] to a [`PrimitiveArray`] of another physical type via numeric conversion.
+/// # Errors
+/// Iff a (non-null) value does not fit in `O`.
+pub fn try_primitive_to_primitive<I, O>(
+    from: &PrimitiveArray<I>,
+    to_type: &DataType,
+) -> Result<PrimitiveArray<O>>
+where
+    I: NativeType + num_traits::NumCast,
+    O: NativeType + num_traits::NumCast,
+{
+    let values = from
+        .values()
+        .iter()
+        .enumerate()
+        .map(|(index, x)| {
+            num_traits::cast::cast::<I, O>(*x).map_or_else(
+                || {
+                    if from.is_null(index) {
+                        Ok(O::default())
+                    } else {
+                        Err(Error::Overflow)
+                    }
+                },
+                Ok,
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(PrimitiveArray::<O>::new(
+        to_type.clone(),
+        values.into(),
+        from.validity().cloned(),
+    ))
+}
 
-    let values = from.iter().map(|x| {
-        x.and_then(|x| {
-            x.as_().checked_mul(multiplier).and_then(|x| {
-                if x > max_for_precision || x < min_for_precision {
-                    None
+/// Cast [`PrimitiveArray`] to a [`PrimitiveArray`] of another physical type via numeric conversion,
+/// clamping values that do not fit in `O` to its minimum or maximum. `NaN`s are converted to `None`.
+pub fn primitive_to_primitive_saturating<I, O>(
+    from: &PrimitiveArray<I>,
+    to_type: &DataType,
+) -> PrimitiveArray<O>
+where
+    I: NativeType + num_traits::NumCast,
+    O: NativeType + num_traits::NumCast + num_traits::Bounded,
+{
+    let iter = from.iter().map(|v| {
+        v.and_then(|x| {
+            num_traits::cast::cast::<I, O>(*x).or_else(|| {
+                // the sign of the value determines the bound it overflowed
+                let x = x.to_f64()?;
+                if x > 0.0 {
+                    Some(O::max_value())
+                } else if x < 0.0 {
+                    Some(O::min_value())
                 } else {
-                    Some(x)
+                    None
                 }
             })
         })
     });
+    PrimitiveArray::<O>::from_trusted_len_iter(iter).to(to_type.clone())
+}
+
+/// Returns the largest (unscaled) value of a decimal with `precision` digits, `10^precision - 1`
+fn max_for_precision(precision: usize) -> i128 {
+    u32::try_from(precision)
+        .ok()
+        .and_then(|precision| 10_i128.checked_pow(precision))
+        .map(|max| max - 1)
+        .unwrap_or(i128::MAX)
+}
+
+/// Returns the function casting an integer to a `Decimal(to_precision, to_scale)`, or `None` on overflow
+fn integer_to_decimal_op<T: NativeType + AsPrimitive<i128>>(
+    to_precision: usize,
+    to_scale: usize,
+) -> impl Fn(T) -> Option<i128> {
+    let multiplier = 10_i128.pow(to_scale as u32);
+
+    let max_for_precision = max_for_precision(to_precision);
+    let min_for_precision = -max_for_precision;
+
+    move |x: T| {
+        x.as_().checked_mul(multiplier).and_then(|x| {
+            if x > max_for_precision || x < min_for_precision {
+                None
+            } else {
+                Some(x)
+            }
+        })
+    }
+}
+
+/// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
+pub fn integer_to_decimal<T: NativeType + AsPrimitive<i128>>(
+    from: &PrimitiveArray<T>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i128> {
+    let to_decimal = integer_to_decimal_op(to_precision, to_scale);
+    let values = from.iter().map(|x| x.and_then(|x| to_decimal(*x)));
 
     PrimitiveArray::<i128>::from_trusted_len_iter(values)
         .to(DataType::Decimal(to_precision, to_scale))
 }
 
+/// Returns a [`PrimitiveArray<i128>`] with the casted values.
+/// # Errors
+/// Iff a (non-null) value does not fit in `Decimal(to_precision, to_scale)`.
+pub fn try_integer_to_decimal<T: NativeType + AsPrimitive<i128>>(
+    from: &PrimitiveArray<T>,
+    to_precision: usize,
+    to_scale: usize,
+) -> Result<PrimitiveArray<i128>> {
+    let to_decimal = integer_to_decimal_op(to_precision, to_scale);
+    let values = from
+        .values()
+        .iter()
+        .enumerate()
+        .map(|(index, x)| match to_decimal(*x) {
+            Some(x) => Ok(x),
+            None if from.is_null(index) => Ok(0),
+            None => Err(Error::Overflow),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(PrimitiveArray::<i128>::new(
+        DataType::Decimal(to_precision, to_scale),
+        values.into(),
+        from.validity().cloned(),
+    ))
+}
+
 pub(super) fn integer_to_decimal_dyn<T>(
     from: &dyn Array,
     precision: usize,
     scale: usize,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>>
 where
     T: NativeType + AsPrimitive<i128>,
 {
     let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(integer_to_decimal::<T>(from, precision, scale)))
+    if options.overflow == OverflowMode::Error {
+        try_integer_to_decimal::<T>(from, precision, scale).map(|x| x.boxed())
+    } else {
+        Ok(Box::new(integer_to_decimal::<T>(from, precision, scale)))
+    }
 }
 
 /// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
@@ -216,10 +329,8 @@ where
     // 1.2 => 12
     let multiplier: T = (10_f64).powi(to_scale as i32).as_();
 
-    let min_for_precision = 9_i128
-        .saturating_pow(1 + to_precision as u32)
-        .saturating_neg();
-    let max_for_precision = 9_i128.saturating_pow(1 + to_precision as u32);
+    let max_for_precision = max_for_precision(to_precision);
+    let min_for_precision = -max_for_precision;
 
     let values = from.iter().map(|x| {
         x.and_then(|x| {
@@ -339,6 +450,17 @@ pub fn time32s_to_time32ms(from: &PrimitiveArray<i32>) -> PrimitiveArray<i32> {
     unary(from, |x| x * 1000, DataType::Time32(TimeUnit::Millisecond))
 }
 
+/// Conversion of times
+/// # Errors
+/// Iff a (non-null) value overflows `i32` in milliseconds.
+pub fn try_time32s_to_time32ms(from: &PrimitiveArray<i32>) -> Result<PrimitiveArray<i32>> {
+    try_unary(
+        from,
+        |x| x.checked_mul(1000).ok_or(Error::Overflow),
+        DataType::Time32(TimeUnit::Millisecond),
+    )
+}
+
 /// Conversion of times
 pub fn time32ms_to_time32s(from: &PrimitiveArray<i32>) -> PrimitiveArray<i32> {
     unary(from, |x| x / 1000, DataType::Time32(TimeUnit::Second))
@@ -354,27 +476,90 @@ pub fn time64ns_to_time64us(from: &PrimitiveArray<i64>) -> PrimitiveArray<i64> {
     unary(from, |x| x / 1000, DataType::Time64(TimeUnit::Microsecond))
 }
 
-/// Conversion of timestamp
+/// Conversion of a naive (or UTC) timestamp to the milliseconds of the start of its day
 pub fn timestamp_to_date64(from: &PrimitiveArray<i64>, from_unit: TimeUnit) -> PrimitiveArray<i64> {
-    let from_size = time_unit_multiple(from_unit);
-    let to_size = MILLISECONDS;
-    let to_type = DataType::Date64;
+    let from_size = time_unit_multiple(from_unit) * SECONDS_IN_DAY;
+    unary(
+        from,
+        |x| x.div_euclid(from_size) * MILLISECONDS_IN_DAY,
+        DataType::Date64,
+    )
+}
+
+/// Conversion of a naive (or UTC) timestamp to the days of its day
+pub fn timestamp_to_date32(from: &PrimitiveArray<i64>, from_unit: TimeUnit) -> PrimitiveArray<i32> {
+    let from_size = time_unit_multiple(from_unit) * SECONDS_IN_DAY;
+    unary(from, |x| x.div_euclid(from_size) as i32, DataType::Date32)
+}
+
+fn timestamp_to_date32_impl<T: chrono::TimeZone>(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: T,
+) -> PrimitiveArray<i32> {
+    use chrono::Datelike;
+
+    unary(
+        from,
+        |x| {
+            timestamp_to_datetime(x, time_unit, &timezone)
+                .date_naive()
+                .num_days_from_ce()
+                - EPOCH_DAYS_FROM_CE
+        },
+        DataType::Date32,
+    )
+}
 
-    // Scale time_array by (to_size / from_size) using a
-    // single integer operation, but need to avoid integer
-    // math rounding down to zero
+#[cfg(feature = "chrono-tz")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono-tz")))]
+fn chrono_tz_timestamp_to_date32(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+) -> Result<PrimitiveArray<i32>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    Ok(timestamp_to_date32_impl(from, time_unit, timezone))
+}
 
-    match to_size.cmp(&from_size) {
-        std::cmp::Ordering::Less => unary(from, |x| (x / (from_size / to_size)), to_type),
-        std::cmp::Ordering::Equal => primitive_to_same_primitive(from, &to_type),
-        std::cmp::Ordering::Greater => unary(from, |x| (x * (to_size / from_size)), to_type),
+#[cfg(not(feature = "chrono-tz"))]
+fn chrono_tz_timestamp_to_date32(
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    timezone_str: &str,
+) -> Result<PrimitiveArray<i32>> {
+    Err(Error::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone_str
+    )))
+}
+
+/// Conversion of a timestamp in `timezone_str` to the days of its local day, i.e. truncated to
+/// the local midnight.
+/// # Errors
+/// Errors iff `timezone_str` is not a valid timezone.
+pub fn timestamp_to_date32_with_tz(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+) -> Result<PrimitiveArray<i32>> {
+    if let Ok(timezone) = parse_offset(timezone_str) {
+        Ok(timestamp_to_date32_impl(from, time_unit, timezone))
+    } else {
+        chrono_tz_timestamp_to_date32(from, time_unit, timezone_str)
     }
 }
 
-/// Conversion of timestamp
-pub fn timestamp_to_date32(from: &PrimitiveArray<i64>, from_unit: TimeUnit) -> PrimitiveArray<i32> {
-    let from_size = time_unit_multiple(from_unit) * SECONDS_IN_DAY;
-    unary(from, |x| (x / from_size) as i32, DataType::Date32)
+/// Conversion of a timestamp in `timezone_str` to the milliseconds of the start of its local day,
+/// i.e. truncated to the local midnight.
+/// # Errors
+/// Errors iff `timezone_str` is not a valid timezone.
+pub fn timestamp_to_date64_with_tz(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+) -> Result<PrimitiveArray<i64>> {
+    timestamp_to_date32_with_tz(from, time_unit, timezone_str).map(|days| date32_to_date64(&days))
 }
 
 /// Conversion of time
@@ -401,6 +586,24 @@ pub fn time64_to_time32(
     unary(from, |x| (x / divisor) as i32, DataType::Time32(to_unit))
 }
 
+/// Conversion of time
+/// # Errors
+/// Iff a (non-null) value overflows `i32` in `to_unit`.
+pub fn try_time64_to_time32(
+    from: &PrimitiveArray<i64>,
+    from_unit: TimeUnit,
+    to_unit: TimeUnit,
+) -> Result<PrimitiveArray<i32>> {
+    let from_size = time_unit_multiple(from_unit);
+    let to_size = time_unit_multiple(to_unit);
+    let divisor = from_size / to_size;
+    try_unary(
+        from,
+        |x| i32::try_from(x / divisor).map_err(|_| Error::Overflow),
+        DataType::Time32(to_unit),
+    )
+}
+
 /// Conversion of timestamp
 pub fn timestamp_to_timestamp(
     from: &PrimitiveArray<i64>,
@@ -411,6 +614,10 @@ pub fn timestamp_to_timestamp(
     let from_size = time_unit_multiple(from_unit);
     let to_size = time_unit_multiple(to_unit);
     let to_type = DataType::Timestamp(to_unit, tz.clone());
+    // only the timezone (if any) changes: relabel the values
+    if from_unit == to_unit {
+        return from.clone().to(to_type);
+    }
     // we either divide or multiply, depending on size of each unit
     if from_size >= to_size {
         unary(from, |x| (x / (from_size / to_size)), to_type)
@@ -490,7 +697,6 @@ fn chrono_tz_timestamp_to_utf8<O: Offset>(
     _: TimeUnit,
     timezone_str: &str,
 ) -> Result<Utf8Array<O>> {
-    use crate::error::Error;
     Err(Error::InvalidArgumentError(format!(
         "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
         timezone_str
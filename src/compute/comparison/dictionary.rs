@@ -0,0 +1,42 @@
+//! Comparison functions for [`DictionaryArray`]
+use std::cmp::Ordering;
+
+use crate::array::{ord::build_compare, BooleanArray, DictionaryArray, DictionaryKey};
+use crate::error::{Error, Result};
+
+/// Perform `lhs == rhs` operation on the logical values of two [`DictionaryArray`]s.
+///
+/// When both arrays have equal values (e.g. they share their dictionary), equal keys are
+/// equal without comparing their values. Otherwise, the values pointed to by the keys are
+/// compared, so that duplicated values are still equal.
+///
+/// A slot is null when its key, or the value its key points to, is null on either side.
+/// # Errors
+/// This function errors iff the arrays have different lengths or their values have
+/// different or non-comparable data types.
+pub fn eq<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &DictionaryArray<K>,
+) -> Result<BooleanArray> {
+    if lhs.len() != rhs.len() {
+        return Err(Error::InvalidArgumentError(
+            "Arrays must have the same length".to_string(),
+        ));
+    }
+
+    let valid_key = |array: &DictionaryArray<K>, key: Option<usize>| {
+        key.filter(|key| array.values().is_valid(*key))
+    };
+    let lhs_keys = lhs.keys_iter().map(|key| valid_key(lhs, key));
+    let rhs_keys = rhs.keys_iter().map(|key| valid_key(rhs, key));
+
+    let shared_values = lhs.values() == rhs.values();
+    let comparator = build_compare(lhs.values().as_ref(), rhs.values().as_ref())?;
+    Ok(lhs_keys
+        .zip(rhs_keys)
+        .map(|(lhs, rhs)| {
+            let (lhs, rhs) = (lhs?, rhs?);
+            Some((shared_values && lhs == rhs) || comparator(lhs, rhs) == Ordering::Equal)
+        })
+        .collect())
+}
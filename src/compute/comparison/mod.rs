@@ -50,6 +50,7 @@ use crate::scalar::*;
 
 pub mod binary;
 pub mod boolean;
+pub mod dictionary;
 pub mod primitive;
 pub mod utf8;
 
@@ -281,6 +282,57 @@ pub fn can_gt_eq(data_type: &DataType) -> bool {
     can_partial_eq_and_ord(data_type)
 }
 
+/// Compares two [`Array`]s of possibly different data types with `op` (e.g. [`gt`]), after
+/// casting both to their common data type (see [`coerce_types`](crate::datatypes::coerce_types)),
+/// e.g. `Int32` and `Int64` are compared as `Int64`.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Int32Array, Int64Array};
+/// use arrow2::compute::comparison::{compare_coerce, gt};
+///
+/// let lhs = Int32Array::from([Some(1), None, Some(3)]);
+/// let rhs = Int64Array::from_slice([2, 2, i64::MIN]);
+/// let result = compare_coerce(&lhs, &rhs, gt).unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(false), None, Some(true)]));
+/// ```
+/// # Errors
+/// Errors iff the arrays have different lengths or their data types have no common data type.
+/// # Panic
+/// Panics iff `op` panics, e.g. when it does not support the common data type.
+#[cfg(feature = "compute_cast")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_cast")))]
+pub fn compare_coerce<F>(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    op: F,
+) -> crate::error::Result<BooleanArray>
+where
+    F: Fn(&dyn Array, &dyn Array) -> BooleanArray,
+{
+    use crate::compute::cast::{cast, CastOptions};
+    use crate::datatypes::coerce_types;
+    use crate::error::Error;
+
+    if lhs.len() != rhs.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "Arrays must have the same length to be compared ({} != {})",
+            lhs.len(),
+            rhs.len()
+        )));
+    }
+    let data_type = coerce_types(lhs.data_type(), rhs.data_type()).ok_or_else(|| {
+        Error::InvalidArgumentError(format!(
+            "Arrays of data types {:?} and {:?} cannot be compared since they have no common data type",
+            lhs.data_type(),
+            rhs.data_type()
+        ))
+    })?;
+
+    let lhs = cast(lhs, &data_type, CastOptions::default())?;
+    let rhs = cast(rhs, &data_type, CastOptions::default())?;
+    Ok(op(lhs.as_ref(), rhs.as_ref()))
+}
+
 macro_rules! compare_scalar {
     ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {{
         let lhs = $lhs;
@@ -13,10 +13,44 @@
 //! assert_eq!(arr.len(), 3);
 //! ```
 
-use crate::array::{growable::make_growable, Array};
+use crate::array::{growable::make_growable, Array, BinaryArray, Utf8Array};
+use crate::datatypes::PhysicalType;
 use crate::error::{Error, Result};
+use crate::offset::{Offset, OffsetsBuffer};
+
+/// Checks that the total length of the values referenced by `offsets` fits in `O`.
+fn check_values_length<'a, O: Offset, I: Iterator<Item = &'a OffsetsBuffer<O>>>(
+    offsets: I,
+) -> Result<()> {
+    offsets
+        .map(|offsets| offsets.range().to_usize())
+        .try_fold(0usize, |acc, length| {
+            acc.checked_add(length)
+                .filter(|total| O::from_usize(*total).is_some())
+        })
+        .map(|_| ())
+        .ok_or(Error::Overflow)
+}
+
+macro_rules! offsets {
+    ($arrays:expr, $ty:ty) => {
+        $arrays
+            .iter()
+            .map(|array| array.as_any().downcast_ref::<$ty>().unwrap().offsets())
+    };
+}
 
 /// Concatenate multiple [Array] of the same type into a single [`Array`].
+///
+/// Nested arrays (e.g. [`StructArray`](crate::array::StructArray) and
+/// [`ListArray`](crate::array::ListArray)) are concatenated recursively.
+/// # Errors
+/// Errors iff
+/// * `arrays` is empty
+/// * the arrays do not have the same [`DataType`](crate::datatypes::DataType)
+/// * the arrays are [`Utf8Array`] or [`BinaryArray`] whose total number of bytes does not fit
+///   in their offsets, in which case [`Error::Overflow`] is returned and the arrays should be
+///   cast to `LargeUtf8` or `LargeBinary` first
 pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
     if arrays.is_empty() {
         return Err(Error::InvalidArgumentError(
@@ -33,6 +67,14 @@ pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
         ));
     }
 
+    match arrays[0].data_type().to_physical_type() {
+        PhysicalType::Utf8 => check_values_length(offsets!(arrays, Utf8Array<i32>))?,
+        PhysicalType::LargeUtf8 => check_values_length(offsets!(arrays, Utf8Array<i64>))?,
+        PhysicalType::Binary => check_values_length(offsets!(arrays, BinaryArray<i32>))?,
+        PhysicalType::LargeBinary => check_values_length(offsets!(arrays, BinaryArray<i64>))?,
+        _ => {}
+    }
+
     let lengths = arrays.iter().map(|array| array.len()).collect::<Vec<_>>();
     let capacity = lengths.iter().sum();
 
@@ -44,3 +86,53 @@ pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
 
     Ok(mutable.as_box())
 }
+
+/// Concatenate multiple [Array] into a single [`Array`], like [`concatenate`], except that arrays
+/// of different data types are first cast to their common data type (see
+/// [`coerce_types`](crate::datatypes::coerce_types)), e.g. `Int32` and `Int64` become `Int64`.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, Int64Array};
+/// use arrow2::compute::concatenate::concatenate_coerce;
+///
+/// let result = concatenate_coerce(&[
+///     &Int32Array::from_slice([1, 2]),
+///     &Int64Array::from_slice([3]),
+/// ]).unwrap();
+/// assert_eq!(result.as_ref(), &Int64Array::from_slice([1, 2, 3]) as &dyn Array);
+/// ```
+/// # Errors
+/// Errors iff `arrays` is empty, the data types have no common data type or
+/// [`concatenate`] errors.
+#[cfg(feature = "compute_cast")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_cast")))]
+pub fn concatenate_coerce(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    use crate::compute::cast::{cast, CastOptions};
+    use crate::datatypes::coerce_types;
+
+    let first = arrays.first().ok_or_else(|| {
+        Error::InvalidArgumentError("concat requires input of at least one array".to_string())
+    })?;
+    let data_type = arrays
+        .iter()
+        .try_fold(first.data_type().clone(), |data_type, array| {
+            coerce_types(&data_type, array.data_type())
+        })
+        .ok_or_else(|| {
+            Error::InvalidArgumentError(
+                "The arrays cannot be concatenated since their data types have no common data type"
+                    .to_string(),
+            )
+        })?;
+
+    let arrays = arrays
+        .iter()
+        .map(|array| cast(*array, &data_type, CastOptions::default()))
+        .collect::<Result<Vec<_>>>()?;
+    concatenate(
+        &arrays
+            .iter()
+            .map(|array| array.as_ref())
+            .collect::<Vec<_>>(),
+    )
+}
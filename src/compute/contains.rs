@@ -1,4 +1,4 @@
-//! Declares the [`contains`] operator
+//! Declares the [`contains`], [`list_contains`] and [`list_contains_utf8`] operators
 
 use crate::{
     array::{Array, BinaryArray, BooleanArray, ListArray, PrimitiveArray, Utf8Array},
@@ -196,3 +196,84 @@ pub fn contains(list: &dyn Array, values: &dyn Array) -> Result<BooleanArray> {
         ))),
     }
 }
+
+/// Returns whether each sublist of `list` has a valid element at which `eq` is true, where
+/// `validity` is the validity of the values of `list`.
+fn list_contains_by<O, F>(list: &ListArray<O>, validity: Option<&Bitmap>, eq: F) -> BooleanArray
+where
+    O: Offset,
+    F: Fn(usize) -> bool,
+{
+    let is_valid = |i: usize| validity.map_or(true, |validity| validity.get_bit(i));
+    let values =
+        list.offsets().buffer().windows(2).map(|window| {
+            (window[0].to_usize()..window[1].to_usize()).any(|i| is_valid(i) && eq(i))
+        });
+    let values = Bitmap::from_trusted_len_iter(values);
+
+    BooleanArray::new(DataType::Boolean, values, list.validity().cloned())
+}
+
+/// Returns whether each sublist of `list` contains `needle`.
+///
+/// Null sublists are null, empty sublists are `false` and null elements never match.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::contains::list_contains;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let result = list_contains(&list, 2).unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(true), None, Some(false)]));
+/// ```
+/// # Errors
+/// This function errors iff the values of `list` are not a [`PrimitiveArray<T>`].
+pub fn list_contains<T, O>(list: &ListArray<O>, needle: T) -> Result<BooleanArray>
+where
+    T: NativeType + PartialEq,
+    O: Offset,
+{
+    let child = list
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .ok_or_else(|| {
+            Error::InvalidArgumentError(format!(
+                "list_contains requires the values of the list to be of the needle's type, but they are {:?}",
+                list.values().data_type()
+            ))
+        })?;
+    let values = child.values();
+    Ok(list_contains_by(list, child.validity(), |i| {
+        values[i] == needle
+    }))
+}
+
+/// Returns whether each sublist of `list` contains `needle`.
+///
+/// Null sublists are null, empty sublists are `false` and null elements never match.
+/// # Errors
+/// This function errors iff the values of `list` are not a [`Utf8Array<OO>`].
+pub fn list_contains_utf8<O, OO>(list: &ListArray<O>, needle: &str) -> Result<BooleanArray>
+where
+    O: Offset,
+    OO: Offset,
+{
+    let child = list
+        .values()
+        .as_any()
+        .downcast_ref::<Utf8Array<OO>>()
+        .ok_or_else(|| {
+            Error::InvalidArgumentError(format!(
+                "list_contains_utf8 requires the values of the list to be a Utf8Array<{}>, but they are {:?}",
+                std::any::type_name::<OO>(),
+                list.values().data_type()
+            ))
+        })?;
+    Ok(list_contains_by(list, child.validity(), |i| {
+        child.value(i) == needle
+    }))
+}
@@ -4,7 +4,7 @@ use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
 use crate::bitmap::{utils::SlicesIterator, Bitmap, MutableBitmap};
 use crate::chunk::Chunk;
 use crate::datatypes::DataType;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::types::simd::Simd;
 use crate::types::BitChunkOnes;
 use crate::{array::*, types::NativeType};
@@ -210,8 +210,14 @@ fn filter_growable<'a>(growable: &mut impl Growable<'a>, chunks: &[(usize, usize
 /// Returns a prepared function optimized to filter multiple arrays.
 /// Creating this function requires time, but using it is faster than [filter] when the
 /// same filter needs to be applied to multiple arrays (e.g. a multiple columns).
+///
+/// As in [filter], the nulls of `filter` are interpreted as `false`.
 pub fn build_filter(filter: &BooleanArray) -> Result<Filter> {
-    let iter = SlicesIterator::new(filter.values());
+    let values = match filter.validity() {
+        Some(validity) => filter.values() & validity,
+        None => filter.values().clone(),
+    };
+    let iter = SlicesIterator::new(&values);
     let filter_count = iter.slots();
     let chunks = iter.collect::<Vec<_>>();
 
@@ -305,12 +311,42 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
     }
 }
 
+/// Returns the indices of the slots of `mask` that are valid and `true`, in increasing order.
+///
+/// Taking these indices (e.g. via [`take`](crate::compute::take::take)) from an array
+/// is equivalent to filtering it by `mask`.
+/// # Panics
+/// This function panics iff `mask.len() > u32::MAX as usize + 1`.
+pub fn filter_to_indices(mask: &BooleanArray) -> PrimitiveArray<u32> {
+    assert!(mask.len() <= u32::MAX as usize + 1);
+    let values = match mask.validity() {
+        Some(validity) => mask.values() & validity,
+        None => mask.values().clone(),
+    };
+
+    let iter = SlicesIterator::new(&values);
+    let mut indices = Vec::with_capacity(iter.slots());
+    iter.for_each(|(start, len)| indices.extend((start..start + len).map(|index| index as u32)));
+    PrimitiveArray::from_vec(indices)
+}
+
 /// Returns a new [Chunk] with arrays containing only values matching the filter.
 /// This is a convenience function: filter multiple columns is embarassingly parallel.
+///
+/// The selection is computed once (via [build_filter]) and applied to every column.
+/// # Errors
+/// This function errors iff the length of `filter_values` differs from the length of `columns`.
 pub fn filter_chunk<A: AsRef<dyn Array>>(
     columns: &Chunk<A>,
     filter_values: &BooleanArray,
 ) -> Result<Chunk<Box<dyn Array>>> {
+    if filter_values.len() != columns.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "The filter must have the same length as the chunk ({} != {})",
+            filter_values.len(),
+            columns.len()
+        )));
+    }
     let arrays = columns.arrays();
 
     let num_colums = arrays.len();
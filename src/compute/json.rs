@@ -0,0 +1,58 @@
+//! Contains kernels to extract values from arrays of JSON documents.
+use serde_json::Value;
+
+use crate::{
+    array::Utf8Array,
+    error::{Error, Result},
+    offset::Offset,
+};
+
+/// Returns the value of `document` at `path`, or `None` if it does not exist.
+fn extract<'a>(document: &'a Value, path: &[&str]) -> Option<&'a Value> {
+    path.iter().try_fold(document, |value, key| match value {
+        Value::Object(object) => object.get(*key),
+        Value::Array(array) => key.parse::<usize>().ok().and_then(|index| array.get(index)),
+        _ => None,
+    })
+}
+
+/// Returns a new [`Utf8Array`] with the value at `path` of each JSON document in `array`,
+/// rendered as JSON.
+///
+/// `path` is a dotted path of object keys and array indices (e.g. `a.b.0.c`); an empty
+/// `path` returns the whole document.
+/// A row is null if it is null, is not valid JSON or does not contain `path`.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::json::json_extract;
+///
+/// let array = Utf8Array::<i32>::from([
+///     Some(r#"{"a": {"b": [1, {"c": "x"}]}}"#),
+///     Some(r#"{"a": 1}"#),
+///     None,
+/// ]);
+/// let result = json_extract(&array, "a.b.1").unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from([Some(r#"{"c":"x"}"#), None, None]));
+/// ```
+/// # Errors
+/// Errors iff `path` is not empty and contains an empty key (e.g. `a..b`).
+pub fn json_extract<O: Offset>(array: &Utf8Array<O>, path: &str) -> Result<Utf8Array<O>> {
+    let path = if path.is_empty() {
+        vec![]
+    } else {
+        path.split('.').collect::<Vec<_>>()
+    };
+    if path.iter().any(|key| key.is_empty()) {
+        return Err(Error::InvalidArgumentError(format!(
+            "The JSON path \"{}\" contains an empty key",
+            path.join(".")
+        )));
+    }
+
+    let iter = array.iter().map(|document| {
+        let document = serde_json::from_str::<Value>(document?).ok()?;
+        extract(&document, &path).map(|value| value.to_string())
+    });
+    Ok(Utf8Array::<O>::from_trusted_len_iter(iter))
+}
@@ -46,7 +46,38 @@ where
     PrimitiveArray::<O>::new(data_type, values.into(), array.validity().cloned())
 }
 
-/// Returns an array of integers with the number of bytes on each string of the array.
+/// Returns an array of integers with the number of elements of each sublist of `list`.
+/// The result is of type `Int32` for `List` and `Int64` for `LargeList`; null sublists have null lengths.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::length::list_lengths;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend(vec![Some(vec![Some(1), None]), None, Some(vec![])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// assert_eq!(list_lengths(&list), Int32Array::from([Some(2), None, Some(0)]));
+/// ```
+pub fn list_lengths<O: Offset + NativeType>(list: &ListArray<O>) -> PrimitiveArray<O> {
+    let values = list
+        .offsets()
+        .buffer()
+        .windows(2)
+        .map(|offset| offset[1] - offset[0])
+        .collect::<Vec<_>>();
+
+    let data_type = if O::IS_LARGE {
+        DataType::Int64
+    } else {
+        DataType::Int32
+    };
+
+    PrimitiveArray::<O>::new(data_type, values.into(), list.validity().cloned())
+}
+
+/// Returns an array of integers with the number of bytes on each string of the array,
+/// or the number of elements of each sublist for list arrays.
 pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
     match array.data_type() {
         DataType::Utf8 => {
@@ -57,6 +88,14 @@ pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
             let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
             Ok(Box::new(unary_offsets_string::<i64, _>(array, |x| x)))
         }
+        DataType::List(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            Ok(Box::new(list_lengths(array)))
+        }
+        DataType::LargeList(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            Ok(Box::new(list_lengths(array)))
+        }
         _ => Err(Error::InvalidArgumentError(format!(
             "length not supported for {:?}",
             array.data_type()
@@ -78,5 +117,8 @@ pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
 /// assert_eq!(can_length(&data_type), false);
 /// ```
 pub fn can_length(data_type: &DataType) -> bool {
-    matches!(data_type, DataType::Utf8 | DataType::LargeUtf8)
+    matches!(
+        data_type,
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::List(_) | DataType::LargeList(_)
+    )
 }
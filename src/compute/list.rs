@@ -0,0 +1,62 @@
+//! Contains kernels for [`ListArray`] such as [`explode`].
+
+use crate::{
+    array::{growable::make_growable, Array, ListArray, PrimitiveArray},
+    error::{Error, Result},
+    offset::Offset,
+};
+
+/// Flattens `list` into its child values, one row per element of each sublist, and returns
+/// them together with the index of the row of `list` that each value originates from.
+///
+/// The parent indices can be used to [`take`](crate::compute::take::take) other columns,
+/// replicating them to the exploded rows (e.g. SQL's `UNNEST`).
+///
+/// Null and empty sublists contribute no rows, or a single null row when
+/// `preserve_empty` is `true`.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::explode;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![Some(3)])]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let (values, parents) = explode(&list, false).unwrap();
+/// assert_eq!(values.as_ref(), &Int32Array::from_slice([1, 2, 3]) as &dyn arrow2::array::Array);
+/// assert_eq!(parents.values().as_slice(), &[0, 0, 2]);
+/// ```
+/// # Errors
+/// This function errors iff `list` has more than `u32::MAX` rows.
+pub fn explode<O: Offset>(
+    list: &ListArray<O>,
+    preserve_empty: bool,
+) -> Result<(Box<dyn Array>, PrimitiveArray<u32>)> {
+    if list.len() > u32::MAX as usize + 1 {
+        return Err(Error::Overflow);
+    }
+
+    let values = list.values().as_ref();
+    let capacity = list.offsets().range().to_usize();
+    let mut growable = make_growable(&[values], preserve_empty, capacity);
+    let mut parents = Vec::<u32>::with_capacity(capacity);
+
+    list.offsets()
+        .buffer()
+        .windows(2)
+        .enumerate()
+        .for_each(|(row, window)| {
+            let start = window[0].to_usize();
+            let length = window[1].to_usize() - start;
+            if list.is_valid(row) && length > 0 {
+                growable.extend(0, start, length);
+                parents.extend(std::iter::repeat(row as u32).take(length));
+            } else if preserve_empty {
+                growable.extend_validity(1);
+                parents.push(row as u32);
+            }
+        });
+
+    Ok((growable.as_box(), PrimitiveArray::from_vec(parents)))
+}
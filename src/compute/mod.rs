@@ -22,6 +22,9 @@ pub mod arity_assign;
 #[cfg(feature = "compute_bitwise")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_bitwise")))]
 pub mod bitwise;
+#[cfg(feature = "compute_bloom_filter")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_bloom_filter")))]
+pub mod bloom_filter;
 #[cfg(feature = "compute_boolean")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_boolean")))]
 pub mod boolean;
@@ -49,6 +52,9 @@ pub mod hash;
 #[cfg(feature = "compute_if_then_else")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_if_then_else")))]
 pub mod if_then_else;
+#[cfg(feature = "compute_json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_json")))]
+pub mod json;
 #[cfg(feature = "compute_length")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_length")))]
 pub mod length;
@@ -58,6 +64,9 @@ pub mod like;
 #[cfg(feature = "compute_limit")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_limit")))]
 pub mod limit;
+#[cfg(feature = "compute_list")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_list")))]
+pub mod list;
 #[cfg(feature = "compute_merge_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_merge_sort")))]
 pub mod merge_sort;
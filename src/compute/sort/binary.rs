@@ -1,10 +1,20 @@
-use crate::array::{BinaryArray, PrimitiveArray};
+use crate::array::{Array, BinaryArray, PrimitiveArray};
 use crate::offset::Offset;
 use crate::types::Index;
 
 use super::common;
 use super::SortOptions;
 
+pub(super) fn sort_by<O: Offset>(
+    array: &BinaryArray<O>,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> BinaryArray<O> {
+    let cmp = |lhs: &&[u8], rhs: &&[u8]| lhs.cmp(rhs);
+    let values = common::sorted_unstable_by(array.iter(), array.null_count(), cmp, options, limit);
+    BinaryArray::<O>::from_trusted_len_iter(values.into_iter())
+}
+
 pub(super) fn indices_sorted_unstable_by<I: Index, O: Offset>(
     array: &BinaryArray<O>,
     options: &SortOptions,
@@ -164,3 +164,54 @@ where
     let data_type = I::PRIMITIVE.into();
     PrimitiveArray::<I>::new(data_type, indices.into(), None)
 }
+
+/// Returns the non-null values of `iter` sorted according to `options`, preceded or
+/// followed by its `null_count` nulls and truncated to `limit`.
+pub(super) fn sorted_unstable_by<'a, T, I, F>(
+    iter: I,
+    null_count: usize,
+    cmp: F,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> Vec<Option<&'a T>>
+where
+    T: ?Sized,
+    I: Iterator<Item = Option<&'a T>>,
+    F: Fn(&&'a T, &&'a T) -> std::cmp::Ordering,
+{
+    let mut values = iter.flatten().collect::<Vec<_>>();
+    let length = values.len() + null_count;
+    let limit = limit.unwrap_or(length).min(length);
+
+    // the number of non-null values in the result
+    let limit_values = if options.nulls_first {
+        limit.saturating_sub(null_count)
+    } else {
+        limit.min(values.len())
+    };
+
+    let compare = |lhs: &&'a T, rhs: &&'a T| {
+        if options.descending {
+            cmp(rhs, lhs)
+        } else {
+            cmp(lhs, rhs)
+        }
+    };
+    if limit_values < values.len() {
+        if limit_values > 0 {
+            let (before, _, _) = values.select_nth_unstable_by(limit_values, compare);
+            before.sort_unstable_by(compare);
+        }
+        values.truncate(limit_values);
+    } else {
+        values.sort_unstable_by(compare);
+    }
+
+    let nulls = std::iter::repeat(None).take(limit - limit_values);
+    let values = values.into_iter().map(Some);
+    if options.nulls_first {
+        nulls.chain(values).collect()
+    } else {
+        values.chain(nulls).collect()
+    }
+}
@@ -22,8 +22,10 @@ pub struct SortColumn<'a> {
 }
 
 /// Sort a list of [`Array`] using [`SortOptions`] provided for each array.
-/// # Implementaqtion
-/// The sort is stable and lexicographical on values.
+/// # Implementation
+/// The sort is lexicographical on values and unstable, i.e. rows that are equal on all columns
+/// may be in any order. For a stable sort, either add a column that breaks ties (e.g. the row
+/// index) or, for a single column, use [`sort_to_indices_stable`](super::sort_to_indices_stable).
 ///
 /// Returns an [`Error`] if any of the array type is either unsupported by
 /// `lexsort_to_indices` or `take`.
@@ -31,10 +31,22 @@ macro_rules! dyn_sort {
     }};
 }
 
+// sorts the values of a Utf8 or Binary array directly, without computing indices
+macro_rules! dyn_sort_binary {
+    ($module:ident, $offset:ty, $array:expr, $options:expr, $limit:expr) => {{
+        let array = $array.as_any().downcast_ref().unwrap();
+        Ok(Box::new($module::sort_by::<$offset>(
+            array, $options, $limit,
+        )))
+    }};
+}
+
 /// Sort the [`Array`] using [`SortOptions`].
 ///
 /// Performs an unstable sort on values and indices. Nulls are ordered according to the `nulls_first` flag in `options`.
-/// Floats are sorted using IEEE 754 totalOrder
+/// Floats are sorted using IEEE 754 totalOrder, except that all NaNs are equal and greater than
+/// any other value (see [`ord::total_cmp_nans_last_f64`]).
+/// The result equals `take(values, sort_to_indices(values, options, limit))`.
 /// # Errors
 /// Errors if the [`DataType`] is not supported.
 pub fn sort(
@@ -60,8 +72,12 @@ pub fn sort(
         DataType::UInt16 => dyn_sort!(u16, values, ord::total_cmp, options, limit),
         DataType::UInt32 => dyn_sort!(u32, values, ord::total_cmp, options, limit),
         DataType::UInt64 => dyn_sort!(u64, values, ord::total_cmp, options, limit),
-        DataType::Float32 => dyn_sort!(f32, values, ord::total_cmp_f32, options, limit),
-        DataType::Float64 => dyn_sort!(f64, values, ord::total_cmp_f64, options, limit),
+        DataType::Float32 => dyn_sort!(f32, values, ord::total_cmp_nans_last_f32, options, limit),
+        DataType::Float64 => dyn_sort!(f64, values, ord::total_cmp_nans_last_f64, options, limit),
+        DataType::Utf8 => dyn_sort_binary!(utf8, i32, values, options, limit),
+        DataType::LargeUtf8 => dyn_sort_binary!(utf8, i64, values, options, limit),
+        DataType::Binary => dyn_sort_binary!(binary, i32, values, options, limit),
+        DataType::LargeBinary => dyn_sort_binary!(binary, i64, values, options, limit),
         _ => {
             let indices = sort_to_indices::<u64>(values, options, limit)?;
             take::take(values, &indices)
@@ -93,6 +109,9 @@ macro_rules! dyn_sort_indices {
 }
 
 /// Sort elements from `values` into a non-nullable [`PrimitiveArray`] of indices that sort `values`.
+///
+/// The sort is unstable, i.e. the indices of equal elements may be in any order.
+/// Use [`sort_to_indices_stable`] when their relative order must be preserved.
 pub fn sort_to_indices<I: Index>(
     values: &dyn Array,
     options: &SortOptions,
@@ -128,8 +147,12 @@ pub fn sort_to_indices<I: Index>(
         DataType::UInt16 => dyn_sort_indices!(I, u16, values, ord::total_cmp, options, limit),
         DataType::UInt32 => dyn_sort_indices!(I, u32, values, ord::total_cmp, options, limit),
         DataType::UInt64 => dyn_sort_indices!(I, u64, values, ord::total_cmp, options, limit),
-        DataType::Float32 => dyn_sort_indices!(I, f32, values, ord::total_cmp_f32, options, limit),
-        DataType::Float64 => dyn_sort_indices!(I, f64, values, ord::total_cmp_f64, options, limit),
+        DataType::Float32 => {
+            dyn_sort_indices!(I, f32, values, ord::total_cmp_nans_last_f32, options, limit)
+        }
+        DataType::Float64 => {
+            dyn_sort_indices!(I, f64, values, ord::total_cmp_nans_last_f64, options, limit)
+        }
         DataType::Utf8 => Ok(utf8::indices_sorted_unstable_by::<I, i32>(
             values.as_any().downcast_ref().unwrap(),
             options,
@@ -226,6 +249,32 @@ fn sort_dict<I: Index, O: Offset>(
     })
 }
 
+/// Sort elements from `values` into a non-nullable [`PrimitiveArray`] of indices that sort `values`,
+/// preserving the relative order of equal elements.
+///
+/// This is slower than [`sort_to_indices`], which should be preferred when the order of ties is irrelevant
+/// (e.g. when sorting values, since ties are indistinguishable).
+/// # Errors
+/// Errors if the [`DataType`] is not supported.
+pub fn sort_to_indices_stable<I: Index>(
+    values: &dyn Array,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> Result<PrimitiveArray<I>> {
+    let compare = lex_sort::build_compare(values, *options)?;
+
+    let mut indices = I::range(0, values.len()).unwrap().collect::<Vec<_>>();
+    indices.sort_by(|lhs, rhs| compare(lhs.to_usize(), rhs.to_usize()));
+    indices.truncate(limit.unwrap_or(values.len()));
+    indices.shrink_to_fit();
+
+    Ok(PrimitiveArray::<I>::new(
+        I::PRIMITIVE.into(),
+        indices.into(),
+        None,
+    ))
+}
+
 /// Checks if an array of type `datatype` can be sorted
 ///
 /// # Examples
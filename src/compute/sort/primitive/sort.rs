@@ -35,7 +35,7 @@ where
 {
     if descending {
         let (before, _, _) = values.select_nth_unstable_by(limit, |x, y| cmp(y, x));
-        before.sort_unstable_by(|x, y| cmp(x, y));
+        before.sort_unstable_by(|x, y| cmp(y, x));
     } else {
         let (before, _, _) = values.select_nth_unstable_by(limit, |x, y| cmp(x, y));
         before.sort_unstable_by(|x, y| cmp(x, y));
@@ -109,13 +109,9 @@ where
             buffer.extend_from_slice(&values[start..start + len])
         }
 
-        // sort all non-null values
-        sort_values(
-            buffer.as_mut_slice(),
-            cmp,
-            options.descending,
-            limit - validity.unset_bits(),
-        );
+        // sort the non-null values that are within the limit
+        let limit_values = limit.min(values.len() - validity.unset_bits());
+        sort_values(buffer.as_mut_slice(), cmp, options.descending, limit_values);
 
         if limit > values.len() - validity.unset_bits() {
             // extend remaining with nulls
@@ -1,10 +1,20 @@
-use crate::array::{DictionaryArray, DictionaryKey, PrimitiveArray, Utf8Array};
+use crate::array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray, Utf8Array};
 use crate::offset::Offset;
 use crate::types::Index;
 
 use super::common;
 use super::SortOptions;
 
+pub(super) fn sort_by<O: Offset>(
+    array: &Utf8Array<O>,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> Utf8Array<O> {
+    let cmp = |lhs: &&str, rhs: &&str| lhs.cmp(rhs);
+    let values = common::sorted_unstable_by(array.iter(), array.null_count(), cmp, options, limit);
+    Utf8Array::<O>::from_trusted_len_iter(values.into_iter())
+}
+
 pub(super) fn indices_sorted_unstable_by<I: Index, O: Offset>(
     array: &Utf8Array<O>,
     options: &SortOptions,
@@ -26,15 +26,56 @@ pub fn take_values<O: Offset>(
     buffer.into()
 }
 
+// gathers `values` at `indices`, copying runs of consecutive indices as a single span of
+// `values` and `offsets`
+fn take_runs<O: Offset, I: Index>(
+    offsets: &[O],
+    values: &[u8],
+    indices: &[I],
+) -> (OffsetsBuffer<O>, Buffer<u8>) {
+    let mut buffer = Vec::<u8>::new();
+    let mut new_offsets = Vec::<O>::with_capacity(indices.len() + 1);
+    new_offsets.push(O::default());
+
+    let mut indices = indices.iter().map(|index| index.to_usize()).peekable();
+    while let Some(start) = indices.next() {
+        let mut end = start + 1;
+        while indices.next_if_eq(&end).is_some() {
+            end += 1;
+        }
+        let first = offsets[start];
+        let base = *new_offsets.last().unwrap();
+        buffer.extend_from_slice(&values[first.to_usize()..offsets[end].to_usize()]);
+        // the last offset of the run is the largest, so checking it checks all of them
+        O::from_usize(buffer.len()).expect("the taken values to fit in the offset type");
+        new_offsets.extend(offsets[start + 1..=end].iter().map(|o| *o - first + base));
+    }
+    // Safety: by construction offsets are monotonically increasing
+    let offsets = unsafe { Offsets::new_unchecked(new_offsets) };
+
+    (offsets.into(), buffer.into())
+}
+
 // take implementation when neither values nor indices contain nulls
 pub fn take_no_validity<O: Offset, I: Index>(
-    offsets: &OffsetsBuffer<O>,
+    offsets: &[O],
     values: &[u8],
     indices: &[I],
 ) -> (OffsetsBuffer<O>, Buffer<u8>, Option<Bitmap>) {
+    let runs = 1 + indices
+        .windows(2)
+        .filter(|w| w[1].to_usize() != w[0].to_usize() + 1)
+        .count();
+    // copying runs only pays off when they are long enough
+    if indices.len() >= 2 * runs {
+        let (offsets, buffer) = take_runs(offsets, values, indices);
+        return (offsets, buffer, None);
+    }
+
     let mut buffer = Vec::<u8>::new();
     let lengths = indices.iter().map(|index| index.to_usize()).map(|index| {
-        let (start, end) = offsets.start_end(index);
+        let start = offsets[index].to_usize();
+        let end = offsets[index + 1].to_usize();
         // todo: remove this bound check
         buffer.extend_from_slice(&values[start..end]);
         end - start
@@ -55,26 +96,7 @@ pub fn take_values_validity<O: Offset, I: Index, A: GenericBinaryArray<O>>(
         .map(|index| validity_values.get_bit(index.to_usize()));
     let validity = Bitmap::from_trusted_len_iter(validity);
 
-    let mut length = O::default();
-
-    let offsets = values.offsets();
-    let values_values = values.values();
-
-    let mut starts = Vec::<O>::with_capacity(indices.len());
-    let offsets = indices.iter().map(|index| {
-        let index = index.to_usize();
-        let start = offsets[index];
-        length += offsets[index + 1] - start;
-        starts.push(start);
-        length
-    });
-    let offsets = std::iter::once(O::default())
-        .chain(offsets)
-        .collect::<Vec<_>>();
-    // Safety: by construction offsets are monotonically increasing
-    let offsets = unsafe { Offsets::new_unchecked(offsets) }.into();
-
-    let buffer = take_values(length, starts.as_slice(), &offsets, values_values);
+    let (offsets, buffer, _) = take_no_validity(values.offsets(), values.values(), indices);
 
     (offsets, buffer, validity.into())
 }
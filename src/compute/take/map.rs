@@ -0,0 +1,36 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::array::{
+    growable::{Growable, GrowableMap},
+    MapArray, PrimitiveArray,
+};
+
+use super::Index;
+
+/// `take` implementation for MapArrays
+pub fn take<O: Index>(values: &MapArray, indices: &PrimitiveArray<O>) -> MapArray {
+    let use_validity = indices.validity().is_some();
+    let mut growable = GrowableMap::new(vec![values], use_validity, indices.len());
+
+    indices.iter().for_each(|index| match index {
+        Some(index) => growable.extend(0, index.to_usize(), 1),
+        None => growable.extend_validity(1),
+    });
+
+    growable.into()
+}
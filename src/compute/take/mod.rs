@@ -18,18 +18,23 @@
 //! Defines take kernel for [`Array`]
 
 use crate::{
-    array::{new_empty_array, Array, NullArray, PrimitiveArray},
+    array::{new_empty_array, new_null_array, Array, NullArray, PrimitiveArray},
+    bitmap::Bitmap,
+    chunk::Chunk,
     datatypes::DataType,
-    error::Result,
+    error::{Error, Result},
     types::Index,
 };
 
+use super::utils::combine_validities;
+
 mod binary;
 mod boolean;
 mod dict;
 mod fixed_size_list;
 mod generic_binary;
 mod list;
+mod map;
 mod primitive;
 mod structure;
 mod utf8;
@@ -38,10 +43,19 @@ pub(crate) use boolean::take as take_boolean;
 
 /// Returns a new [`Array`] with only indices at `indices`. Null indices are taken as nulls.
 /// The returned array has a length equal to `indices.len()`.
+///
+/// The data type of `values` is preserved; in particular, the `keys_sorted` flag of a
+/// [`DataType::Map`] describes the keys within each map entry, which taking rows does not reorder.
+///
+/// When `indices` are a contiguous range without nulls (e.g. the identity `0..values.len()`),
+/// the result is a slice of `values` that shares its buffers.
 pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result<Box<dyn Array>> {
     if indices.len() == 0 {
         return Ok(new_empty_array(values.data_type().clone()));
     }
+    if let Some(start) = contiguous_start(values.len(), indices) {
+        return Ok(values.sliced(start, indices.len()));
+    }
 
     use crate::datatypes::PhysicalType::*;
     match values.data_type().to_physical_type() {
@@ -95,10 +109,99 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
             let array = values.as_any().downcast_ref().unwrap();
             Ok(Box::new(fixed_size_list::take::<O>(array, indices)))
         }
+        Map => {
+            let array = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(map::take::<O>(array, indices)))
+        }
         t => unimplemented!("Take not supported for data type {:?}", t),
     }
 }
 
+/// Returns the first index iff `indices` has no nulls and is the range
+/// `start..start + indices.len()` within `0..len`.
+fn contiguous_start<O: Index>(len: usize, indices: &PrimitiveArray<O>) -> Option<usize> {
+    if indices.null_count() > 0 {
+        return None;
+    }
+    let start = indices.values().first()?.to_usize();
+    if start.checked_add(indices.len())? > len {
+        return None;
+    }
+    indices
+        .values()
+        .iter()
+        .enumerate()
+        .all(|(i, index)| index.to_usize() == start + i)
+        .then_some(start)
+}
+
+/// Returns a new [`Array`] with only indices at `indices`, like [`take`], except that
+/// out-of-range indices (including negative indices) are taken as nulls instead of panicking.
+///
+/// This is useful when `indices` come from an untrusted source.
+pub fn take_safe<O: Index>(
+    values: &dyn Array,
+    indices: &PrimitiveArray<O>,
+) -> Result<Box<dyn Array>> {
+    let len = values.len();
+    if len == 0 {
+        return Ok(new_null_array(values.data_type().clone(), indices.len()));
+    }
+
+    let in_range =
+        Bitmap::from_trusted_len_iter(indices.values().iter().map(|index| index.to_usize() < len));
+    if in_range.unset_bits() == 0 {
+        return take(values, indices);
+    }
+
+    // out-of-range indices are replaced by a valid index and then masked off
+    let clamped = indices
+        .values()
+        .iter()
+        .map(|index| {
+            if index.to_usize() < len {
+                *index
+            } else {
+                O::default()
+            }
+        })
+        .collect::<Vec<_>>();
+    let validity = combine_validities(indices.validity(), Some(&in_range));
+    let indices = PrimitiveArray::new(indices.data_type().clone(), clamped.into(), validity);
+    take(values, &indices)
+}
+
+/// Returns a new [`Chunk`] whose columns are the columns of `chunk` taken at `indices`
+/// (see [`take`]). Null indices are taken as nulls.
+///
+/// Together with [`lexsort_to_indices`](crate::compute::sort::lexsort_to_indices), this
+/// sorts a whole [`Chunk`].
+/// # Errors
+/// This function errors iff any of the non-null `indices` is out of range of `chunk`.
+pub fn take_chunk<A: AsRef<dyn Array>, O: Index>(
+    chunk: &Chunk<A>,
+    indices: &PrimitiveArray<O>,
+) -> Result<Chunk<Box<dyn Array>>> {
+    let len = chunk.len();
+    if let Some(index) = indices
+        .iter()
+        .flatten()
+        .find(|index| index.to_usize() >= len)
+    {
+        return Err(Error::InvalidArgumentError(format!(
+            "The index {} is out of range of a chunk of length {len}",
+            index.to_usize()
+        )));
+    }
+
+    let arrays = chunk
+        .arrays()
+        .iter()
+        .map(|array| take(array.as_ref(), indices))
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new(arrays)
+}
+
 /// Checks if an array of type `datatype` can perform take operation
 ///
 /// # Examples
@@ -141,6 +244,7 @@ pub fn can_take(data_type: &DataType) -> bool {
             | DataType::List(_)
             | DataType::LargeList(_)
             | DataType::FixedSizeList(_, _)
+            | DataType::Map(_, _)
             | DataType::Dictionary(..)
     )
 }
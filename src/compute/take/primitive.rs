@@ -7,17 +7,27 @@ use crate::{
 
 use super::Index;
 
+// gathers `values` at `indices`, copying runs of consecutive indices as a single slice
+fn take_values<T: NativeType, I: Index>(values: &[T], indices: &[I]) -> Vec<T> {
+    let mut result = Vec::<T>::with_capacity(indices.len());
+
+    let mut indices = indices.iter().map(|index| index.to_usize()).peekable();
+    while let Some(start) = indices.next() {
+        let mut end = start + 1;
+        while indices.next_if_eq(&end).is_some() {
+            end += 1;
+        }
+        result.extend_from_slice(&values[start..end]);
+    }
+    result
+}
+
 // take implementation when neither values nor indices contain nulls
 fn take_no_validity<T: NativeType, I: Index>(
     values: &[T],
     indices: &[I],
 ) -> (Buffer<T>, Option<Bitmap>) {
-    let values = indices
-        .iter()
-        .map(|index| values[index.to_usize()])
-        .collect::<Vec<_>>();
-
-    (values.into(), None)
+    (take_values(values, indices).into(), None)
 }
 
 // take implementation when only values contain nulls
@@ -32,12 +42,7 @@ fn take_values_validity<T: NativeType, I: Index>(
         .map(|index| values_validity.get_bit(index.to_usize()));
     let validity = MutableBitmap::from_trusted_len_iter(validity);
 
-    let values_values = values.values();
-
-    let values = indices
-        .iter()
-        .map(|index| values_values[index.to_usize()])
-        .collect::<Vec<_>>();
+    let values = take_values(values.values(), indices);
 
     (values.into(), validity.into())
 }
@@ -401,3 +401,142 @@ fn can_time(data_type: &DataType) -> bool {
             | DataType::Timestamp(_, _)
     )
 }
+
+/// A timezone of a [`DataType::Timestamp`], either a fixed offset or (with `chrono-tz`) a named one.
+enum Timezone {
+    Offset(chrono::FixedOffset),
+    #[cfg(feature = "chrono-tz")]
+    Tz(chrono_tz::Tz),
+}
+
+impl Timezone {
+    fn try_new(timezone: &str) -> Result<Self> {
+        if let Ok(offset) = parse_offset(timezone) {
+            return Ok(Self::Offset(offset));
+        }
+        #[cfg(feature = "chrono-tz")]
+        {
+            parse_offset_tz(timezone).map(Self::Tz)
+        }
+        #[cfg(not(feature = "chrono-tz"))]
+        {
+            Err(Error::InvalidArgumentError(format!(
+                "timezone \"{timezone}\" cannot be parsed (feature chrono-tz is not active)"
+            )))
+        }
+    }
+
+    /// Returns the wall-clock in this timezone of the instant `utc`.
+    fn to_local(&self, utc: &chrono::NaiveDateTime) -> chrono::NaiveDateTime {
+        use chrono::TimeZone;
+        match self {
+            Self::Offset(timezone) => timezone.from_utc_datetime(utc).naive_local(),
+            #[cfg(feature = "chrono-tz")]
+            Self::Tz(timezone) => timezone.from_utc_datetime(utc).naive_local(),
+        }
+    }
+
+    /// Returns the instant of the wall-clock `local` in this timezone, the earliest one if
+    /// `local` is ambiguous and `None` if it does not exist (e.g. skipped by a DST transition).
+    fn to_utc(&self, local: &chrono::NaiveDateTime) -> Option<chrono::NaiveDateTime> {
+        use chrono::TimeZone;
+        match self {
+            Self::Offset(timezone) => timezone
+                .from_local_datetime(local)
+                .earliest()
+                .map(|x| x.naive_utc()),
+            #[cfg(feature = "chrono-tz")]
+            Self::Tz(timezone) => timezone
+                .from_local_datetime(local)
+                .earliest()
+                .map(|x| x.naive_utc()),
+        }
+    }
+}
+
+fn naive_datetime_to_timestamp(datetime: chrono::NaiveDateTime, time_unit: TimeUnit) -> i64 {
+    match time_unit {
+        TimeUnit::Second => datetime.timestamp(),
+        TimeUnit::Millisecond => datetime.timestamp_millis(),
+        TimeUnit::Microsecond => datetime.timestamp_micros(),
+        TimeUnit::Nanosecond => datetime.timestamp_nanos(),
+    }
+}
+
+/// Returns `array` as timestamps in the timezone `to_tz`, representing the same instants.
+///
+/// Arrow timestamps with a timezone are instants (counted from the Unix epoch in UTC), and the
+/// timezone only affects how they are displayed. Therefore, the values are unchanged and only the
+/// timezone of the [`DataType::Timestamp`] changes. Timestamps without a timezone (`from_tz` is `None`)
+/// are taken as instants in UTC.
+///
+/// Use [`convert_timezone`] to keep the wall-clock time instead.
+/// # Example
+/// ```
+/// use arrow2::array::Int64Array;
+/// use arrow2::compute::temporal::with_timezone;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let array = Int64Array::from_slice([0]);
+/// let result = with_timezone(&array, TimeUnit::Second, Some("+01:00"), "-02:00").unwrap();
+/// assert_eq!(result.values().as_slice(), &[0]);
+/// assert_eq!(result.data_type(), &DataType::Timestamp(TimeUnit::Second, Some("-02:00".to_string())));
+/// ```
+/// # Errors
+/// This function errors iff `from_tz` or `to_tz` is not a valid timezone.
+pub fn with_timezone(
+    array: &PrimitiveArray<i64>,
+    from_unit: TimeUnit,
+    from_tz: Option<&str>,
+    to_tz: &str,
+) -> Result<PrimitiveArray<i64>> {
+    from_tz.map(Timezone::try_new).transpose()?;
+    Timezone::try_new(to_tz)?;
+    Ok(array
+        .clone()
+        .to(DataType::Timestamp(from_unit, Some(to_tz.to_string()))))
+}
+
+/// Returns `array` as timestamps in the timezone `to_tz` with the same wall-clock time as in
+/// `from_tz`, e.g. `10:00` in `+01:00` becomes `10:00` in `-02:00` (i.e. a different instant).
+///
+/// Timestamps without a timezone (`from_tz` is `None`) are wall-clock times, which are localized
+/// to `to_tz`. Wall-clock times that are ambiguous in `to_tz` (e.g. repeated when clocks are set
+/// back) take the earliest instant, and wall-clock times that do not exist in `to_tz` (e.g.
+/// skipped when clocks are set forward) are null.
+///
+/// Use [`with_timezone`] to keep the instants instead.
+/// # Example
+/// ```
+/// use arrow2::array::Int64Array;
+/// use arrow2::compute::temporal::convert_timezone;
+/// use arrow2::datatypes::TimeUnit;
+///
+/// // 01:00 in +01:00 is 01:00 in -02:00, 3 hours later
+/// let array = Int64Array::from_slice([0]);
+/// let result = convert_timezone(&array, TimeUnit::Second, Some("+01:00"), "-02:00").unwrap();
+/// assert_eq!(result.values().as_slice(), &[3 * 60 * 60]);
+/// ```
+/// # Errors
+/// This function errors iff `from_tz` or `to_tz` is not a valid timezone.
+pub fn convert_timezone(
+    array: &PrimitiveArray<i64>,
+    from_unit: TimeUnit,
+    from_tz: Option<&str>,
+    to_tz: &str,
+) -> Result<PrimitiveArray<i64>> {
+    let from = from_tz.map(Timezone::try_new).transpose()?;
+    let to = Timezone::try_new(to_tz)?;
+
+    let iter = array.iter().map(|x| {
+        let datetime = timestamp_to_naive_datetime(*x?, from_unit);
+        let local = match &from {
+            Some(from) => from.to_local(&datetime),
+            None => datetime,
+        };
+        to.to_utc(&local)
+            .map(|utc| naive_datetime_to_timestamp(utc, from_unit))
+    });
+    Ok(PrimitiveArray::from_trusted_len_iter(iter)
+        .to(DataType::Timestamp(from_unit, Some(to_tz.to_string()))))
+}
@@ -1,12 +1,15 @@
 //! Defines common maps to a [`Utf8Array`]
 
 use crate::{
-    array::{Array, Utf8Array},
+    array::{Array, BooleanArray, Utf8Array},
+    bitmap::{Bitmap, MutableBitmap},
     datatypes::DataType,
     error::{Error, Result},
-    offset::Offset,
+    offset::{Offset, Offsets},
 };
 
+use super::utils::{check_same_len, combine_validities};
+
 /// utf8_apply will apply `Fn(&str) -> String` to every value in Utf8Array.
 pub fn utf8_apply<O: Offset, F: Fn(&str) -> String>(f: F, array: &Utf8Array<O>) -> Utf8Array<O> {
     let iter = array.values_iter().map(f);
@@ -98,3 +101,306 @@ pub fn lower(array: &dyn Array) -> Result<Box<dyn Array>> {
 pub fn can_lower(data_type: &DataType) -> bool {
     matches!(data_type, DataType::LargeUtf8 | DataType::Utf8)
 }
+
+/// Returns a new [`Utf8Array`] whose `i`-th element is the concatenation of the `i`-th element
+/// of each of `arrays`, separated by `separator`.
+///
+/// When `skip_nulls` is `false`, a null in any of `arrays` makes the row null. When it is `true`,
+/// nulls are skipped (without adding a separator), and the row is only null if all elements are.
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::concat_str;
+///
+/// let a = Utf8Array::<i32>::from([Some("a"), Some("b"), None]);
+/// let b = Utf8Array::<i32>::from([Some("1"), None, None]);
+///
+/// let result = concat_str(&[&a, &b], "-", false).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("a-1"), None, None]));
+///
+/// let result = concat_str(&[&a, &b], "-", true).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("a-1"), Some("b"), None]));
+/// ```
+/// # Errors
+/// This function errors iff `arrays` is empty, the arrays have different lengths or the
+/// result overflows `O`.
+pub fn concat_str<O: Offset>(
+    arrays: &[&Utf8Array<O>],
+    separator: &str,
+    skip_nulls: bool,
+) -> Result<Utf8Array<O>> {
+    let length = arrays
+        .first()
+        .ok_or_else(|| {
+            Error::InvalidArgumentError("concat_str requires at least one array".to_string())
+        })?
+        .len();
+    if arrays.iter().any(|array| array.len() != length) {
+        return Err(Error::InvalidArgumentError(
+            "Arrays must have the same length".to_string(),
+        ));
+    }
+
+    let capacity = arrays
+        .iter()
+        .map(|array| array.values().len())
+        .sum::<usize>()
+        + separator.len() * arrays.len().saturating_sub(1) * length;
+    let mut values = Vec::<u8>::with_capacity(capacity);
+    let mut offsets = Offsets::<O>::with_capacity(length);
+    let mut validity = MutableBitmap::with_capacity(length);
+
+    for index in 0..length {
+        let start = values.len();
+        // whether any value was written, and whether a null was found
+        let (mut any_valid, mut any_null) = (false, false);
+        for array in arrays {
+            if array.is_valid(index) {
+                if any_valid {
+                    values.extend_from_slice(separator.as_bytes());
+                }
+                // safety: `index < length == array.len()`
+                values.extend_from_slice(unsafe { array.value_unchecked(index) }.as_bytes());
+                any_valid = true;
+            } else if !skip_nulls {
+                any_null = true;
+                break;
+            }
+        }
+        let is_valid = any_valid && !any_null;
+        if !is_valid {
+            values.truncate(start);
+        }
+        offsets.try_push_usize(values.len() - start)?;
+        validity.push(is_valid);
+    }
+
+    // Safety: the values are a concatenation of valid utf8 strings and the offsets are in bounds
+    Ok(unsafe {
+        Utf8Array::<O>::new_unchecked(
+            Utf8Array::<O>::default_data_type(),
+            offsets.into(),
+            values.into(),
+            validity.into(),
+        )
+    })
+}
+
+/// Returns a new [`Utf8Array`] where all non-overlapping occurrences of `from` in each value
+/// are replaced by `to`, scanning from left to right (like [`str::replace`]).
+///
+/// An empty `from` leaves the values unchanged. Nulls are propagated.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::replace;
+///
+/// let array = Utf8Array::<i32>::from([Some("aaa"), None, Some("bab")]);
+/// let result = replace(&array, "aa", "c");
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("ca"), None, Some("bab")]));
+/// ```
+/// # Panics
+/// This function panics iff the result overflows `O`.
+pub fn replace<O: Offset>(array: &Utf8Array<O>, from: &str, to: &str) -> Utf8Array<O> {
+    if from.is_empty() {
+        return array.clone();
+    }
+
+    let mut values = Vec::<u8>::with_capacity(array.values().len());
+    let mut offsets = Offsets::<O>::with_capacity(array.len());
+
+    for value in array.iter() {
+        let start = values.len();
+        if let Some(value) = value {
+            let mut last = 0;
+            for (index, _) in value.match_indices(from) {
+                values.extend_from_slice(value[last..index].as_bytes());
+                values.extend_from_slice(to.as_bytes());
+                last = index + from.len();
+            }
+            values.extend_from_slice(value[last..].as_bytes());
+        }
+        offsets.try_push_usize(values.len() - start).unwrap();
+    }
+
+    // Safety: `from` and `to` are valid utf8 and `from` matches on char boundaries, so the
+    // values are valid utf8. The offsets are in bounds by construction.
+    unsafe {
+        Utf8Array::<O>::new_unchecked(
+            array.data_type().clone(),
+            offsets.into(),
+            values.into(),
+            array.validity().cloned(),
+        )
+    }
+}
+
+fn utf8_predicate_scalar<O: Offset, F: Fn(&str) -> bool>(
+    array: &Utf8Array<O>,
+    op: F,
+) -> BooleanArray {
+    let values = Bitmap::from_trusted_len_iter(array.values_iter().map(op));
+    BooleanArray::new(DataType::Boolean, values, array.validity().cloned())
+}
+
+fn utf8_predicate<O: Offset, F: Fn(&str, &str) -> bool>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+    op: F,
+) -> Result<BooleanArray> {
+    check_same_len(lhs, rhs)?;
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let values = lhs
+        .values_iter()
+        .zip(rhs.values_iter())
+        .map(|(lhs, rhs)| op(lhs, rhs));
+    let values = Bitmap::from_trusted_len_iter(values);
+    Ok(BooleanArray::new(DataType::Boolean, values, validity))
+}
+
+/// Returns whether each value of `array` starts with `prefix`. Nulls are propagated.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Utf8Array};
+/// use arrow2::compute::utf8::starts_with;
+///
+/// let array = Utf8Array::<i32>::from([Some("arrow"), None, Some("parquet")]);
+/// let result = starts_with(&array, "ar");
+/// assert_eq!(result, BooleanArray::from([Some(true), None, Some(false)]));
+/// ```
+pub fn starts_with<O: Offset>(array: &Utf8Array<O>, prefix: &str) -> BooleanArray {
+    utf8_predicate_scalar(array, |x| x.starts_with(prefix))
+}
+
+/// Returns whether each value of `array` ends with `suffix`. Nulls are propagated.
+pub fn ends_with<O: Offset>(array: &Utf8Array<O>, suffix: &str) -> BooleanArray {
+    utf8_predicate_scalar(array, |x| x.ends_with(suffix))
+}
+
+/// Returns whether each value of `array` contains `pattern`. Nulls are propagated.
+pub fn contains<O: Offset>(array: &Utf8Array<O>, pattern: &str) -> BooleanArray {
+    utf8_predicate_scalar(array, |x| x.contains(pattern))
+}
+
+/// Returns whether each value of `lhs` starts with the value of `rhs` in the same row.
+/// The result is null where either is null.
+/// # Errors
+/// Errors iff the arrays have a different length.
+pub fn starts_with_array<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+) -> Result<BooleanArray> {
+    utf8_predicate(lhs, rhs, |lhs, rhs| lhs.starts_with(rhs))
+}
+
+/// Returns whether each value of `lhs` ends with the value of `rhs` in the same row.
+/// The result is null where either is null.
+/// # Errors
+/// Errors iff the arrays have a different length.
+pub fn ends_with_array<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    utf8_predicate(lhs, rhs, |lhs, rhs| lhs.ends_with(rhs))
+}
+
+/// Returns whether each value of `lhs` contains the value of `rhs` in the same row.
+/// The result is null where either is null.
+/// # Errors
+/// Errors iff the arrays have a different length.
+pub fn contains_array<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    utf8_predicate(lhs, rhs, |lhs, rhs| lhs.contains(rhs))
+}
+
+fn pad<O: Offset>(
+    array: &Utf8Array<O>,
+    length: usize,
+    fill: char,
+    truncate: bool,
+    left: bool,
+) -> Utf8Array<O> {
+    let mut fill_bytes = [0; 4];
+    let fill = fill.encode_utf8(&mut fill_bytes).as_bytes();
+
+    let mut values = Vec::<u8>::with_capacity(array.values().len());
+    let mut offsets = Offsets::<O>::with_capacity(array.len());
+
+    for value in array.iter() {
+        let start = values.len();
+        if let Some(value) = value {
+            // the number of characters, and the byte index of the `length`-th character (if any)
+            let mut chars = value.char_indices().map(|(index, _)| index);
+            let end = chars.nth(length);
+            let n_chars = if end.is_some() {
+                length + 1 + chars.count()
+            } else {
+                value.chars().count()
+            };
+
+            if n_chars >= length {
+                let value = match end {
+                    Some(end) if truncate => &value[..end],
+                    _ => value,
+                };
+                values.extend_from_slice(value.as_bytes());
+            } else {
+                let padding = (0..length - n_chars).flat_map(|_| fill.iter().copied());
+                if left {
+                    values.extend(padding);
+                    values.extend_from_slice(value.as_bytes());
+                } else {
+                    values.extend_from_slice(value.as_bytes());
+                    values.extend(padding);
+                }
+            }
+        }
+        offsets.try_push_usize(values.len() - start).unwrap();
+    }
+
+    // Safety: the values are a concatenation of valid utf8 strings and characters, cut on char
+    // boundaries. The offsets are in bounds by construction.
+    unsafe {
+        Utf8Array::<O>::new_unchecked(
+            array.data_type().clone(),
+            offsets.into(),
+            values.into(),
+            array.validity().cloned(),
+        )
+    }
+}
+
+/// Returns a new [`Utf8Array`] where each value is left-padded with `fill` to `length` characters.
+///
+/// Values longer than `length` characters are truncated to their first `length` characters if
+/// `truncate` is `true` and are left unchanged otherwise. Nulls are propagated.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::lpad;
+///
+/// let array = Utf8Array::<i32>::from([Some("1"), None, Some("12345")]);
+/// let result = lpad(&array, 3, '0', true);
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("001"), None, Some("123")]));
+/// ```
+/// # Panics
+/// This function panics iff the result overflows `O`.
+pub fn lpad<O: Offset>(
+    array: &Utf8Array<O>,
+    length: usize,
+    fill: char,
+    truncate: bool,
+) -> Utf8Array<O> {
+    pad(array, length, fill, truncate, true)
+}
+
+/// Returns a new [`Utf8Array`] where each value is right-padded with `fill` to `length` characters.
+///
+/// Values longer than `length` characters are truncated to their first `length` characters if
+/// `truncate` is `true` and are left unchanged otherwise. Nulls are propagated.
+/// # Panics
+/// This function panics iff the result overflows `O`.
+pub fn rpad<O: Offset>(
+    array: &Utf8Array<O>,
+    length: usize,
+    fill: char,
+    truncate: bool,
+) -> Utf8Array<O> {
+    pad(array, length, fill, truncate, false)
+}
@@ -0,0 +1,131 @@
+use super::DataType;
+
+/// Returns the common [`DataType`] that both `lhs` and `rhs` can be represented as,
+/// or `None` if there is no such type.
+///
+/// The rules are:
+/// * equal types are their own common type and [`DataType::Null`] coerces to any type
+/// * integers widen to the narrowest integer holding both (e.g. `Int8` and `UInt8` become `Int16`)
+/// * integers and floats become the narrowest float holding the integer exactly; 64-bit
+///   integers have no such float, so e.g. `Int64` and `Float64` have no common type
+/// * floats widen to the widest float
+/// * decimals (and integers with decimals) become a decimal with the largest scale and
+///   enough precision to hold the integral digits of both, using `Decimal256` when
+///   the precision exceeds 38
+/// * `Utf8` and `LargeUtf8` become `LargeUtf8`, and `Binary` and `LargeBinary` become `LargeBinary`
+///
+/// Any other combination, such as `UInt64` and `Int64`, has no lossless common type.
+pub fn coerce_types(lhs: &DataType, rhs: &DataType) -> Option<DataType> {
+    use DataType::*;
+    if lhs == rhs {
+        return Some(lhs.clone());
+    }
+    match (lhs, rhs) {
+        (Null, other) | (other, Null) => Some(other.clone()),
+        (Utf8 | LargeUtf8, Utf8 | LargeUtf8) => Some(LargeUtf8),
+        (Binary | LargeBinary, Binary | LargeBinary) => Some(LargeBinary),
+        (Float16 | Float32 | Float64, Float16 | Float32 | Float64) => Some(
+            if float_width(lhs) >= float_width(rhs) {
+                lhs
+            } else {
+                rhs
+            }
+            .clone(),
+        ),
+        (Float16 | Float32 | Float64, other) | (other, Float16 | Float32 | Float64)
+            if is_integer(other) =>
+        {
+            let (width, _) = integer_width(other);
+            // a float holds integers exactly up to its mantissa, i.e. it must be wider
+            // than the integer
+            let width = (width * 2).max(float_width(if is_integer(lhs) { rhs } else { lhs }));
+            match width {
+                2 => Some(Float16),
+                4 => Some(Float32),
+                8 => Some(Float64),
+                _ => None,
+            }
+        }
+        (lhs, rhs) if is_integer(lhs) && is_integer(rhs) => {
+            let (lhs_width, lhs_signed) = integer_width(lhs);
+            let (rhs_width, rhs_signed) = integer_width(rhs);
+            if lhs_signed == rhs_signed {
+                return Some(if lhs_width >= rhs_width { lhs } else { rhs }.clone());
+            }
+            let (signed, unsigned) = if lhs_signed {
+                (lhs_width, rhs_width)
+            } else {
+                (rhs_width, lhs_width)
+            };
+            // a signed integer holds an unsigned integer iff it is wider
+            match signed.max(unsigned * 2) {
+                2 => Some(Int16),
+                4 => Some(Int32),
+                8 => Some(Int64),
+                _ => None,
+            }
+        }
+        (lhs, rhs) => {
+            let (lhs_precision, lhs_scale) = decimal_precision_scale(lhs)?;
+            let (rhs_precision, rhs_scale) = decimal_precision_scale(rhs)?;
+            let scale = lhs_scale.max(rhs_scale);
+            let integral = (lhs_precision.saturating_sub(lhs_scale))
+                .max(rhs_precision.saturating_sub(rhs_scale));
+            let precision = integral + scale;
+            let is_256 = matches!(lhs, Decimal256(_, _)) || matches!(rhs, Decimal256(_, _));
+            if precision <= 38 && !is_256 {
+                Some(Decimal(precision, scale))
+            } else if precision <= 76 {
+                Some(Decimal256(precision, scale))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+fn is_integer(data_type: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        data_type,
+        Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64
+    )
+}
+
+/// The width in bytes and signedness of an integer type
+fn integer_width(data_type: &DataType) -> (usize, bool) {
+    use DataType::*;
+    match data_type {
+        Int8 => (1, true),
+        Int16 => (2, true),
+        Int32 => (4, true),
+        Int64 => (8, true),
+        UInt8 => (1, false),
+        UInt16 => (2, false),
+        UInt32 => (4, false),
+        UInt64 => (8, false),
+        _ => unreachable!(),
+    }
+}
+
+fn float_width(data_type: &DataType) -> usize {
+    match data_type {
+        DataType::Float16 => 2,
+        DataType::Float32 => 4,
+        _ => 8,
+    }
+}
+
+/// The precision and scale of a decimal type, or of the decimal holding all values of an integer type
+fn decimal_precision_scale(data_type: &DataType) -> Option<(usize, usize)> {
+    use DataType::*;
+    match data_type {
+        Decimal(precision, scale) | Decimal256(precision, scale) => Some((*precision, *scale)),
+        Int8 | UInt8 => Some((3, 0)),
+        Int16 | UInt16 => Some((5, 0)),
+        Int32 | UInt32 => Some((10, 0)),
+        Int64 => Some((19, 0)),
+        UInt64 => Some((20, 0)),
+        _ => None,
+    }
+}
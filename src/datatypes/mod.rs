@@ -1,10 +1,12 @@
 #![forbid(unsafe_code)]
 //! Contains all metadata, such as [`PhysicalType`], [`DataType`], [`Field`] and [`Schema`].
 
+mod coerce;
 mod field;
 mod physical_type;
 mod schema;
 
+pub use coerce::coerce_types;
 pub use field::Field;
 pub use physical_type::*;
 pub use schema::Schema;
@@ -158,6 +160,10 @@ pub enum DataType {
     Decimal256(usize, usize),
     /// Extension type.
     Extension(String, Box<DataType>, Option<String>),
+    /// A run-end encoded array (`run_ends_type`, `values`), where consecutive equal values
+    /// (runs) are stored once in the field `values`, together with the (exclusive) logical
+    /// index at which each run ends, of `run_ends_type`.
+    RunEndEncoded(IntegerType, Box<Field>),
 }
 
 #[cfg(feature = "arrow")]
@@ -216,10 +222,21 @@ impl From<DataType> for arrow_schema::DataType {
             DataType::Decimal(precision, scale) => Self::Decimal128(precision as _, scale as _),
             DataType::Decimal256(precision, scale) => Self::Decimal256(precision as _, scale as _),
             DataType::Extension(_, d, _) => (*d).into(),
+            DataType::RunEndEncoded(run_ends, values) => Self::RunEndEncoded(
+                Arc::new(ArrowField::new(
+                    "run_ends",
+                    DataType::from(run_ends).into(),
+                    false,
+                )),
+                Arc::new((*values).into()),
+            ),
         }
     }
 }
 
+/// # Panics
+/// This conversion panics iff `value` is a dictionary whose keys are not integers or a run-end
+/// encoded type whose run ends are not `Int16`, `Int32` or `Int64`.
 #[cfg(feature = "arrow")]
 impl From<arrow_schema::DataType> for DataType {
     fn from(value: arrow_schema::DataType) -> Self {
@@ -278,7 +295,15 @@ impl From<arrow_schema::DataType> for DataType {
             }
             DataType::Decimal128(precision, scale) => Self::Decimal(precision as _, scale as _),
             DataType::Decimal256(precision, scale) => Self::Decimal256(precision as _, scale as _),
-            DataType::RunEndEncoded(_, _) => panic!("Run-end encoding not supported by arrow2"),
+            DataType::RunEndEncoded(run_ends, values) => {
+                let run_ends = match run_ends.data_type() {
+                    DataType::Int16 => IntegerType::Int16,
+                    DataType::Int32 => IntegerType::Int32,
+                    DataType::Int64 => IntegerType::Int64,
+                    d => panic!("illegal run ends type: {d}"),
+                };
+                Self::RunEndEncoded(run_ends, Box::new(values.into()))
+            }
         }
     }
 }
@@ -448,6 +473,7 @@ impl DataType {
             Union(_, _, _) => PhysicalType::Union,
             Map(_, _) => PhysicalType::Map,
             Dictionary(key, _, _) => PhysicalType::Dictionary(*key),
+            RunEndEncoded(run_ends, _) => PhysicalType::RunEndEncoded(*run_ends),
             Extension(_, key, _) => key.to_physical_type(),
         }
     }
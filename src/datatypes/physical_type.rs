@@ -39,6 +39,8 @@ pub enum PhysicalType {
     Map,
     /// A dictionary encoded array by `IntegerType`.
     Dictionary(IntegerType),
+    /// A run-end encoded array by `IntegerType` of its run ends.
+    RunEndEncoded(IntegerType),
 }
 
 impl PhysicalType {
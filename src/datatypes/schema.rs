@@ -1,4 +1,5 @@
-use super::{Field, Metadata};
+use super::{coerce_types, Field, Metadata};
+use crate::error::{Error, Result};
 
 #[cfg(feature = "serde_types")]
 use serde_derive::{Deserialize, Serialize};
@@ -48,6 +49,46 @@ impl Schema {
             metadata: self.metadata,
         }
     }
+
+    /// Returns a new [`Schema`] with the union of the fields of `self` and `other`.
+    ///
+    /// Fields are matched by name: fields of `self` keep their position,
+    /// and fields only present in `other` are appended in their order.
+    /// A field present in both is nullable if it is nullable in either, and its
+    /// data type is the common type of both (see [`coerce_types`]).
+    /// The metadata of `self` takes precedence over the metadata of `other`.
+    /// # Errors
+    /// Iff a field present in both schemas has data types without a common type
+    /// (e.g. `Int32` and `Utf8`). In particular, `Int64` or `UInt64` and a float have no common
+    /// type, as no float holds every 64-bit integer exactly.
+    pub fn merge(&self, other: &Schema) -> Result<Schema> {
+        let mut fields = self.fields.clone();
+        for field in &other.fields {
+            if let Some(existing) = fields.iter_mut().find(|f| f.name == field.name) {
+                existing.data_type = coerce_types(&existing.data_type, &field.data_type)
+                    .ok_or_else(|| {
+                        Error::InvalidArgumentError(format!(
+                            "Field \"{}\" cannot be merged: {:?} and {:?} have no common type",
+                            field.name, existing.data_type, field.data_type
+                        ))
+                    })?;
+                existing.is_nullable |= field.is_nullable;
+                for (key, value) in &field.metadata {
+                    existing
+                        .metadata
+                        .entry(key.clone())
+                        .or_insert_with(|| value.clone());
+                }
+            } else {
+                fields.push(field.clone());
+            }
+        }
+
+        let mut metadata = other.metadata.clone();
+        metadata.extend(self.metadata.clone());
+
+        Ok(Schema { fields, metadata })
+    }
 }
 
 impl From<Vec<Field>> for Schema {
@@ -43,6 +43,11 @@ pub unsafe fn try_from<A: ArrowArrayRef>(array: A) -> Result<Box<dyn Array>> {
         }
         Union => Box::new(UnionArray::try_from_ffi(array)?),
         Map => Box::new(MapArray::try_from_ffi(array)?),
+        RunEndEncoded(run_ends_type) => {
+            match_integer_type!(run_ends_type, |$T| {
+                Box::new(RunEndEncodedArray::<$T>::try_from_ffi(array)?)
+            })
+        }
     })
 }
 
@@ -35,5 +35,10 @@ pub fn align_to_c_data_interface(array: Box<dyn Array>) -> Box<dyn Array> {
                 ffi_dyn!(array, DictionaryArray<$T>)
             })
         }
+        RunEndEncoded(run_ends_type) => {
+            match_integer_type!(run_ends_type, |$T| {
+                ffi_dyn!(array, RunEndEncodedArray<$T>)
+            })
+        }
     }
 }
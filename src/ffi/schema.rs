@@ -51,6 +51,13 @@ fn schema_children(data_type: &DataType, flags: &mut i64) -> Box<[*mut ArrowSche
             .iter()
             .map(|field| Box::into_raw(Box::new(ArrowSchema::new(field))))
             .collect::<Box<[_]>>(),
+        DataType::RunEndEncoded(run_ends, field) => {
+            let run_ends = Field::new("run_ends", (*run_ends).into(), false);
+            Box::new([
+                Box::into_raw(Box::new(ArrowSchema::new(&run_ends))),
+                Box::into_raw(Box::new(ArrowSchema::new(field.as_ref()))),
+            ])
+        }
         DataType::Extension(_, inner, _) => schema_children(inner, flags),
         _ => Box::new([]),
     }
@@ -281,6 +288,14 @@ unsafe fn to_data_type(schema: &ArrowSchema) -> Result<DataType> {
                 .collect::<Result<Vec<_>>>()?;
             DataType::Struct(children)
         }
+        "+r" => {
+            let run_ends = to_integer_type(schema.child(0).format()).map_err(|_| {
+                Error::OutOfSpec(
+                    "The run ends of a run-end encoded array must be integers".to_string(),
+                )
+            })?;
+            DataType::RunEndEncoded(run_ends, Box::new(to_field(schema.child(1))?))
+        }
         other => {
             match other.splitn(2, ':').collect::<Vec<_>>()[..] {
                 // Timestamps with no timezone
@@ -462,6 +477,7 @@ fn to_format(data_type: &DataType) -> String {
         DataType::Map(_, _) => "+m".to_string(),
         DataType::Dictionary(index, _, _) => to_format(&(*index).into()),
         DataType::Extension(_, inner, _) => to_format(inner.as_ref()),
+        DataType::RunEndEncoded(_, _) => "+r".to_string(),
     }
 }
 
@@ -471,6 +487,8 @@ pub(super) fn get_child(data_type: &DataType, index: usize) -> Result<DataType>
         (0, DataType::FixedSizeList(field, _)) => Ok(field.data_type().clone()),
         (0, DataType::LargeList(field)) => Ok(field.data_type().clone()),
         (0, DataType::Map(field, _)) => Ok(field.data_type().clone()),
+        (0, DataType::RunEndEncoded(run_ends, _)) => Ok((*run_ends).into()),
+        (1, DataType::RunEndEncoded(_, field)) => Ok(field.data_type().clone()),
         (index, DataType::Struct(fields)) => Ok(fields[index].data_type().clone()),
         (index, DataType::Union(fields, _, _)) => Ok(fields[index].data_type().clone()),
         (index, DataType::Extension(_, subtype, _)) => get_child(subtype, index),
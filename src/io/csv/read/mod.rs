@@ -6,8 +6,14 @@ mod reader;
 pub use csv::{ByteRecord, Reader, ReaderBuilder};
 
 mod infer_schema;
+#[cfg(feature = "io_csv_read_parallel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_csv_read_parallel")))]
+mod parallel;
 
 pub use super::utils::infer;
 pub use deserialize::{deserialize_batch, deserialize_column};
 pub use infer_schema::infer_schema;
+#[cfg(feature = "io_csv_read_parallel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_csv_read_parallel")))]
+pub use parallel::read_parallel;
 pub use reader::*;
@@ -0,0 +1,108 @@
+use rayon::prelude::*;
+
+use super::{deserialize_batch, deserialize_column, ByteRecord, ReaderBuilder};
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::compute::concatenate::concatenate;
+use crate::datatypes::Field;
+use crate::error::Result;
+
+/// Splits `data` in at most `n` slices of whole records, of roughly equal length.
+///
+/// Slices end on a newline that is not inside a field quoted by `quote`, so that
+/// a record whose quoted fields contain newlines is never split.
+fn split_records(data: &[u8], quote: u8, n: usize) -> Vec<&[u8]> {
+    let n = n.max(1);
+    let target = ((data.len() + n - 1) / n).max(1);
+
+    let mut slices = vec![];
+    let mut start = 0;
+    let mut in_quotes = false;
+    for (index, byte) in data.iter().enumerate() {
+        if *byte == quote {
+            // an escaped quote (`""`) toggles twice and thus leaves the state unchanged
+            in_quotes = !in_quotes;
+        } else if *byte == b'\n' && !in_quotes && index + 1 - start >= target {
+            slices.push(&data[start..index + 1]);
+            start = index + 1;
+        }
+    }
+    if start < data.len() {
+        slices.push(&data[start..]);
+    }
+    slices
+}
+
+/// Reads the records of `data` into a [`Chunk`], parsing `n_chunks` slices of it in parallel.
+///
+/// `data` is split in newline-aligned slices that are read and deserialized in parallel
+/// (via `rayon`) and concatenated back in order. `data` must not contain the header
+/// (e.g. skip the first [`csv::Position::byte`] bytes of the file after [`super::infer_schema`]).
+///
+/// `quote` is the quote character used to split `data` and to read each slice.
+/// `reader_builder` creates the [`ReaderBuilder`] used to read each slice; its `quote` and
+/// `has_headers` are always overwritten by `quote` and `false` respectively.
+/// # Errors
+/// Iff any record is invalid CSV or cannot be deserialized into `fields`.
+pub fn read_parallel<F>(
+    data: &[u8],
+    fields: &[Field],
+    projection: Option<&[usize]>,
+    n_chunks: usize,
+    quote: u8,
+    reader_builder: F,
+) -> Result<Chunk<Box<dyn Array>>>
+where
+    F: Fn() -> ReaderBuilder + Sync,
+{
+    let rows = split_records(data, quote, n_chunks)
+        .into_par_iter()
+        .map(|slice| {
+            let mut reader = reader_builder()
+                .quote(quote)
+                .has_headers(false)
+                .from_reader(slice);
+            let mut rows = vec![];
+            let mut row = ByteRecord::new();
+            while reader.read_byte_record(&mut row)? {
+                rows.push(std::mem::take(&mut row));
+            }
+            Ok(rows)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // the line number of the first row of each slice
+    let line_numbers = rows
+        .iter()
+        .scan(0, |line_number, rows| {
+            let start = *line_number;
+            *line_number += rows.len();
+            Some(start)
+        })
+        .collect::<Vec<_>>();
+
+    let chunks = rows
+        .par_iter()
+        .zip(line_numbers.into_par_iter())
+        .map(|(rows, line_number)| {
+            deserialize_batch(rows, fields, projection, line_number, deserialize_column)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if chunks.is_empty() {
+        return deserialize_batch(&[], fields, projection, 0, deserialize_column);
+    }
+
+    let n_columns = chunks[0].arrays().len();
+    let arrays = (0..n_columns)
+        .map(|column| {
+            let arrays = chunks
+                .iter()
+                .map(|chunk| chunk.arrays()[column].as_ref())
+                .collect::<Vec<_>>();
+            concatenate(&arrays)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new(arrays)
+}
@@ -1,7 +1,10 @@
 use std::io::Read;
 
-use super::{ByteRecord, Reader};
+use super::{deserialize_batch, deserialize_column, ByteRecord, Reader};
 
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Field;
 use crate::error::{Error, Result};
 
 /// Reads `len` rows from `reader` into `row`, skiping the first `skip`.
@@ -33,3 +36,88 @@ pub fn read_rows<R: Read>(
     }
     Ok(row_number)
 }
+
+/// An iterator of [`Chunk`]s of at most `batch_size` rows read from a CSV [`Reader`].
+///
+/// Only one batch of rows is held in memory at a time, so that arbitrarily large files can be
+/// read with bounded memory. Records spanning the reader's internal buffer are handled by
+/// the underlying [`Reader`].
+/// # Example
+/// ```
+/// use std::io::Cursor;
+/// use arrow2::io::csv::read::{infer, infer_schema, CsvBatchReader, ReaderBuilder};
+///
+/// let mut reader = ReaderBuilder::new().from_reader(Cursor::new("a,b\n1,x\n2,y\n3,z"));
+/// let (fields, _) = infer_schema(&mut reader, None, true, &infer).unwrap();
+///
+/// let batches = CsvBatchReader::new(reader, fields, 2)
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+/// assert_eq!(batches.iter().map(|x| x.len()).collect::<Vec<_>>(), vec![2, 1]);
+/// ```
+pub struct CsvBatchReader<R: Read> {
+    reader: Reader<R>,
+    fields: Vec<Field>,
+    projection: Option<Vec<usize>>,
+    rows: Vec<ByteRecord>,
+    line_number: usize,
+}
+
+impl<R: Read> CsvBatchReader<R> {
+    /// Creates a new [`CsvBatchReader`] deserializing `fields` from `reader`,
+    /// `batch_size` rows at a time.
+    ///
+    /// `reader` is read from its current position, e.g. after [`super::infer_schema`]
+    /// has seeked it back to the first record.
+    /// # Panics
+    /// Iff `batch_size` is zero
+    pub fn new(reader: Reader<R>, fields: Vec<Field>, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "batch_size must be larger than zero");
+        Self {
+            reader,
+            fields,
+            projection: None,
+            rows: vec![ByteRecord::default(); batch_size],
+            line_number: 0,
+        }
+    }
+
+    /// Sets the indices of the columns to deserialize.
+    pub fn with_projection(mut self, projection: Vec<usize>) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Returns the position in the underlying reader after the last record read.
+    pub fn position(&self) -> &csv::Position {
+        self.reader.position()
+    }
+
+    /// Returns the inner [`Reader`]
+    pub fn into_inner(self) -> Reader<R> {
+        self.reader
+    }
+}
+
+impl<R: Read> Iterator for CsvBatchReader<R> {
+    type Item = Result<Chunk<Box<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rows_read = match read_rows(&mut self.reader, 0, &mut self.rows) {
+            Ok(rows_read) => rows_read,
+            Err(e) => return Some(Err(e)),
+        };
+        if rows_read == 0 {
+            return None;
+        }
+        let chunk = deserialize_batch(
+            &self.rows[..rows_read],
+            &self.fields,
+            self.projection.as_deref(),
+            self.line_number,
+            deserialize_column,
+        );
+        self.line_number += rows_read;
+        Some(chunk)
+    }
+}
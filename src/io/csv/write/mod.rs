@@ -9,7 +9,8 @@ pub use serialize::*;
 
 use crate::array::Array;
 use crate::chunk::Chunk;
-use crate::error::Result;
+use crate::datatypes::{DataType, Schema};
+use crate::error::{Error, Result};
 
 /// Creates serializers that iterate over each column that serializes each item according
 /// to `options`.
@@ -23,6 +24,26 @@ fn new_serializers<'a, A: AsRef<dyn Array>>(
         .collect()
 }
 
+/// Creates serializers like [`new_serializers`], serializing each column as its type in `data_types`.
+fn new_serializers_with_types<'a, A: AsRef<dyn Array>>(
+    columns: &'a [A],
+    data_types: &[DataType],
+    options: &'a SerializeOptions,
+) -> Result<Vec<Box<dyn StreamingIterator<Item = [u8]> + 'a>>> {
+    if columns.len() != data_types.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "The chunk has {} columns but the schema has {} fields",
+            columns.len(),
+            data_types.len()
+        )));
+    }
+    columns
+        .iter()
+        .zip(data_types)
+        .map(|(column, data_type)| new_serializer_with_type(column.as_ref(), data_type, options))
+        .collect()
+}
+
 /// Serializes [`Chunk`] to a vector of rows.
 /// The vector is guaranteed to have `columns.len()` entries.
 /// Each `row` is guaranteed to have `columns.array().len()` fields.
@@ -63,10 +84,40 @@ pub fn write_chunk<W: Write, A: AsRef<dyn Array>>(
     columns: &Chunk<A>,
     options: &SerializeOptions,
 ) -> Result<()> {
-    let mut serializers = new_serializers(columns.arrays(), options)?;
+    let serializers = new_serializers(columns.arrays(), options)?;
+    write_rows(writer, serializers, columns.len(), options)
+}
 
-    let rows = columns.len();
-    let mut row = Vec::with_capacity(columns.arrays().len() * 10);
+/// Writes [`Chunk`] to `writer` according to the serialization options `options`, formatting
+/// each column by the data type of its field in `schema` instead of the data type of the array.
+///
+/// This allows e.g. an `Int32` column of days since epoch declared as [`DataType::Date32`]
+/// to be written as a date.
+/// # Errors
+/// Errors iff the number of fields of `schema` differs from the number of columns or
+/// a column cannot be serialized as its declared data type.
+pub fn write_chunk_with_schema<W: Write, A: AsRef<dyn Array>>(
+    writer: &mut W,
+    columns: &Chunk<A>,
+    schema: &Schema,
+    options: &SerializeOptions,
+) -> Result<()> {
+    let data_types = schema
+        .fields
+        .iter()
+        .map(|field| field.data_type().clone())
+        .collect::<Vec<_>>();
+    let serializers = new_serializers_with_types(columns.arrays(), &data_types, options)?;
+    write_rows(writer, serializers, columns.len(), options)
+}
+
+fn write_rows<W: Write>(
+    writer: &mut W,
+    mut serializers: Vec<Box<dyn StreamingIterator<Item = [u8]> + '_>>,
+    rows: usize,
+    options: &SerializeOptions,
+) -> Result<()> {
+    let mut row = Vec::with_capacity(serializers.len() * 10);
 
     // this is where the (expensive) transposition happens: the outer loop is on rows, the inner on columns
     (0..rows).try_for_each(|_| {
@@ -106,3 +157,112 @@ where
     writer.write_all(&[b'\n'])?;
     Ok(())
 }
+
+/// A writer of CSV that writes [`Chunk`]s incrementally to `W`.
+///
+/// The header is written before the first chunk, and `W` is flushed after each chunk,
+/// so that only one chunk is serialized in memory at a time.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::chunk::Chunk;
+/// use arrow2::io::csv::write::{CsvWriter, SerializeOptions};
+///
+/// let mut writer = CsvWriter::new(vec![], vec!["a".to_string()], SerializeOptions::default());
+/// writer.write(&Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()])).unwrap();
+/// writer.write(&Chunk::new(vec![Int32Array::from_slice([3]).boxed()])).unwrap();
+/// assert_eq!(writer.into_inner(), b"a\n1\n2\n3\n");
+/// ```
+#[derive(Debug)]
+pub struct CsvWriter<W: Write> {
+    writer: W,
+    names: Vec<String>,
+    options: SerializeOptions,
+    data_types: Option<Vec<DataType>>,
+    has_header: bool,
+    header_written: bool,
+}
+
+impl<W: Write> CsvWriter<W> {
+    /// Creates a new [`CsvWriter`] of columns named `names`, serialized according to `options`.
+    pub fn new(writer: W, names: Vec<String>, options: SerializeOptions) -> Self {
+        Self {
+            writer,
+            names,
+            options,
+            data_types: None,
+            has_header: true,
+            header_written: false,
+        }
+    }
+
+    /// Creates a new [`CsvWriter`] of columns named and typed by the fields of `schema`.
+    /// Each column is formatted by the data type of its field (see [`write_chunk_with_schema`]).
+    pub fn from_schema(writer: W, schema: &Schema, options: SerializeOptions) -> Self {
+        Self {
+            names: schema
+                .fields
+                .iter()
+                .map(|field| field.name.clone())
+                .collect(),
+            data_types: Some(
+                schema
+                    .fields
+                    .iter()
+                    .map(|field| field.data_type().clone())
+                    .collect(),
+            ),
+            ..Self::new(writer, vec![], options)
+        }
+    }
+
+    /// Sets whether the header is written (defaults to `true`).
+    pub fn with_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    fn write_header(&mut self) -> Result<()> {
+        if self.has_header && !self.header_written {
+            write_header(&mut self.writer, &self.names, &self.options)?;
+        }
+        self.header_written = true;
+        Ok(())
+    }
+
+    /// Writes `chunk` to the inner writer, preceded by the header if this is the first chunk.
+    /// # Errors
+    /// Errors iff the number of columns of `chunk` differs from the number of names,
+    /// a column cannot be serialized, or the inner writer errors.
+    pub fn write<A: AsRef<dyn Array>>(&mut self, chunk: &Chunk<A>) -> Result<()> {
+        if chunk.arrays().len() != self.names.len() {
+            return Err(Error::InvalidArgumentError(format!(
+                "The chunk has {} columns but the writer has {} names",
+                chunk.arrays().len(),
+                self.names.len()
+            )));
+        }
+        self.write_header()?;
+        if let Some(data_types) = &self.data_types {
+            let serializers =
+                new_serializers_with_types(chunk.arrays(), data_types, &self.options)?;
+            write_rows(&mut self.writer, serializers, chunk.len(), &self.options)?;
+        } else {
+            write_chunk(&mut self.writer, chunk, &self.options)?;
+        }
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Writes the header if no chunk was written and flushes the inner writer.
+    pub fn finish(&mut self) -> Result<()> {
+        self.write_header()?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Returns the inner writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
         Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, PrimitiveArray, Utf8Array,
     },
     datatypes::{DataType, TimeUnit},
-    error::Result,
+    error::{Error, Result},
     offset::Offset,
 };
 
@@ -32,6 +32,8 @@ pub struct SerializeOptions {
     pub time64_format: Option<String>,
     /// used for [`DataType::Timestamp`]
     pub timestamp_format: Option<String>,
+    /// number of decimal places of [`DataType::Float32`] and [`DataType::Float64`]
+    pub float_precision: Option<usize>,
     /// used as separator/delimiter
     pub delimiter: u8,
     /// quoting character
@@ -46,6 +48,7 @@ impl Default for SerializeOptions {
             time32_format: None,
             time64_format: None,
             timestamp_format: None,
+            float_precision: None,
             delimiter: b',',
             quote: b'"',
         }
@@ -76,6 +79,25 @@ fn primitive_write<'a, T: NativeType + ToLexical>(
     ))
 }
 
+fn float_write<'a, T: NativeType + ToLexical + std::fmt::Display>(
+    array: &'a PrimitiveArray<T>,
+    precision: Option<usize>,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a> {
+    if let Some(precision) = precision {
+        Box::new(BufStreamingIterator::new(
+            array.iter(),
+            move |x, buf| {
+                if let Some(x) = x {
+                    let _ = write!(StringWrap(buf), "{x:.precision$}");
+                }
+            },
+            vec![],
+        ))
+    } else {
+        primitive_write(array)
+    }
+}
+
 macro_rules! dyn_primitive {
     ($ty:ty, $array:expr) => {{
         let array = $array.as_any().downcast_ref().unwrap();
@@ -280,7 +302,37 @@ pub fn new_serializer<'a>(
     array: &'a dyn Array,
     options: &'a SerializeOptions,
 ) -> Result<Box<dyn StreamingIterator<Item = [u8]> + 'a>> {
-    Ok(match array.data_type() {
+    new_serializer_with_type(array, array.data_type(), options)
+}
+
+/// Returns whether an array of type `rhs` can be serialized as `lhs`: both have the same physical
+/// type and, for dictionaries, compatible values.
+fn is_compatible(lhs: &DataType, rhs: &DataType) -> bool {
+    match (lhs.to_logical_type(), rhs.to_logical_type()) {
+        (DataType::Dictionary(lhs_keys, lhs, _), DataType::Dictionary(rhs_keys, rhs, _)) => {
+            lhs_keys == rhs_keys && is_compatible(lhs, rhs)
+        }
+        _ => lhs.to_physical_type() == rhs.to_physical_type(),
+    }
+}
+
+/// Returns a [`StreamingIterator`] that yields `&[u8]` serialized from `array` as if it was of
+/// type `data_type` (e.g. a declared [`DataType::Date32`] of an `Int32` array), according to `options`.
+/// # Error
+/// This function errors if `data_type` has a different physical type than `array` or, for
+/// dictionaries, if their values have different physical types.
+pub fn new_serializer_with_type<'a>(
+    array: &'a dyn Array,
+    data_type: &DataType,
+    options: &'a SerializeOptions,
+) -> Result<Box<dyn StreamingIterator<Item = [u8]> + 'a>> {
+    if !is_compatible(data_type, array.data_type()) {
+        return Err(Error::InvalidArgumentError(format!(
+            "An array of type {:?} cannot be serialized as {data_type:?}",
+            array.data_type()
+        )));
+    }
+    Ok(match data_type {
         DataType::Boolean => {
             let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
             Box::new(BufStreamingIterator::new(
@@ -410,10 +462,12 @@ pub fn new_serializer<'a>(
             )
         }
         DataType::Float32 => {
-            dyn_primitive!(f32, array)
+            let array = array.as_any().downcast_ref().unwrap();
+            float_write::<f32>(array, options.float_precision)
         }
         DataType::Float64 => {
-            dyn_primitive!(f64, array)
+            let array = array.as_any().downcast_ref().unwrap();
+            float_write::<f64>(array, options.float_precision)
         }
         DataType::Utf8 => {
             let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
@@ -59,21 +59,25 @@ impl From<EncodedData> for FlightData {
 }
 
 /// Serializes a [`Schema`] to [`SchemaResult`].
+/// # Errors
+/// This function errors iff the schema has a data type not supported by IPC.
 pub fn serialize_schema_to_result(
     schema: &Schema,
     ipc_fields: Option<&[IpcField]>,
-) -> SchemaResult {
-    SchemaResult {
-        schema: _serialize_schema(schema, ipc_fields),
-    }
+) -> Result<SchemaResult> {
+    Ok(SchemaResult {
+        schema: _serialize_schema(schema, ipc_fields)?,
+    })
 }
 
 /// Serializes a [`Schema`] to [`FlightData`].
-pub fn serialize_schema(schema: &Schema, ipc_fields: Option<&[IpcField]>) -> FlightData {
-    FlightData {
-        data_header: _serialize_schema(schema, ipc_fields),
+/// # Errors
+/// This function errors iff the schema has a data type not supported by IPC.
+pub fn serialize_schema(schema: &Schema, ipc_fields: Option<&[IpcField]>) -> Result<FlightData> {
+    Ok(FlightData {
+        data_header: _serialize_schema(schema, ipc_fields)?,
         ..Default::default()
-    }
+    })
 }
 
 /// Convert a [`Schema`] to bytes in the format expected in [`arrow_format::flight::data::FlightInfo`].
@@ -82,10 +86,10 @@ pub fn serialize_schema_to_info(
     ipc_fields: Option<&[IpcField]>,
 ) -> Result<Vec<u8>> {
     let encoded_data = if let Some(ipc_fields) = ipc_fields {
-        schema_as_encoded_data(schema, ipc_fields)
+        schema_as_encoded_data(schema, ipc_fields)?
     } else {
         let ipc_fields = default_ipc_fields(&schema.fields);
-        schema_as_encoded_data(schema, &ipc_fields)
+        schema_as_encoded_data(schema, &ipc_fields)?
     };
 
     let mut schema = vec![];
@@ -93,7 +97,7 @@ pub fn serialize_schema_to_info(
     Ok(schema)
 }
 
-fn _serialize_schema(schema: &Schema, ipc_fields: Option<&[IpcField]>) -> Vec<u8> {
+fn _serialize_schema(schema: &Schema, ipc_fields: Option<&[IpcField]>) -> Result<Vec<u8>> {
     if let Some(ipc_fields) = ipc_fields {
         write::schema_to_bytes(schema, ipc_fields)
     } else {
@@ -102,11 +106,11 @@ fn _serialize_schema(schema: &Schema, ipc_fields: Option<&[IpcField]>) -> Vec<u8
     }
 }
 
-fn schema_as_encoded_data(schema: &Schema, ipc_fields: &[IpcField]) -> EncodedData {
-    EncodedData {
-        ipc_message: write::schema_to_bytes(schema, ipc_fields),
+fn schema_as_encoded_data(schema: &Schema, ipc_fields: &[IpcField]) -> Result<EncodedData> {
+    Ok(EncodedData {
+        ipc_message: write::schema_to_bytes(schema, ipc_fields)?,
         arrow_data: vec![],
-    }
+    })
 }
 
 /// Deserialize an IPC message into [`Schema`], [`IpcSchema`].
@@ -4,6 +4,7 @@ use std::io::{Read, Seek};
 
 use arrow_format;
 
+use crate::array::growable::make_growable;
 use crate::array::*;
 use crate::chunk::Chunk;
 use crate::datatypes::{DataType, Field};
@@ -239,14 +240,9 @@ pub fn read_dictionary<R: Read + Seek>(
     file_size: u64,
     scratch: &mut Vec<u8>,
 ) -> Result<()> {
-    if batch
+    let is_delta = batch
         .is_delta()
-        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferIsDelta(err)))?
-    {
-        return Err(Error::NotYetImplemented(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferIsDelta(err)))?;
 
     let id = batch
         .id()
@@ -287,7 +283,23 @@ pub fn read_dictionary<R: Read + Seek>(
         scratch,
     )?;
 
-    dictionaries.insert(id, chunk.into_arrays().pop().unwrap());
+    let values = chunk.into_arrays().pop().unwrap();
+
+    let values = if is_delta {
+        // a delta dictionary batch appends its values to the existing dictionary
+        let existing = dictionaries
+            .get(&id)
+            .ok_or_else(|| Error::from(OutOfSpecKind::InvalidId { requested_id: id }))?;
+        let arrays = [existing.as_ref(), values.as_ref()];
+        let mut growable = make_growable(&arrays, false, existing.len() + values.len());
+        growable.extend(0, 0, existing.len());
+        growable.extend(1, 0, values.len());
+        growable.as_box()
+    } else {
+        values
+    };
+
+    dictionaries.insert(id, values);
 
     Ok(())
 }
@@ -6,7 +6,7 @@ use arrow_format::ipc::MetadataVersion;
 
 use crate::array::*;
 use crate::datatypes::{DataType, Field, PhysicalType};
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::io::ipc::IpcField;
 
 use super::{array::*, Dictionaries};
@@ -226,6 +226,9 @@ pub fn read<R: Read + Seek>(
             scratch,
         )
         .map(|x| x.boxed()),
+        RunEndEncoded(_) => Err(Error::nyi(
+            "Reading run-end encoded arrays from IPC is not yet supported",
+        )),
     }
 }
 
@@ -249,5 +252,8 @@ pub fn skip(
         Dictionary(_) => skip_dictionary(field_nodes, buffers),
         Union => skip_union(field_nodes, data_type, buffers),
         Map => skip_map(field_nodes, data_type, buffers),
+        RunEndEncoded(_) => Err(Error::nyi(
+            "Reading run-end encoded arrays from IPC is not yet supported",
+        )),
     }
 }
@@ -8,7 +8,7 @@ use crate::error::Result;
 
 use super::common::*;
 use super::Dictionaries;
-use super::{read_batch, read_file_dictionaries, FileMetadata};
+use super::{read_batch, read_file_dictionaries, read_file_metadata, FileMetadata};
 
 /// An iterator of [`Chunk`]s from an Arrow IPC file.
 pub struct FileReader<R: Read + Seek> {
@@ -136,3 +136,16 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
         Some(chunk)
     }
 }
+
+/// Deserializes the bytes of an Arrow IPC file, such as the ones from
+/// [`chunk_to_ipc_bytes`](crate::io::ipc::write::chunk_to_ipc_bytes), to its [`Schema`] and
+/// [`Chunk`]s.
+/// # Errors
+/// This function errors iff `bytes` is not a valid Arrow IPC file.
+pub fn chunk_from_ipc_bytes(bytes: &[u8]) -> Result<(Schema, Vec<Chunk<Box<dyn Array>>>)> {
+    let mut reader = std::io::Cursor::new(bytes);
+    let metadata = read_file_metadata(&mut reader)?;
+    let schema = metadata.schema.clone();
+    let chunks = FileReader::new(reader, metadata, None, None).collect::<Result<_>>()?;
+    Ok((schema, chunks))
+}
@@ -10,7 +10,7 @@ use crate::io::ipc::endianess::is_native_little_endian;
 use crate::io::ipc::read::Dictionaries;
 
 use super::super::IpcField;
-use super::{write, write_dictionary};
+use super::write;
 
 /// Compression codec
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -55,13 +55,16 @@ fn encode_dictionary(
                 encoded_dictionaries
             )?;
 
-            if emit {
-                encoded_dictionaries.push(dictionary_batch_to_bytes::<$T>(
+            if let Some(offset) = emit {
+                // values past `offset` are new and are emitted as a delta dictionary batch
+                let is_delta = offset > 0;
+                encoded_dictionaries.push(dictionary_batch_to_bytes(
                     dict_id,
-                    array,
+                    values.sliced(offset, values.len() - offset).as_ref(),
+                    is_delta,
                     options,
                     is_native_little_endian(),
-                ));
+                )?);
             };
             Ok(())
         }),
@@ -168,6 +171,9 @@ fn encode_dictionary(
                 encoded_dictionaries,
             )
         }
+        RunEndEncoded(_) => Err(Error::nyi(
+            "Writing run-end encoded arrays to IPC is not yet supported",
+        )),
     }
 }
 
@@ -208,7 +214,7 @@ pub fn encode_chunk_amortized(
         )?;
     }
 
-    chunk_to_bytes_amortized(chunk, options, encoded_message);
+    chunk_to_bytes_amortized(chunk, options, encoded_message)?;
 
     Ok(encoded_dictionaries)
 }
@@ -236,7 +242,7 @@ fn chunk_to_bytes_amortized(
     chunk: &Chunk<Box<dyn Array>>,
     options: &WriteOptions,
     encoded_message: &mut EncodedData,
-) {
+) -> Result<()> {
     let mut nodes: Vec<arrow_format::ipc::FieldNode> = vec![];
     let mut buffers: Vec<arrow_format::ipc::Buffer> = vec![];
     let mut arrow_data = std::mem::take(&mut encoded_message.arrow_data);
@@ -252,7 +258,7 @@ fn chunk_to_bytes_amortized(
             &mut offset,
             is_native_little_endian(),
             options.compression,
-        )
+        )?;
     }
 
     let compression = serialize_compression(options.compression);
@@ -274,31 +280,33 @@ fn chunk_to_bytes_amortized(
     let mut builder = Builder::new();
     let ipc_message = builder.finish(&message, None);
     encoded_message.ipc_message = ipc_message.to_vec();
-    encoded_message.arrow_data = arrow_data
+    encoded_message.arrow_data = arrow_data;
+    Ok(())
 }
 
 /// Write dictionary values into two sets of bytes, one for the header (ipc::Schema::Message) and the
 /// other for the data
-fn dictionary_batch_to_bytes<K: DictionaryKey>(
+fn dictionary_batch_to_bytes(
     dict_id: i64,
-    array: &DictionaryArray<K>,
+    values: &dyn Array,
+    is_delta: bool,
     options: &WriteOptions,
     is_little_endian: bool,
-) -> EncodedData {
+) -> Result<EncodedData> {
     let mut nodes: Vec<arrow_format::ipc::FieldNode> = vec![];
     let mut buffers: Vec<arrow_format::ipc::Buffer> = vec![];
     let mut arrow_data: Vec<u8> = vec![];
 
-    let length = write_dictionary(
-        array,
+    write(
+        values,
         &mut buffers,
         &mut arrow_data,
         &mut nodes,
         &mut 0,
         is_little_endian,
         options.compression,
-        false,
-    );
+    )?;
+    let length = values.len();
 
     let compression = serialize_compression(options.compression);
 
@@ -313,7 +321,7 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
                     buffers: Some(buffers),
                     compression,
                 })),
-                is_delta: false,
+                is_delta,
             },
         ))),
         body_length: arrow_data.len() as i64,
@@ -323,15 +331,15 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
     let mut builder = Builder::new();
     let ipc_message = builder.finish(&message, None);
 
-    EncodedData {
+    Ok(EncodedData {
         ipc_message: ipc_message.to_vec(),
         arrow_data,
-    }
+    })
 }
 
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
-/// multiple times. Can optionally error if an update to an existing dictionary is attempted, which
-/// isn't allowed in the `FileWriter`.
+/// multiple times. Can optionally error if a replacement of an existing dictionary is attempted, which
+/// isn't allowed in the `FileWriter`. Dictionaries that only grow are emitted as deltas.
 pub struct DictionaryTracker {
     pub dictionaries: Dictionaries,
     pub cannot_replace: bool,
@@ -340,14 +348,17 @@ pub struct DictionaryTracker {
 impl DictionaryTracker {
     /// Keep track of the dictionary with the given ID and values. Behavior:
     ///
-    /// * If this ID has been written already and has the same data, return `Ok(false)` to indicate
+    /// * If this ID has been written already and has the same data, return `Ok(None)` to indicate
     ///   that the dictionary was not actually inserted (because it's already been seen).
+    /// * If this ID has been written already and the data starts with it, return `Ok(Some(n))`,
+    ///   where `n` is the number of values already written, to indicate that only the values
+    ///   after `n` must be written, as a delta.
     /// * If this ID has been written already but with different data, and this tracker is
     ///   configured to return an error, return an error.
     /// * If the tracker has not been configured to error on replacement or this dictionary
-    ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
+    ///   has never been seen before, return `Ok(Some(0))` to indicate that the dictionary was just
     ///   inserted.
-    pub fn insert(&mut self, dict_id: i64, array: &dyn Array) -> Result<bool> {
+    pub fn insert(&mut self, dict_id: i64, array: &dyn Array) -> Result<Option<usize>> {
         let values = match array.data_type() {
             DataType::Dictionary(key_type, _, _) => {
                 match_integer_type!(key_type, |$T| {
@@ -362,10 +373,16 @@ impl DictionaryTracker {
         };
 
         // If a dictionary with this id was already emitted, check if it was the same.
+        let mut offset = 0;
         if let Some(last) = self.dictionaries.get(&dict_id) {
             if last.as_ref() == values.as_ref() {
                 // Same dictionary values => no need to emit it again
-                return Ok(false);
+                return Ok(None);
+            } else if last.len() < values.len()
+                && values.sliced(0, last.len()).as_ref() == last.as_ref()
+            {
+                // Appended dictionary values => only emit the new ones
+                offset = last.len();
             } else if self.cannot_replace {
                 return Err(Error::InvalidArgumentError(
                     "Dictionary replacement detected when writing IPC file format. \
@@ -377,7 +394,7 @@ impl DictionaryTracker {
         };
 
         self.dictionaries.insert(dict_id, values.clone());
-        Ok(true)
+        Ok(Some(offset))
     }
 }
 
@@ -390,7 +407,7 @@ pub struct EncodedData {
     pub arrow_data: Vec<u8>,
 }
 
-/// Calculate an 8-byte boundary and return the number of bytes needed to pad to 8 bytes
+/// Calculate a 64-byte boundary and return the number of bytes needed to pad to 64 bytes
 #[inline]
 pub(crate) fn pad_to_64(len: usize) -> usize {
     ((len + 63) & !63) - len
@@ -37,6 +37,11 @@ pub async fn write_message<W: AsyncWrite + Unpin + Send>(
         0
     };
 
+    debug_assert_eq!(
+        body_len % 64,
+        0,
+        "IPC message bodies must be 64-byte aligned"
+    );
     Ok((aligned_size, body_len))
 }
 
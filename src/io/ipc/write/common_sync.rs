@@ -35,6 +35,11 @@ pub fn write_message<W: Write>(writer: &mut W, encoded: &EncodedData) -> Result<
         0
     };
 
+    debug_assert_eq!(
+        body_len % 64,
+        0,
+        "IPC message bodies must be 64-byte aligned"
+    );
     Ok((aligned_size, body_len))
 }
 
@@ -83,10 +83,10 @@ where
         options: WriteOptions,
     ) -> Self {
         let fields = ipc_fields.unwrap_or_else(|| default_ipc_fields(&schema.fields));
-        let encoded = EncodedData {
-            ipc_message: schema_to_bytes(&schema, &fields),
+        let encoded = schema_to_bytes(&schema, &fields).map(|ipc_message| EncodedData {
+            ipc_message,
             arrow_data: vec![],
-        };
+        });
         let task = Some(Self::start(writer, encoded).boxed());
         Self {
             writer: None,
@@ -104,7 +104,8 @@ where
         }
     }
 
-    async fn start(mut writer: W, encoded: EncodedData) -> Result<WriteOutput<W>> {
+    async fn start(mut writer: W, encoded: Result<EncodedData>) -> Result<WriteOutput<W>> {
+        let encoded = encoded?;
         writer.write_all(&ARROW_MAGIC[..]).await?;
         writer.write_all(&[0, 0]).await?;
         let (meta, data) = write_message(&mut writer, encoded).await?;
@@ -230,7 +231,10 @@ where
         match futures::ready!(this.poll_write(cx)) {
             Ok(()) => {
                 if let Some(writer) = this.writer.take() {
-                    let schema = serialize_schema(&this.schema, &this.fields);
+                    let schema = match serialize_schema(&this.schema, &this.fields) {
+                        Ok(schema) => schema,
+                        Err(error) => return Poll::Ready(Err(error)),
+                    };
                     let footer = Footer {
                         version: MetadataVersion::V5,
                         schema: Some(Box::new(schema)),
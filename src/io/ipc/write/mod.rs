@@ -1,4 +1,10 @@
 //! APIs to write to Arrow's IPC format.
+//!
+//! Every message written starts at an 8-byte boundary, and every buffer of a message body
+//! is padded to a 64-byte boundary, as recommended by the
+//! [specification](https://arrow.apache.org/docs/format/Columnar.html#buffer-alignment-and-padding).
+//! This is a multiple of the 8-byte alignment that readers such as `pyarrow` require,
+//! which allows them to use the buffers without copying them.
 pub(crate) mod common;
 mod schema;
 mod serialize;
@@ -8,9 +14,8 @@ pub(crate) mod writer;
 pub use common::{Compression, Record, WriteOptions};
 pub use schema::schema_to_bytes;
 pub use serialize::write;
-pub(self) use serialize::write_dictionary;
 pub use stream::StreamWriter;
-pub use writer::FileWriter;
+pub use writer::{chunk_to_ipc_bytes, FileWriter};
 
 pub(crate) mod common_sync;
 
@@ -5,6 +5,7 @@ use crate::{
     array::*,
     bitmap::Bitmap,
     datatypes::PhysicalType,
+    error::{Error, Result},
     offset::{Offset, OffsetsBuffer},
     trusted_len::TrustedLen,
     types::NativeType,
@@ -186,7 +187,7 @@ fn write_list<O: Offset>(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     let offsets = array.offsets().buffer();
     let validity = array.validity();
 
@@ -232,7 +233,8 @@ fn write_list<O: Offset>(
         offset,
         is_little_endian,
         compression,
-    );
+    )?;
+    Ok(())
 }
 
 pub fn write_struct(
@@ -243,7 +245,7 @@ pub fn write_struct(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     write_bitmap(
         array.validity(),
         array.len(),
@@ -252,7 +254,7 @@ pub fn write_struct(
         offset,
         compression,
     );
-    array.values().iter().for_each(|array| {
+    for array in array.values() {
         write(
             array.as_ref(),
             buffers,
@@ -261,8 +263,9 @@ pub fn write_struct(
             offset,
             is_little_endian,
             compression,
-        );
-    });
+        )?;
+    }
+    Ok(())
 }
 
 pub fn write_union(
@@ -273,7 +276,7 @@ pub fn write_union(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     write_buffer(
         array.types(),
         buffers,
@@ -293,7 +296,7 @@ pub fn write_union(
             compression,
         );
     }
-    array.fields().iter().for_each(|array| {
+    for array in array.fields() {
         write(
             array.as_ref(),
             buffers,
@@ -302,8 +305,9 @@ pub fn write_union(
             offset,
             is_little_endian,
             compression,
-        )
-    });
+        )?;
+    }
+    Ok(())
 }
 
 fn write_map(
@@ -314,7 +318,7 @@ fn write_map(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     let offsets = array.offsets().buffer();
     let validity = array.validity();
 
@@ -360,7 +364,8 @@ fn write_map(
         offset,
         is_little_endian,
         compression,
-    );
+    )?;
+    Ok(())
 }
 
 fn write_fixed_size_list(
@@ -371,7 +376,7 @@ fn write_fixed_size_list(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     write_bitmap(
         array.validity(),
         array.len(),
@@ -388,12 +393,13 @@ fn write_fixed_size_list(
         offset,
         is_little_endian,
         compression,
-    );
+    )?;
+    Ok(())
 }
 
 // use `write_keys` to either write keys or values
 #[allow(clippy::too_many_arguments)]
-pub(super) fn write_dictionary<K: DictionaryKey>(
+fn write_dictionary<K: DictionaryKey>(
     array: &DictionaryArray<K>,
     buffers: &mut Vec<ipc::Buffer>,
     arrow_data: &mut Vec<u8>,
@@ -402,7 +408,7 @@ pub(super) fn write_dictionary<K: DictionaryKey>(
     is_little_endian: bool,
     compression: Option<Compression>,
     write_keys: bool,
-) -> usize {
+) -> Result<usize> {
     if write_keys {
         write_primitive(
             array.keys(),
@@ -412,7 +418,7 @@ pub(super) fn write_dictionary<K: DictionaryKey>(
             is_little_endian,
             compression,
         );
-        array.keys().len()
+        Ok(array.keys().len())
     } else {
         write(
             array.values().as_ref(),
@@ -422,12 +428,15 @@ pub(super) fn write_dictionary<K: DictionaryKey>(
             offset,
             is_little_endian,
             compression,
-        );
-        array.values().len()
+        )?;
+        Ok(array.values().len())
     }
 }
 
 /// Writes an [`Array`] to `arrow_data`
+/// # Errors
+/// This function errors iff the array's data type is not supported by IPC
+/// (e.g. [`DataType::RunEndEncoded`](crate::datatypes::DataType::RunEndEncoded)).
 pub fn write(
     array: &dyn Array,
     buffers: &mut Vec<ipc::Buffer>,
@@ -436,7 +445,7 @@ pub fn write(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     nodes.push(ipc::FieldNode {
         length: array.len() as i64,
         null_count: array.null_count() as i64,
@@ -504,7 +513,7 @@ pub fn write(
             offset,
             is_little_endian,
             compression,
-        ),
+        )?,
         LargeList => write_list::<i64>(
             array.as_any().downcast_ref().unwrap(),
             buffers,
@@ -513,7 +522,7 @@ pub fn write(
             offset,
             is_little_endian,
             compression,
-        ),
+        )?,
         FixedSizeList => write_fixed_size_list(
             array.as_any().downcast_ref().unwrap(),
             buffers,
@@ -522,7 +531,7 @@ pub fn write(
             offset,
             is_little_endian,
             compression,
-        ),
+        )?,
         Struct => write_struct(
             array.as_any().downcast_ref().unwrap(),
             buffers,
@@ -531,7 +540,7 @@ pub fn write(
             offset,
             is_little_endian,
             compression,
-        ),
+        )?,
         Dictionary(key_type) => match_integer_type!(key_type, |$T| {
             write_dictionary::<$T>(
                 array.as_any().downcast_ref().unwrap(),
@@ -542,7 +551,7 @@ pub fn write(
                 is_little_endian,
                 compression,
                 true,
-            );
+            )?;
         }),
         Union => {
             write_union(
@@ -553,7 +562,7 @@ pub fn write(
                 offset,
                 is_little_endian,
                 compression,
-            );
+            )?;
         }
         Map => {
             write_map(
@@ -564,9 +573,15 @@ pub fn write(
                 offset,
                 is_little_endian,
                 compression,
-            );
+            )?;
+        }
+        RunEndEncoded(_) => {
+            return Err(Error::nyi(
+                "Writing run-end encoded arrays to IPC is not yet supported",
+            ))
         }
     }
+    Ok(())
 }
 
 #[inline]
@@ -575,7 +590,7 @@ fn pad_buffer_to_64(buffer: &mut Vec<u8>, length: usize) {
     buffer.extend_from_slice(&vec![0u8; pad_len]);
 }
 
-/// writes `bytes` to `arrow_data` updating `buffers` and `offset` and guaranteeing a 8 byte boundary.
+/// writes `bytes` to `arrow_data` updating `buffers` and `offset` and guaranteeing a 64 byte boundary.
 fn write_bytes(
     bytes: &[u8],
     buffers: &mut Vec<ipc::Buffer>,
@@ -631,7 +646,7 @@ fn write_bitmap(
     }
 }
 
-/// writes `bytes` to `arrow_data` updating `buffers` and `offset` and guaranteeing a 8 byte boundary.
+/// writes `bytes` to `arrow_data` updating `buffers` and `offset` and guaranteeing a 64 byte boundary.
 fn write_buffer<T: NativeType>(
     buffer: &[T],
     buffers: &mut Vec<ipc::Buffer>,
@@ -730,7 +745,7 @@ fn _write_compressed_buffer<T: NativeType>(
     }
 }
 
-/// writes `bytes` to `arrow_data` updating `buffers` and `offset` and guaranteeing a 8 byte boundary.
+/// writes `bytes` to `arrow_data` updating `buffers` and `offset` and guaranteeing a 64 byte boundary.
 #[inline]
 fn write_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
     buffer: I,
@@ -762,5 +777,6 @@ fn finish_buffer(arrow_data: &mut Vec<u8>, start: usize, offset: &mut i64) -> ip
         length: buffer_len,
     };
     *offset += total_len;
+    debug_assert_eq!(*offset % 64, 0, "IPC buffers must be 64-byte aligned");
     buffer
 }
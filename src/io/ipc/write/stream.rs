@@ -59,7 +59,7 @@ impl<W: Write> StreamWriter<W> {
         });
 
         let encoded_message = EncodedData {
-            ipc_message: schema_to_bytes(schema, self.ipc_fields.as_ref().unwrap()),
+            ipc_message: schema_to_bytes(schema, self.ipc_fields.as_ref().unwrap())?,
             arrow_data: vec![],
         };
         write_message(&mut self.writer, &encoded_message)?;
@@ -85,12 +85,12 @@ where
         schema: &Schema,
         ipc_fields: &[IpcField],
     ) -> BoxFuture<'a, Result<Option<W>>> {
-        let message = EncodedData {
-            ipc_message: schema_to_bytes(schema, ipc_fields),
+        let message = schema_to_bytes(schema, ipc_fields).map(|ipc_message| EncodedData {
+            ipc_message,
             arrow_data: vec![],
-        };
+        });
         async move {
-            write_message(&mut writer, message).await?;
+            write_message(&mut writer, message?).await?;
             Ok(Some(writer))
         }
         .boxed()
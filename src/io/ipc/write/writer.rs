@@ -120,7 +120,7 @@ impl<W: Write> FileWriter<W> {
         // write the schema, set the written bytes to the schema
 
         let encoded_message = EncodedData {
-            ipc_message: schema_to_bytes(&self.schema, &self.ipc_fields),
+            ipc_message: schema_to_bytes(&self.schema, &self.ipc_fields)?,
             arrow_data: vec![],
         };
 
@@ -159,6 +159,11 @@ impl<W: Write> FileWriter<W> {
         for encoded_dictionary in encoded_dictionaries {
             let (meta, data) = write_message(&mut self.writer, &encoded_dictionary)?;
 
+            debug_assert_eq!(
+                self.block_offsets % 8,
+                0,
+                "IPC blocks must be 8-byte aligned"
+            );
             let block = arrow_format::ipc::Block {
                 offset: self.block_offsets as i64,
                 meta_data_length: meta as i32,
@@ -170,6 +175,11 @@ impl<W: Write> FileWriter<W> {
 
         let (meta, data) = write_message(&mut self.writer, &self.encoded_message)?;
         // add a record block for the footer
+        debug_assert_eq!(
+            self.block_offsets % 8,
+            0,
+            "IPC blocks must be 8-byte aligned"
+        );
         let block = arrow_format::ipc::Block {
             offset: self.block_offsets as i64,
             meta_data_length: meta as i32, // TODO: is this still applicable?
@@ -191,7 +201,7 @@ impl<W: Write> FileWriter<W> {
         // write EOS
         write_continuation(&mut self.writer, 0)?;
 
-        let schema = schema::serialize_schema(&self.schema, &self.ipc_fields);
+        let schema = schema::serialize_schema(&self.schema, &self.ipc_fields)?;
 
         let root = arrow_format::ipc::Footer {
             version: arrow_format::ipc::MetadataVersion::V5,
@@ -212,3 +222,17 @@ impl<W: Write> FileWriter<W> {
         Ok(())
     }
 }
+
+/// Serializes `chunk` with `schema` to the bytes of an (uncompressed) Arrow IPC file.
+///
+/// This is a convenience over [`FileWriter`] for in-memory serialization, and the inverse of
+/// [`chunk_from_ipc_bytes`](crate::io::ipc::read::chunk_from_ipc_bytes).
+/// # Errors
+/// This function errors iff `chunk` cannot be written with `schema`.
+pub fn chunk_to_ipc_bytes(chunk: &Chunk<Box<dyn Array>>, schema: &Schema) -> Result<Vec<u8>> {
+    let options = WriteOptions { compression: None };
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    writer.write(chunk, None)?;
+    writer.finish()?;
+    Ok(writer.into_inner())
+}
@@ -207,18 +207,73 @@ fn deserialize_utf8_into<'a, O: Offset, A: Borrow<Value<'a>>>(
     for row in rows {
         match row.borrow() {
             Value::String(v) => target.push(Some(v.as_ref())),
-            Value::Number(number) => match number {
-                Number::Integer(number, exponent) | Number::Float(number, exponent) => {
-                    scratch.clear();
-                    scratch.extend_from_slice(number);
-                    scratch.push(b'e');
-                    scratch.extend_from_slice(exponent);
-                }
-            },
             Value::Bool(v) => target.push(Some(if *v { "true" } else { "false" })),
-            _ => target.push_null(),
+            Value::Null => target.push_null(),
+            // numbers, lists and objects are written as raw JSON
+            value => {
+                scratch.clear();
+                write_json(value, &mut scratch);
+                target.push(std::str::from_utf8(&scratch).ok());
+            }
+        }
+    }
+}
+
+/// Writes `value` as JSON to `buffer`.
+fn write_json(value: &Value, buffer: &mut Vec<u8>) {
+    match value {
+        Value::Null => buffer.extend_from_slice(b"null"),
+        Value::Bool(v) => buffer.extend_from_slice(if *v { b"true" } else { b"false" }),
+        Value::Number(Number::Integer(number, exponent) | Number::Float(number, exponent)) => {
+            buffer.extend_from_slice(number);
+            if !exponent.is_empty() {
+                buffer.push(b'e');
+                buffer.extend_from_slice(exponent);
+            }
+        }
+        Value::String(v) => write_json_str(v, buffer),
+        Value::Array(values) => {
+            buffer.push(b'[');
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    buffer.push(b',');
+                }
+                write_json(value, buffer);
+            }
+            buffer.push(b']');
+        }
+        Value::Object(values) => {
+            buffer.push(b'{');
+            for (index, (key, value)) in values.iter().enumerate() {
+                if index > 0 {
+                    buffer.push(b',');
+                }
+                write_json_str(key, buffer);
+                buffer.push(b':');
+                write_json(value, buffer);
+            }
+            buffer.push(b'}');
+        }
+    }
+}
+
+/// Writes `value` as an escaped JSON string to `buffer`.
+fn write_json_str(value: &str, buffer: &mut Vec<u8>) {
+    buffer.push(b'"');
+    for c in value.chars() {
+        match c {
+            '"' => buffer.extend_from_slice(b"\\\""),
+            '\\' => buffer.extend_from_slice(b"\\\\"),
+            '\n' => buffer.extend_from_slice(b"\\n"),
+            '\r' => buffer.extend_from_slice(b"\\r"),
+            '\t' => buffer.extend_from_slice(b"\\t"),
+            c if c.is_control() => {
+                buffer.extend_from_slice(format!("\\u{:04x}", c as u32).as_bytes())
+            }
+            c => buffer.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
         }
     }
+    buffer.push(b'"');
 }
 
 fn deserialize_list<'a, O: Offset, A: Borrow<Value<'a>>>(
@@ -9,15 +9,47 @@ use crate::error::{Error, Result};
 
 const ITEM_NAME: &str = "item";
 
+/// Options to bound the inference of [`DataType`]s from JSON.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InferOptions {
+    /// The maximum number of records (items of the outer array) used to infer the types.
+    /// All records are used when `None`.
+    pub max_records: Option<usize>,
+    /// The maximum number of nested lists or objects to descend into.
+    /// Lists and objects nested deeper are inferred as [`DataType::Utf8`] and
+    /// deserialized as their raw JSON. There is no limit when `None`.
+    pub max_depth: Option<usize>,
+}
+
 /// Infers [`DataType`] from [`Value`].
 pub fn infer(json: &Value) -> Result<DataType> {
+    infer_value(json, None)
+}
+
+/// Infers [`DataType`] from [`Value`] according to `options`.
+///
+/// When `json` is an array, only its first `options.max_records` items are used.
+pub fn infer_with_options(json: &Value, options: &InferOptions) -> Result<DataType> {
+    match (json, options.max_depth) {
+        (Value::Array(_), Some(0)) => Ok(DataType::Utf8),
+        (Value::Array(array), max_depth) => {
+            let len = options.max_records.unwrap_or(usize::MAX).min(array.len());
+            infer_array(&array[..len], max_depth.map(|x| x - 1))
+        }
+        (json, max_depth) => infer_value(json, max_depth),
+    }
+}
+
+/// Infers the [`DataType`] of `json`, descending into at most `depth` nested lists or objects.
+fn infer_value(json: &Value, depth: Option<usize>) -> Result<DataType> {
     Ok(match json {
+        Value::Array(_) | Value::Object(_) if depth == Some(0) => DataType::Utf8,
         Value::Bool(_) => DataType::Boolean,
-        Value::Array(array) => infer_array(array)?,
+        Value::Array(array) => infer_array(array, depth.map(|x| x - 1))?,
         Value::Null => DataType::Null,
         Value::Number(number) => infer_number(number),
         Value::String(_) => DataType::Utf8,
-        Value::Object(inner) => infer_object(inner)?,
+        Value::Object(inner) => infer_object(inner, depth.map(|x| x - 1))?,
     })
 }
 
@@ -56,6 +88,53 @@ pub fn infer_records_schema(json: &Value) -> Result<Schema> {
     })
 }
 
+/// Infers [`Schema`] from JSON [`Value`] in (pandas-compatible) records format according to `options`.
+///
+/// Contrarily to [`infer_records_schema`], the fields are inferred from the union of the
+/// first `options.max_records` records (all records when `None`), and the
+/// values of each record are inferred with a maximum depth of `options.max_depth`.
+pub fn infer_records_schema_with_options(json: &Value, options: &InferOptions) -> Result<Schema> {
+    let outer_array = match json {
+        Value::Array(array) => Ok(array),
+        _ => Err(Error::ExternalFormat(
+            "outer type is not an array".to_string(),
+        )),
+    }?;
+    let len = options
+        .max_records
+        .unwrap_or(usize::MAX)
+        .min(outer_array.len());
+
+    let data_types = outer_array[..len]
+        .iter()
+        .map(|record| match record {
+            Value::Object(record) => {
+                let fields = record
+                    .iter()
+                    .map(|(name, json)| {
+                        let data_type = infer_value(json, options.max_depth)?;
+                        Ok(Field::new(name, data_type, true))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataType::Struct(fields))
+            }
+            _ => Err(Error::ExternalFormat(
+                "element in array is not a record".to_string(),
+            )),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let fields = match coerce_data_type(&data_types) {
+        DataType::Struct(fields) => fields,
+        _ => vec![],
+    };
+
+    Ok(Schema {
+        fields,
+        metadata: Metadata::default(),
+    })
+}
+
 fn filter_map_nulls(dt: DataType) -> Option<DataType> {
     if dt == DataType::Null {
         None
@@ -64,11 +143,11 @@ fn filter_map_nulls(dt: DataType) -> Option<DataType> {
     }
 }
 
-fn infer_object(inner: &HashMap<String, Value>) -> Result<DataType> {
+fn infer_object(inner: &HashMap<String, Value>, depth: Option<usize>) -> Result<DataType> {
     let fields = inner
         .iter()
         .filter_map(|(key, value)| {
-            infer(value)
+            infer_value(value, depth)
                 .map(|dt| filter_map_nulls(dt).map(|dt| (key, dt)))
                 .transpose()
         })
@@ -80,10 +159,10 @@ fn infer_object(inner: &HashMap<String, Value>) -> Result<DataType> {
     Ok(DataType::Struct(fields))
 }
 
-fn infer_array(values: &[Value]) -> Result<DataType> {
+fn infer_array(values: &[Value], depth: Option<usize>) -> Result<DataType> {
     let types = values
         .iter()
-        .map(infer)
+        .map(|value| infer_value(value, depth))
         .filter_map(|x| x.map(filter_map_nulls).transpose())
         // deduplicate entries
         .collect::<Result<HashSet<_>>>()?;
@@ -5,7 +5,10 @@ mod infer_schema;
 pub(crate) use deserialize::_deserialize;
 pub use deserialize::{deserialize, deserialize_records};
 pub(crate) use infer_schema::coerce_data_type;
-pub use infer_schema::{infer, infer_records_schema};
+pub use infer_schema::{
+    infer, infer_records_schema, infer_records_schema_with_options, infer_with_options,
+    InferOptions,
+};
 
 pub use json_deserializer;
 
@@ -424,6 +424,9 @@ pub fn to_array(
             Ok(Box::new(array))
         }
         Map => to_map(json_col, data_type, field, dictionaries),
+        RunEndEncoded(_) => Err(Error::nyi(
+            "Reading run-end encoded arrays from JSON is not yet supported",
+        )),
     }
 }
 
@@ -1,13 +1,14 @@
 use serde_json::{json, Map, Value};
 
 use crate::datatypes::{DataType, Field, IntervalUnit, Metadata, Schema, TimeUnit};
+use crate::error::{Error, Result};
 use crate::io::ipc::IpcField;
 use crate::io::json_integration::ArrowJsonSchema;
 
 use super::super::{ArrowJsonField, ArrowJsonFieldDictionary, IntegerType};
 
-fn serialize_data_type(data_type: &DataType) -> Value {
-    match data_type {
+fn serialize_data_type(data_type: &DataType) -> Result<Value> {
+    Ok(match data_type {
         DataType::Null => json!({"name": "null"}),
         DataType::Boolean => json!({"name": "bool"}),
         DataType::Int8 => json!({"name": "int", "bitWidth": 8, "isSigned": true}),
@@ -92,22 +93,27 @@ fn serialize_data_type(data_type: &DataType) -> Value {
         DataType::Decimal256(precision, scale) => {
             json!({"name": "decimal", "precision": precision, "scale": scale, "bit_width": 256})
         }
-        DataType::Extension(_, inner_data_type, _) => serialize_data_type(inner_data_type),
-    }
+        DataType::Extension(_, inner_data_type, _) => return serialize_data_type(inner_data_type),
+        DataType::RunEndEncoded(_, _) => {
+            return Err(Error::nyi(
+                "Writing run-end encoded arrays to JSON integration is not yet supported",
+            ))
+        }
+    })
 }
 
-fn serialize_field(field: &Field, ipc_field: &IpcField) -> ArrowJsonField {
+fn serialize_field(field: &Field, ipc_field: &IpcField) -> Result<ArrowJsonField> {
     let children = match field.data_type() {
         DataType::Union(fields, ..) | DataType::Struct(fields) => fields
             .iter()
             .zip(ipc_field.fields.iter())
             .map(|(field, ipc_field)| serialize_field(field, ipc_field))
-            .collect(),
+            .collect::<Result<_>>()?,
         DataType::Map(field, ..)
         | DataType::FixedSizeList(field, _)
         | DataType::LargeList(field)
         | DataType::List(field) => {
-            vec![serialize_field(field, &ipc_field.fields[0])]
+            vec![serialize_field(field, &ipc_field.fields[0])?]
         }
         _ => vec![],
     };
@@ -136,27 +142,30 @@ fn serialize_field(field: &Field, ipc_field: &IpcField) -> ArrowJsonField {
         None
     };
 
-    ArrowJsonField {
+    Ok(ArrowJsonField {
         name: field.name.clone(),
-        field_type: serialize_data_type(field.data_type()),
+        field_type: serialize_data_type(field.data_type())?,
         nullable: field.is_nullable,
         children,
         dictionary,
         metadata,
-    }
+    })
 }
 
 /// Serializes a [`Schema`] and associated [`IpcField`] to [`ArrowJsonSchema`].
-pub fn serialize_schema(schema: &Schema, ipc_fields: &[IpcField]) -> ArrowJsonSchema {
-    ArrowJsonSchema {
+/// # Errors
+/// This function errors iff the schema has a data type not supported by the JSON integration
+/// format (e.g. [`DataType::RunEndEncoded`]).
+pub fn serialize_schema(schema: &Schema, ipc_fields: &[IpcField]) -> Result<ArrowJsonSchema> {
+    Ok(ArrowJsonSchema {
         fields: schema
             .fields
             .iter()
             .zip(ipc_fields.iter())
             .map(|(field, ipc_field)| serialize_field(field, ipc_field))
-            .collect(),
+            .collect::<Result<_>>()?,
         metadata: Some(serde_json::to_value(&schema.metadata).unwrap()),
-    }
+    })
 }
 
 fn serialize_metadata(metadata: &Metadata) -> Option<Value> {
@@ -107,6 +107,18 @@ pub fn get_field_pages<'a, T>(
         .collect()
 }
 
+/// Returns the byte ranges `(start, length)` in the file of all column chunks associated
+/// to `field_name`. For non-nested parquet types, this returns a single range.
+///
+/// This allows to fetch the columns with any IO (e.g. range requests to an object store)
+/// and deserialize them with [`to_deserializer`], together with [`get_field_columns`].
+pub fn get_field_byte_ranges(columns: &[ColumnChunkMetaData], field_name: &str) -> Vec<(u64, u64)> {
+    get_field_columns(columns, field_name)
+        .into_iter()
+        .map(|meta| meta.byte_range())
+        .collect()
+}
+
 /// Reads all columns that are part of the parquet field `field_name`
 /// # Implementation
 /// This operation is IO-bounded `O(C)` where C is the number of columns associated to
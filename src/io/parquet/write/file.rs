@@ -14,13 +14,11 @@ use super::{schema::schema_to_metadata_key, to_parquet_schema, ThriftFileMetaDat
 pub fn add_arrow_schema(
     schema: &Schema,
     key_value_metadata: Option<Vec<KeyValue>>,
-) -> Option<Vec<KeyValue>> {
-    key_value_metadata
-        .map(|mut x| {
-            x.push(schema_to_metadata_key(schema));
-            x
-        })
-        .or_else(|| Some(vec![schema_to_metadata_key(schema)]))
+) -> Result<Option<Vec<KeyValue>>> {
+    let key = schema_to_metadata_key(schema)?;
+    let mut key_value_metadata = key_value_metadata.unwrap_or_default();
+    key_value_metadata.push(key);
+    Ok(Some(key_value_metadata))
 }
 
 /// An interface to write a parquet to a [`Write`]
@@ -79,7 +77,7 @@ impl<W: Write> FileWriter<W> {
 
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> Result<u64> {
-        let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
+        let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata)?;
         Ok(self.writer.end(key_value_metadata)?)
     }
 
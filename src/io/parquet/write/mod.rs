@@ -728,6 +728,13 @@ fn transverse_recursive<T, F: Fn(&DataType) -> T + Clone>(
             }
         }
         Union => todo!(),
+        RunEndEncoded(_) => {
+            if let DataType::RunEndEncoded(_, field) = data_type.to_logical_type() {
+                transverse_recursive(&field.data_type, map, encodings)
+            } else {
+                unreachable!()
+            }
+        }
     }
 }
 
@@ -4,7 +4,7 @@ use std::fmt::Debug;
 
 use crate::array::{ListArray, MapArray, StructArray};
 use crate::bitmap::Bitmap;
-use crate::datatypes::PhysicalType;
+use crate::datatypes::{DataType, PhysicalType};
 use crate::io::parquet::read::schema::is_nullable;
 use crate::offset::{Offset, OffsetsBuffer};
 use crate::{
@@ -61,15 +61,18 @@ impl Nested {
 pub fn to_nested(array: &dyn Array, type_: &ParquetType) -> Result<Vec<Vec<Nested>>> {
     let mut nested = vec![];
 
-    to_nested_recursive(array, type_, &mut nested, vec![])?;
+    to_nested_recursive(array, type_, &mut nested, vec![], None)?;
     Ok(nested)
 }
 
+/// `mask` is the validity of the structs enclosing `array`: a slot of a null struct
+/// is null in all its children, so that its definition level stops at the struct.
 fn to_nested_recursive(
     array: &dyn Array,
     type_: &ParquetType,
     nested: &mut Vec<Vec<Nested>>,
     mut parents: Vec<Nested>,
+    mask: Option<Bitmap>,
 ) -> Result<()> {
     let is_optional = is_nullable(type_.get_field_info());
 
@@ -85,14 +88,21 @@ fn to_nested_recursive(
                 ));
             };
 
+            let validity = combine_validities(mask.as_ref(), array.validity());
             parents.push(Nested::Struct(
-                array.validity().cloned(),
+                masked_validity(array, is_optional, &validity),
                 is_optional,
                 array.len(),
             ));
 
             for (type_, array) in fields.iter().zip(array.values()) {
-                to_nested_recursive(array.as_ref(), type_, nested, parents.clone())?;
+                to_nested_recursive(
+                    array.as_ref(),
+                    type_,
+                    nested,
+                    parents.clone(),
+                    validity.clone(),
+                )?;
             }
         }
         List => {
@@ -113,10 +123,14 @@ fn to_nested_recursive(
 
             parents.push(Nested::List(ListNested::new(
                 array.offsets().clone(),
-                array.validity().cloned(),
+                masked_validity(
+                    array,
+                    is_optional,
+                    &combine_validities(mask.as_ref(), array.validity()),
+                ),
                 is_optional,
             )));
-            to_nested_recursive(array.values().as_ref(), type_, nested, parents)?;
+            to_nested_recursive(array.values().as_ref(), type_, nested, parents, None)?;
         }
         LargeList => {
             let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
@@ -136,10 +150,14 @@ fn to_nested_recursive(
 
             parents.push(Nested::LargeList(ListNested::new(
                 array.offsets().clone(),
-                array.validity().cloned(),
+                masked_validity(
+                    array,
+                    is_optional,
+                    &combine_validities(mask.as_ref(), array.validity()),
+                ),
                 is_optional,
             )));
-            to_nested_recursive(array.values().as_ref(), type_, nested, parents)?;
+            to_nested_recursive(array.values().as_ref(), type_, nested, parents, None)?;
         }
         Map => {
             let array = array.as_any().downcast_ref::<MapArray>().unwrap();
@@ -159,14 +177,22 @@ fn to_nested_recursive(
 
             parents.push(Nested::List(ListNested::new(
                 array.offsets().clone(),
-                array.validity().cloned(),
+                masked_validity(
+                    array,
+                    is_optional,
+                    &combine_validities(mask.as_ref(), array.validity()),
+                ),
                 is_optional,
             )));
-            to_nested_recursive(array.field().as_ref(), type_, nested, parents)?;
+            to_nested_recursive(array.field().as_ref(), type_, nested, parents, None)?;
         }
         _ => {
             parents.push(Nested::Primitive(
-                array.validity().cloned(),
+                masked_validity(
+                    array,
+                    is_optional,
+                    &combine_validities(mask.as_ref(), array.validity()),
+                ),
                 is_optional,
                 array.len(),
             ));
@@ -211,6 +237,28 @@ fn to_leaves_recursive<'a>(array: &'a dyn Array, leaves: &mut Vec<&'a dyn Array>
     }
 }
 
+/// The validity of a level: the masked `validity` when optional (since it declares its
+/// definition level), the validity of `array` otherwise.
+fn masked_validity(
+    array: &dyn Array,
+    is_optional: bool,
+    validity: &Option<Bitmap>,
+) -> Option<Bitmap> {
+    if is_optional {
+        validity.clone()
+    } else {
+        array.validity().cloned()
+    }
+}
+
+fn combine_validities(lhs: Option<&Bitmap>, rhs: Option<&Bitmap>) -> Option<Bitmap> {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => Some(lhs & rhs),
+        (Some(validity), None) | (None, Some(validity)) => Some(validity.clone()),
+        (None, None) => None,
+    }
+}
+
 /// Convert `ParquetType` to `Vec<ParquetPrimitiveType>` leaves in DFS order.
 pub fn to_parquet_leaves(type_: ParquetType) -> Vec<ParquetPrimitiveType> {
     let mut leaves = vec![];
@@ -251,7 +299,18 @@ pub fn array_to_columns<A: AsRef<dyn Array> + Send + Sync>(
         .zip(types.into_iter())
         .zip(encoding.iter())
         .map(|(((values, nested), type_), encoding)| {
-            array_to_pages(*values, type_, &nested, options, *encoding)
+            // values in slots of null structs are not written, as their definition level
+            // declares them as null
+            match nested.last() {
+                Some(Nested::Primitive(validity, true, _))
+                    if validity.as_ref() != values.validity()
+                        && values.data_type() != &DataType::Null =>
+                {
+                    let values = values.with_validity(validity.clone());
+                    array_to_pages(values.as_ref(), type_, &nested, options, *encoding)
+                }
+                _ => array_to_pages(*values, type_, &nested, options, *encoding),
+            }
         })
         .collect()
 }
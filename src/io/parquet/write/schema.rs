@@ -20,8 +20,8 @@ use crate::{
 
 use super::super::ARROW_SCHEMA_META_KEY;
 
-pub fn schema_to_metadata_key(schema: &Schema) -> KeyValue {
-    let serialized_schema = schema_to_bytes(schema, &default_ipc_fields(&schema.fields));
+pub fn schema_to_metadata_key(schema: &Schema) -> Result<KeyValue> {
+    let serialized_schema = schema_to_bytes(schema, &default_ipc_fields(&schema.fields))?;
 
     // manually prepending the length to the schema as arrow uses the legacy IPC format
     // TODO: change after addressing ARROW-9777
@@ -33,10 +33,10 @@ pub fn schema_to_metadata_key(schema: &Schema) -> KeyValue {
 
     let encoded = general_purpose::STANDARD.encode(&len_prefix_schema);
 
-    KeyValue {
+    Ok(KeyValue {
         key: ARROW_SCHEMA_META_KEY.to_string(),
         value: Some(encoded),
-    }
+    })
 }
 
 /// Creates a [`ParquetType`] from a [`Field`].
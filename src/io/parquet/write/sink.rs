@@ -215,7 +215,10 @@ where
                                 .collect::<Vec<_>>(),
                         )
                     };
-                    let kv_meta = add_arrow_schema(&this.schema, metadata);
+                    let kv_meta = match add_arrow_schema(&this.schema, metadata) {
+                        Ok(kv_meta) => kv_meta,
+                        Err(error) => return Poll::Ready(Err(error)),
+                    };
 
                     this.task = Some(Box::pin(async move {
                         writer.end(kv_meta).map_err(Error::from).await?;
@@ -3,12 +3,90 @@
 use crate::{
     array::{get_display, Array},
     chunk::Chunk,
+    datatypes::Schema,
 };
 
 use comfy_table::{Cell, Table};
 
+/// The maximum number of rows displayed by [`pretty_format`].
+pub const MAX_ROWS: usize = 20;
+
+/// The maximum number of characters of a cell displayed by [`pretty_format`].
+pub const MAX_WIDTH: usize = 32;
+
 /// Returns a visual representation of [`Chunk`]
 pub fn write<A: AsRef<dyn Array>, N: AsRef<str>>(chunks: &[Chunk<A>], names: &[N]) -> String {
+    write_impl(chunks, names, "", None, None)
+}
+
+/// Returns a visual representation of `chunk` as a table whose headers are the field
+/// names of `schema`.
+///
+/// Null slots are rendered as `null`, cells longer than [`MAX_WIDTH`] characters are
+/// truncated with an ellipsis (`…`) and at most [`MAX_ROWS`] rows are displayed.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// use arrow2::io::print::pretty_format;
+///
+/// let a = Int32Array::from([Some(1), None]);
+/// let b = Utf8Array::<i32>::from([Some("x"), Some("y")]);
+/// let chunk = Chunk::new(vec![a.boxed(), b.boxed()]);
+/// let schema = Schema::from(vec![
+///     Field::new("a", DataType::Int32, true),
+///     Field::new("b", DataType::Utf8, true),
+/// ]);
+///
+/// let table = pretty_format(&chunk, &schema);
+/// assert_eq!(
+///     table.lines().collect::<Vec<_>>(),
+///     vec![
+///         "+------+---+",
+///         "| a    | b |",
+///         "+------+---+",
+///         "| 1    | x |",
+///         "| null | y |",
+///         "+------+---+",
+///     ]
+/// );
+/// ```
+pub fn pretty_format<A: AsRef<dyn Array>>(chunk: &Chunk<A>, schema: &Schema) -> String {
+    let names = schema
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect::<Vec<_>>();
+    write_impl(
+        std::slice::from_ref(chunk),
+        &names,
+        "null",
+        Some(MAX_ROWS),
+        Some(MAX_WIDTH),
+    )
+}
+
+fn truncate(string: String, max_width: usize) -> String {
+    if string.chars().count() <= max_width {
+        string
+    } else {
+        let mut string = string
+            .chars()
+            .take(max_width.saturating_sub(1))
+            .collect::<String>();
+        string.push('…');
+        string
+    }
+}
+
+fn write_impl<A: AsRef<dyn Array>, N: AsRef<str>>(
+    chunks: &[Chunk<A>],
+    names: &[N],
+    null: &'static str,
+    max_rows: Option<usize>,
+    max_width: Option<usize>,
+) -> String {
     let mut table = Table::new();
     table.load_preset("||--+-++|    ++++++");
 
@@ -19,22 +97,31 @@ pub fn write<A: AsRef<dyn Array>, N: AsRef<str>>(chunks: &[Chunk<A>], names: &[N
     let header = names.iter().map(|name| Cell::new(name.as_ref()));
     table.set_header(header);
 
+    let mut remaining = max_rows.unwrap_or(usize::MAX);
+    let total = chunks.iter().map(|chunk| chunk.len()).sum::<usize>();
     for chunk in chunks {
         let displayes = chunk
             .arrays()
             .iter()
-            .map(|array| get_display(array.as_ref(), ""))
+            .map(|array| get_display(array.as_ref(), null))
             .collect::<Vec<_>>();
 
-        for row in 0..chunk.len() {
+        for row in 0..chunk.len().min(remaining) {
             let mut cells = Vec::new();
             (0..chunk.arrays().len()).for_each(|col| {
                 let mut string = String::new();
                 displayes[col](&mut string, row).unwrap();
+                if let Some(max_width) = max_width {
+                    string = truncate(string, max_width);
+                }
                 cells.push(Cell::new(string));
             });
             table.add_row(cells);
         }
+        remaining = remaining.saturating_sub(chunk.len());
+    }
+    if max_rows.map_or(false, |max_rows| total > max_rows) {
+        table.add_row(names.iter().map(|_| Cell::new("…")));
     }
     table.to_string()
 }
@@ -14,6 +14,8 @@
 #![cfg_attr(feature = "simd", feature(portable_simd))]
 #![cfg_attr(feature = "nightly_build", feature(build_hasher_simple_hash_one))]
 
+#[macro_use]
+mod macros;
 #[macro_use]
 pub mod array;
 pub mod bitmap;
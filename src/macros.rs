@@ -0,0 +1,91 @@
+//! Declarative macros to conveniently declare arrays and chunks, e.g. in tests.
+
+/// Converts a comma-separated list of expressions or `null` into a [`Vec`] of [`Option`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __arrow2_options {
+    (@[$($out:expr),*]) => {
+        vec![$($out),*]
+    };
+    (@[$($out:expr),*] null $(, $($rest:tt)*)?) => {
+        $crate::__arrow2_options!(@[$($out,)* None] $($($rest)*)?)
+    };
+    (@[$($out:expr),*] $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__arrow2_options!(@[$($out,)* Some($value)] $($($rest)*)?)
+    };
+}
+
+/// Creates a [`PrimitiveArray`](crate::array::PrimitiveArray) from a list of values,
+/// where `null` declares a null slot.
+///
+/// The type of the array is inferred from its values (e.g. `i32` for integer literals).
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, PrimitiveArray};
+/// use arrow2::array;
+///
+/// let a = array![1, 2, null, 4];
+/// assert_eq!(a, Int32Array::from([Some(1), Some(2), None, Some(4)]));
+///
+/// let a: PrimitiveArray<f64> = array![-1.0, null];
+/// assert_eq!(a, PrimitiveArray::from([Some(-1.0), None]));
+/// ```
+#[macro_export]
+macro_rules! array {
+    ($($values:tt)*) => {
+        $crate::array::PrimitiveArray::from($crate::__arrow2_options!(@[] $($values)*))
+    };
+}
+
+/// Creates a [`Utf8Array<i32>`](crate::array::Utf8Array) from a list of values,
+/// where `null` declares a null slot.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::utf8_array;
+///
+/// let a = utf8_array!["a", null, "c"];
+/// assert_eq!(a, Utf8Array::<i32>::from([Some("a"), None, Some("c")]));
+/// ```
+#[macro_export]
+macro_rules! utf8_array {
+    ($($values:tt)*) => {
+        $crate::array::Utf8Array::<i32>::from($crate::__arrow2_options!(@[] $($values)*))
+    };
+}
+
+/// Creates a [`Schema`](crate::datatypes::Schema) and a [`Chunk`](crate::chunk::Chunk)
+/// from a list of `name => array`.
+///
+/// The fields of the schema are nullable and have the data type of their arrays.
+/// # Panics
+/// Iff the arrays do not have the same length
+/// # Example
+/// ```
+/// use arrow2::datatypes::{DataType, Field};
+/// use arrow2::{array, chunk, utf8_array};
+///
+/// let (schema, chunk) = chunk![
+///     "a" => array![1, null, 3],
+///     "b" => utf8_array!["x", "y", null],
+/// ];
+/// assert_eq!(schema.fields[0], Field::new("a", DataType::Int32, true));
+/// assert_eq!(schema.fields[1], Field::new("b", DataType::Utf8, true));
+/// assert_eq!(chunk.len(), 3);
+/// ```
+#[macro_export]
+macro_rules! chunk {
+    ($($name:expr => $array:expr),* $(,)?) => {{
+        let arrays: Vec<Box<dyn $crate::array::Array>> = vec![$($array.boxed()),*];
+        let names: Vec<String> = vec![$($name.into()),*];
+        let fields = names
+            .into_iter()
+            .zip(arrays.iter())
+            .map(|(name, array)| $crate::datatypes::Field::new(name, array.data_type().clone(), true))
+            .collect::<Vec<_>>();
+        (
+            $crate::datatypes::Schema::from(fields),
+            $crate::chunk::Chunk::new(arrays),
+        )
+    }};
+}
@@ -105,7 +105,8 @@ impl<O: Offset> Offsets<O> {
 
     /// Pushes a new element with a given length.
     /// # Error
-    /// This function errors iff the new last item is larger than what `O` supports.
+    /// This function errors with [`Error::Overflow`] iff the new last item is larger than what
+    /// `O` supports. Use `i64` offsets (e.g. `LargeUtf8` or `LargeBinary`) to hold larger values.
     /// # Panic
     /// This function asserts that `length > 0`.
     #[inline]
@@ -119,7 +120,8 @@ impl<O: Offset> Offsets<O> {
 
     /// Pushes a new element with a given length.
     /// # Error
-    /// This function errors iff the new last item is larger than what `O` supports.
+    /// This function errors with [`Error::Overflow`] iff the new last item is larger than what
+    /// `O` supports. Use `i64` offsets (e.g. `LargeUtf8` or `LargeBinary`) to hold larger values.
     /// # Implementation
     /// This function:
     /// * checks that this length does not overflow
@@ -213,7 +215,8 @@ impl<O: Offset> Offsets<O> {
 
     /// Try to create a new [`Offsets`] from a sequence of `lengths`
     /// # Errors
-    /// This function errors iff this operation overflows for the maximum value of `O`.
+    /// This function errors with [`Error::Overflow`] iff this operation overflows for the maximum
+    /// value of `O`. Use `i64` offsets (e.g. `LargeUtf8` or `LargeBinary`) to hold larger values.
     #[inline]
     pub fn try_from_lengths<I: Iterator<Item = usize>>(lengths: I) -> Result<Self, Error> {
         let mut self_ = Self::with_capacity(lengths.size_hint().0);
@@ -223,37 +226,43 @@ impl<O: Offset> Offsets<O> {
 
     /// Try extend from an iterator of lengths
     /// # Errors
-    /// This function errors iff this operation overflows for the maximum value of `O`.
+    /// This function errors with [`Error::Overflow`] iff this operation overflows for the maximum
+    /// value of `O`. Use `i64` offsets (e.g. `LargeUtf8` or `LargeBinary`) to hold larger values.
     #[inline]
     pub fn try_extend_from_lengths<I: Iterator<Item = usize>>(
         &mut self,
         lengths: I,
     ) -> Result<(), Error> {
-        let mut total_length = 0;
+        let mut total_length = 0usize;
         let mut offset = *self.last();
         let original_offset = offset.to_usize();
+        let original_len = self.0.len();
 
         let lengths = lengths.map(|length| {
-            total_length += length;
+            total_length = total_length.saturating_add(length);
             O::from_as_usize(length)
         });
 
         let offsets = lengths.map(|length| {
-            offset += length; // this may overflow, checked below
+            // this may overflow, checked below
+            offset = O::from_as_usize(offset.to_usize().wrapping_add(length.to_usize()));
             offset
         });
         self.0.extend(offsets);
 
-        let last_offset = original_offset
-            .checked_add(total_length)
-            .ok_or(Error::Overflow)?;
-        O::from_usize(last_offset).ok_or(Error::Overflow)?;
+        let last_offset = original_offset.checked_add(total_length);
+        if last_offset.and_then(O::from_usize).is_none() {
+            // do not leave overflown offsets behind
+            self.0.truncate(original_len);
+            return Err(Error::Overflow);
+        }
         Ok(())
     }
 
     /// Extends itself from another [`Offsets`]
     /// # Errors
-    /// This function errors iff this operation overflows for the maximum value of `O`.
+    /// This function errors with [`Error::Overflow`] iff this operation overflows for the maximum
+    /// value of `O`. Use `i64` offsets (e.g. `LargeUtf8` or `LargeBinary`) to hold larger values.
     pub fn try_extend_from_self(&mut self, other: &Self) -> Result<(), Error> {
         let mut length = *self.last();
         let other_length = *other.last();
@@ -271,7 +280,8 @@ impl<O: Offset> Offsets<O> {
 
     /// Extends itself from another [`Offsets`] sliced by `start, length`
     /// # Errors
-    /// This function errors iff this operation overflows for the maximum value of `O`.
+    /// This function errors with [`Error::Overflow`] iff this operation overflows for the maximum
+    /// value of `O`. Use `i64` offsets (e.g. `LargeUtf8` or `LargeBinary`) to hold larger values.
     pub fn try_extend_from_slice(
         &mut self,
         other: &OffsetsBuffer<O>,
@@ -282,10 +292,10 @@ impl<O: Offset> Offsets<O> {
             return Ok(());
         }
         let other = &other.0[start..start + length + 1];
-        let other_length = other.last().expect("Length to be non-zero");
+        let other_length = *other.last().expect("Length to be non-zero") - other[0];
         let mut length = *self.last();
         // check if the operation would overflow
-        length.checked_add(other_length).ok_or(Error::Overflow)?;
+        length.checked_add(&other_length).ok_or(Error::Overflow)?;
 
         let lengths = other.windows(2).map(|w| w[1] - w[0]);
         let offsets = lengths.map(|new_length| {
@@ -2,7 +2,12 @@
 //! as well as concrete implementations such as [`BooleanScalar`].
 use std::any::Any;
 
-use crate::{array::*, datatypes::*};
+use crate::{
+    array::*,
+    bitmap::MutableBitmap,
+    datatypes::*,
+    error::{Error, Result},
+};
 
 mod dictionary;
 pub use dictionary::*;
@@ -84,6 +89,19 @@ macro_rules! dyn_new_list {
     }};
 }
 
+/// Returns the slot `index` of `array` as a [`Scalar`] of the corresponding type.
+/// The returned scalar is invalid (see [`Scalar::is_valid`]) when the slot is null.
+/// # Panics
+/// This function panics iff `index >= array.len()`.
+pub fn get(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
+    assert!(
+        index < array.len(),
+        "index {index} is out of bounds for an array of length {}",
+        array.len()
+    );
+    new_scalar(array, index)
+}
+
 /// creates a new [`Scalar`] from an [`Array`].
 pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
     use PhysicalType::*;
@@ -182,5 +200,94 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
                 value,
             ))
         }),
+        RunEndEncoded(run_ends_type) => match_integer_type!(run_ends_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<RunEndEncodedArray<$T>>()
+                .unwrap();
+            array.value(index)
+        }),
     }
 }
+
+macro_rules! dyn_to_utf8 {
+    ($scalar:expr, $length:expr, $type:ty) => {{
+        let scalar = $scalar
+            .as_any()
+            .downcast_ref::<Utf8Scalar<$type>>()
+            .unwrap();
+        match scalar.value() {
+            Some(value) => Utf8Array::<$type>::from_trusted_len_values_iter(
+                std::iter::repeat(value).take($length),
+            )
+            .boxed(),
+            None => Utf8Array::<$type>::new_null(scalar.data_type().clone(), $length).boxed(),
+        }
+    }};
+}
+
+macro_rules! dyn_to_binary {
+    ($scalar:expr, $length:expr, $type:ty) => {{
+        let scalar = $scalar
+            .as_any()
+            .downcast_ref::<BinaryScalar<$type>>()
+            .unwrap();
+        match scalar.value() {
+            Some(value) => BinaryArray::<$type>::from_trusted_len_values_iter(
+                std::iter::repeat(value).take($length),
+            )
+            .boxed(),
+            None => BinaryArray::<$type>::new_null(scalar.data_type().clone(), $length).boxed(),
+        }
+    }};
+}
+
+/// creates a new [`Array`] of length `length` whose all slots are equal to the [`Scalar`].
+///
+/// This is the inverse of [`new_scalar`]: `to_array(new_scalar(array, i).as_ref(), 1)`
+/// is equal to `array.sliced(i, 1)`.
+/// # Errors
+/// This function errors iff the scalar's [`PhysicalType`] is not one of
+/// null, boolean, primitive, utf8 or binary.
+pub fn to_array(scalar: &dyn Scalar, length: usize) -> Result<Box<dyn Array>> {
+    use PhysicalType::*;
+    let data_type = scalar.data_type().clone();
+    Ok(match data_type.to_physical_type() {
+        Null => NullArray::new(data_type, length).boxed(),
+        Boolean => {
+            let scalar = scalar.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            match scalar.value() {
+                Some(value) => {
+                    let values = if value {
+                        MutableBitmap::from_len_set(length)
+                    } else {
+                        MutableBitmap::from_len_zeroed(length)
+                    };
+                    BooleanArray::new(data_type, values.into(), None).boxed()
+                }
+                None => BooleanArray::new_null(data_type, length).boxed(),
+            }
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let scalar = scalar
+                .as_any()
+                .downcast_ref::<PrimitiveScalar<$T>>()
+                .unwrap();
+            match scalar.value() {
+                Some(value) => {
+                    PrimitiveArray::<$T>::new(data_type, vec![*value; length].into(), None).boxed()
+                }
+                None => PrimitiveArray::<$T>::new_null(data_type, length).boxed(),
+            }
+        }),
+        Utf8 => dyn_to_utf8!(scalar, length, i32),
+        LargeUtf8 => dyn_to_utf8!(scalar, length, i64),
+        Binary => dyn_to_binary!(scalar, length, i32),
+        LargeBinary => dyn_to_binary!(scalar, length, i64),
+        other => {
+            return Err(Error::NotYetImplemented(format!(
+                "Creating an array from a scalar of physical type {other:?}"
+            )))
+        }
+    })
+}
@@ -206,6 +206,23 @@ fn rev_iter() {
     );
 }
 
+#[test]
+fn iter_sliced() {
+    let array = BinaryArray::<i64>::from([Some("a"), None, Some("bb"), Some(""), None, Some("c")])
+        .sliced(1, 4);
+
+    let iter = array.iter();
+    assert_eq!(iter.len(), 4);
+    let expected = (0..array.len()).map(|i| array.get(i)).collect::<Vec<_>>();
+    assert_eq!(iter.collect::<Vec<_>>(), expected);
+    assert_eq!(expected, vec![None, Some(b"bb".as_ref()), Some(b""), None]);
+
+    let iter = array.values_iter();
+    assert_eq!(iter.len(), 4);
+    let expected = (0..array.len()).map(|i| array.value(i)).collect::<Vec<_>>();
+    assert_eq!(iter.collect::<Vec<_>>(), expected);
+}
+
 #[test]
 fn iter_nth() {
     let array = BinaryArray::<i32>::from([Some("hello"), Some(" "), None]);
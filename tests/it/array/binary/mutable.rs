@@ -213,3 +213,21 @@ fn test_apply_validity_with_no_validity_inited() {
     assert!(array.is_valid(1));
     assert!(array.is_valid(2));
 }
+
+#[test]
+#[ignore] // allocates 2 GiB
+fn try_from_iter_offsets_overflow() {
+    // 2 * 2^30 bytes do not fit in `i32` offsets
+    let value = vec![0u8; 1 << 30];
+    let result = MutableBinaryArray::<i32>::try_from_iter([Some(&value), Some(&value)]);
+    assert!(matches!(result, Err(Error::Overflow)));
+}
+
+#[test]
+#[ignore] // allocates 2 GiB
+#[should_panic]
+fn try_from_trusted_len_iter_offsets_overflow() {
+    let value = vec![0u8; 1 << 30];
+    let iter = vec![Some(&value), Some(&value)].into_iter().map(Ok);
+    let _ = MutableBinaryArray::<i32>::try_from_trusted_len_iter::<Error, _, _>(iter);
+}
@@ -1,4 +1,4 @@
-use arrow2::array::{MutableArray, MutableBooleanArray, TryExtendFromSelf};
+use arrow2::array::{BooleanArray, MutableArray, MutableBooleanArray, TryExtendFromSelf};
 use arrow2::bitmap::MutableBitmap;
 use arrow2::datatypes::DataType;
 use arrow2::error::Result;
@@ -16,6 +16,34 @@ fn set() {
     assert_eq!(a.values(), &MutableBitmap::from([true, false, false]));
 }
 
+#[test]
+fn set_random_access() {
+    let expected = [Some(true), None, Some(false), Some(true), None, Some(false)];
+
+    let mut pushed = MutableBooleanArray::new();
+    expected.iter().for_each(|x| pushed.push(*x));
+
+    let mut a = MutableBooleanArray::from_slice([false; 6]);
+    for index in [4, 0, 5, 3, 1, 2] {
+        a.set(index, expected[index]);
+    }
+    for (index, value) in expected.iter().enumerate() {
+        assert_eq!(a.get(index), *value);
+    }
+    assert!(!a.value(1));
+    assert_eq!(a, pushed);
+
+    let a: BooleanArray = a.into();
+    let pushed: BooleanArray = pushed.into();
+    assert_eq!(a, pushed);
+}
+
+#[test]
+#[should_panic]
+fn get_out_of_bounds() {
+    MutableBooleanArray::from_slice([true, false]).get(2);
+}
+
 #[test]
 fn push() {
     let mut a = MutableBooleanArray::new();
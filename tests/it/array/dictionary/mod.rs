@@ -104,6 +104,21 @@ fn try_new_out_of_bounds_neg() {
     assert!(r);
 }
 
+#[test]
+fn try_new_out_of_bounds_unsigned() {
+    let values = Utf8Array::<i32>::from_slice(["a", "aa"]);
+
+    let r = DictionaryArray::try_from_keys(PrimitiveArray::from_vec(vec![0u32, 2]), values.boxed());
+    let err = r.unwrap_err().to_string();
+    assert!(err.contains("keys is 2"), "{err}");
+
+    let r = DictionaryArray::try_from_keys(
+        PrimitiveArray::from_vec(vec![1u8, 0]),
+        Utf8Array::<i32>::from_slice(["a", "aa"]).boxed(),
+    );
+    assert!(r.is_ok());
+}
+
 #[test]
 fn new_null() {
     let dt = DataType::Dictionary(i16::KEY_TYPE, Box::new(DataType::Int32), false);
@@ -210,3 +225,34 @@ fn iter_values_typed_panic_2() {
     let iter = array.iter_typed::<Utf8Array<i32>>().unwrap();
     let _ = iter.collect::<Vec<_>>();
 }
+
+#[test]
+fn iter_typed_null_key() {
+    let keys = PrimitiveArray::from([Some(1), None, Some(0), Some(1)]);
+    let values = Utf8Array::<i32>::from_slice(["a", "aa"]);
+    let array = DictionaryArray::try_from_keys(keys.clone(), values.clone().boxed()).unwrap();
+
+    assert_eq!(array.keys(), &keys);
+    assert_eq!(array.values().as_ref(), &values as &dyn Array);
+    assert_eq!(
+        array.keys_iter().collect::<Vec<_>>(),
+        vec![Some(1), None, Some(0), Some(1)]
+    );
+
+    let iter = array.iter_typed::<Utf8Array<i32>>().unwrap();
+    assert_eq!(
+        iter.collect::<Vec<_>>(),
+        vec![Some("aa"), None, Some("a"), Some("aa")]
+    );
+    assert_eq!(array.iter().filter(|x| x.is_none()).count(), 1);
+
+    let values = PrimitiveArray::from_slice([10i64, 20]);
+    let array = DictionaryArray::try_from_keys(keys, values.boxed()).unwrap();
+    let iter = array.iter_typed::<PrimitiveArray<i64>>().unwrap();
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(
+        iter.collect::<Vec<_>>(),
+        vec![Some(20), None, Some(10), Some(20)]
+    );
+    assert!(array.iter_typed::<PrimitiveArray<i32>>().is_err());
+}
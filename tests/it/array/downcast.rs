@@ -0,0 +1,46 @@
+use arrow2::array::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn primitive() {
+    let array = Int32Array::from([Some(1), None]).boxed();
+
+    assert_eq!(
+        array.as_primitive::<i32>(),
+        Some(&Int32Array::from([Some(1), None]))
+    );
+    assert!(array.as_primitive::<u32>().is_none());
+    assert!(array.try_as_primitive::<i32>().is_ok());
+    assert!(array.try_as_primitive::<i64>().is_err());
+}
+
+#[test]
+fn offsets() {
+    let array = Utf8Array::<i64>::from_slice(["a"]).boxed();
+
+    assert!(array.as_utf8::<i64>().is_some());
+    assert!(array.as_utf8::<i32>().is_none());
+    assert!(array.as_binary::<i64>().is_none());
+}
+
+#[test]
+fn nested() {
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]).boxed();
+    let array = DictionaryArray::try_from_keys(Int8Array::from_slice([0, 1, 0]), values)
+        .unwrap()
+        .boxed();
+
+    let dictionary = array.as_dictionary::<i8>().unwrap();
+    assert_eq!(dictionary.values().as_utf8::<i32>().unwrap().value(1), "b");
+    assert!(array.as_dictionary::<i32>().is_none());
+}
+
+#[test]
+fn error() {
+    let array = NullArray::new(DataType::Null, 2).boxed();
+
+    assert!(array.try_as_null().is_ok());
+    let error = array.try_as_boolean().unwrap_err().to_string();
+    assert!(error.contains("Null"));
+    assert!(error.contains("BooleanArray"));
+}
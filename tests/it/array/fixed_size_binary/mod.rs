@@ -1,4 +1,9 @@
-use arrow2::{array::FixedSizeBinaryArray, bitmap::Bitmap, buffer::Buffer, datatypes::DataType};
+use arrow2::{
+    array::{Array, FixedSizeBinaryArray},
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+};
 
 mod mutable;
 
@@ -63,6 +68,42 @@ fn from_iter() {
     assert_eq!(a.len(), 2);
 }
 
+#[test]
+fn try_from_iter() {
+    let a =
+        FixedSizeBinaryArray::try_from_iter([Some(b"ab".as_ref()), None, Some(b"cd".as_ref())], 2)
+            .unwrap();
+    assert_eq!(a.size(), 2);
+    assert_eq!(a.data_type(), &DataType::FixedSizeBinary(2));
+    assert_eq!(a.value(2), b"cd");
+    assert_eq!(a.get(1), None);
+
+    let a = a.sliced(2, 1);
+    assert_eq!(a.value(0), b"cd");
+
+    let error =
+        FixedSizeBinaryArray::try_from_iter([Some(b"ab".as_ref()), Some(b"abc".as_ref())], 2)
+            .unwrap_err();
+    assert!(error.to_string().contains("length 3"), "{error}");
+}
+
+#[test]
+fn iter_sliced() {
+    let array = FixedSizeBinaryArray::from([Some(*b"ab"), None, Some(*b"cd"), Some(*b"ef"), None])
+        .sliced(1, 3);
+
+    let iter = array.iter();
+    assert_eq!(iter.len(), 3);
+    let expected = (0..array.len()).map(|i| array.get(i)).collect::<Vec<_>>();
+    assert_eq!(iter.collect::<Vec<_>>(), expected);
+    assert_eq!(expected, vec![None, Some(b"cd".as_ref()), Some(b"ef")]);
+
+    let iter = array.values_iter();
+    assert_eq!(iter.len(), 3);
+    let expected = (0..array.len()).map(|i| array.value(i)).collect::<Vec<_>>();
+    assert_eq!(iter.collect::<Vec<_>>(), expected);
+}
+
 #[test]
 fn wrong_size() {
     let values = Buffer::from(b"abb".to_vec());
@@ -40,6 +40,31 @@ fn test_make_growable() {
     make_growable(&[&array], false, 2);
 }
 
+#[test]
+fn test_make_growable_multiple_sources() {
+    let a = Int32Array::from([Some(1), None, Some(3)]);
+    let b = Int32Array::from_slice([4, 5]);
+    let mut growable = make_growable(&[&a, &b], true, 6);
+    growable.extend(1, 1, 1);
+    growable.extend(0, 0, 2);
+    growable.extend_validity(2);
+    growable.extend(1, 0, 1);
+    assert_eq!(growable.len(), 6);
+    let result = growable.as_box();
+    let expected = Int32Array::from([Some(5), Some(1), None, None, None, Some(4)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let a = Utf8Array::<i32>::from([Some("a"), None, Some("ccc")]);
+    let b = Utf8Array::<i32>::from_slice(["dd", "e"]);
+    let mut growable = make_growable(&[&a, &b], false, 5);
+    growable.extend(0, 2, 1);
+    growable.extend(1, 0, 2);
+    growable.extend(0, 0, 2);
+    let result = growable.as_box();
+    let expected = Utf8Array::<i32>::from([Some("ccc"), Some("dd"), Some("e"), Some("a"), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
 #[test]
 fn test_make_growable_extension() {
     let array = DictionaryArray::try_from_keys(
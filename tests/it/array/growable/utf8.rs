@@ -85,3 +85,27 @@ fn null_offset_validity() {
     let expected = Utf8Array::<i32>::from([None, Some("defh"), None]);
     assert_eq!(result, expected);
 }
+
+/// tests assembling an array from two sliced arrays with nulls, which rebases their offsets
+#[test]
+fn multiple_sliced_with_validity() {
+    let a = Utf8Array::<i32>::from([Some("aa"), None, Some("bbb"), Some("c")]).sliced(1, 3);
+    let b = Utf8Array::<i32>::from([Some("dddd"), Some("e"), None, Some("ff")]).sliced(1, 3);
+
+    let mut a = GrowableUtf8::new(vec![&a, &b], false, 5);
+
+    a.extend(1, 0, 2);
+    a.extend(0, 1, 2);
+    a.extend(0, 0, 1);
+    a.extend(1, 2, 1);
+    assert_eq!(a.len(), 6);
+
+    let result: Utf8Array<i32> = a.into();
+
+    let expected =
+        Utf8Array::<i32>::from([Some("e"), None, Some("bbb"), Some("c"), None, Some("ff")]);
+    assert_eq!(result, expected);
+    // only the bytes of the extended slots are copied
+    assert_eq!(result.values().as_slice(), b"ebbbcff");
+    assert_eq!(result.offsets().as_slice(), &[0, 1, 1, 4, 5, 5, 7]);
+}
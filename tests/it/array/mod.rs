@@ -1,6 +1,7 @@
 mod binary;
 mod boolean;
 mod dictionary;
+mod downcast;
 mod equal;
 mod fixed_size_binary;
 mod fixed_size_list;
@@ -9,11 +10,15 @@ mod list;
 mod map;
 mod ord;
 mod primitive;
+mod run_end;
 mod struct_;
 mod union;
 mod utf8;
 
-use arrow2::array::{clone, new_empty_array, new_null_array, Array, PrimitiveArray};
+use arrow2::array::{
+    clone, new_empty_array, new_null_array, with_validity, Array, BooleanArray, DictionaryArray,
+    Int32Array, Int8Array, NullArray, PrimitiveArray, Utf8Array,
+};
 use arrow2::bitmap::Bitmap;
 use arrow2::datatypes::{DataType, Field, UnionMode};
 
@@ -135,8 +140,112 @@ fn test_with_validity() {
     assert_eq!(arr_ref, &expected);
 }
 
+#[test]
+fn test_with_validity_dyn() {
+    let validity = Bitmap::from([true, false, true]);
+    let arrays = vec![
+        PrimitiveArray::from_slice([1i32, 2, 3]).boxed(),
+        Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed(),
+        BooleanArray::from_slice([true, true, false]).boxed(),
+        new_null_array(
+            DataType::List(Box::new(Field::new("a", DataType::Int32, true))),
+            3,
+        ),
+    ];
+    for array in arrays {
+        let result = with_validity(array.clone(), Some(validity.clone())).unwrap();
+        assert_eq!(result.validity(), Some(&validity));
+        assert_eq!(result.data_type(), array.data_type());
+
+        let result = with_validity(result, None).unwrap();
+        assert_eq!(result.validity(), None);
+
+        assert!(with_validity(array, Some(Bitmap::from([true]))).is_err());
+    }
+
+    let array = NullArray::new(DataType::Null, 3).boxed();
+    assert!(with_validity(array.clone(), None).is_ok());
+    assert!(with_validity(array, Some(validity)).is_err());
+}
+
+#[test]
+fn null_ratio() {
+    let array = PrimitiveArray::<i32>::from([Some(1), None, Some(3), None]);
+    assert!(!array.is_empty());
+    assert_eq!(array.null_ratio(), 0.5);
+    assert!(!array.is_all_null());
+
+    let array = PrimitiveArray::<i32>::new_empty(DataType::Int32);
+    assert!(array.is_empty());
+    assert_eq!(array.null_ratio(), 0.0);
+    assert!(array.is_all_null());
+
+    let array = new_null_array(DataType::Utf8, 3);
+    assert_eq!(array.null_ratio(), 1.0);
+    assert!(array.is_all_null());
+
+    let array = NullArray::new(DataType::Null, 2);
+    assert_eq!(array.null_ratio(), 1.0);
+    assert!(array.is_all_null());
+}
+
 // check that we ca derive stuff
 #[derive(PartialEq, Clone, Debug)]
 struct A {
     array: Box<dyn Array>,
 }
+
+#[test]
+fn null_array_sliced() {
+    let array = NullArray::new(DataType::Null, 5);
+    let array = array.sliced(1, 3);
+    assert_eq!(array, NullArray::new(DataType::Null, 3));
+    assert_eq!(array.null_count(), 3);
+}
+
+#[test]
+fn shallow_and_deep_clone() {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("cde")]).boxed();
+    let data = |array: &dyn Array| {
+        let array = array.as_utf8::<i32>().unwrap();
+        (array.values().as_ptr(), array.offsets().buffer().as_ptr())
+    };
+
+    let shallow = array.clone();
+    assert_eq!(data(shallow.as_ref()), data(array.as_ref()));
+    let shallow = array.to_boxed();
+    assert_eq!(data(shallow.as_ref()), data(array.as_ref()));
+
+    let deep = array.deep_clone();
+    assert_eq!(deep, array);
+    let (values, offsets) = data(deep.as_ref());
+    assert_ne!(values, data(array.as_ref()).0);
+    assert_ne!(offsets, data(array.as_ref()).1);
+}
+
+#[test]
+fn deep_clone_nested() {
+    let values = Int32Array::from_slice([1, 2, 3]).boxed();
+    let array = DictionaryArray::try_from_keys(Int8Array::from([Some(2), None, Some(0)]), values)
+        .unwrap()
+        .sliced(1, 2)
+        .boxed();
+
+    let deep = array.deep_clone();
+    assert_eq!(deep, array);
+    let deep = deep.as_dictionary::<i8>().unwrap();
+    let array = array.as_dictionary::<i8>().unwrap();
+    assert_ne!(
+        deep.values()
+            .as_primitive::<i32>()
+            .unwrap()
+            .values()
+            .as_ptr(),
+        array
+            .values()
+            .as_primitive::<i32>()
+            .unwrap()
+            .values()
+            .as_ptr()
+    );
+}
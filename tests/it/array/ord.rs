@@ -68,6 +68,28 @@ fn f64_zeros() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn total_cmp_nans() {
+    use arrow2::array::ord::{total_cmp_f64, total_cmp_nans_last_f64};
+
+    // IEEE 754 totalOrder: a negative NaN is smaller than any other value
+    assert_eq!(
+        total_cmp_f64(&-f64::NAN, &f64::NEG_INFINITY),
+        Ordering::Less
+    );
+    assert_eq!(total_cmp_f64(&-f64::NAN, &f64::NAN), Ordering::Less);
+
+    assert_eq!(
+        total_cmp_nans_last_f64(&-f64::NAN, &f64::INFINITY),
+        Ordering::Greater
+    );
+    assert_eq!(
+        total_cmp_nans_last_f64(&-f64::NAN, &f64::NAN),
+        Ordering::Equal
+    );
+    assert_eq!(total_cmp_nans_last_f64(&-0.0, &0.0), Ordering::Less);
+}
+
 #[test]
 fn decimal() -> Result<()> {
     let array = Int128Array::from_slice([1, 2]).to(DataType::Decimal(38, 0));
@@ -136,3 +136,161 @@ fn into_iter() {
 
     assert!(array.into_iter().rev().eq(rev))
 }
+
+#[test]
+fn and_validity() {
+    let mut array = Int32Array::from([Some(1), None, Some(3), Some(4)]);
+    array.and_validity(&Bitmap::from([true, true, false, true]));
+    assert_eq!(array, Int32Array::from([Some(1), None, None, Some(4)]));
+
+    let mut array = Int32Array::from_slice([1, 2]);
+    array.and_validity(&Bitmap::from([false, true]));
+    assert_eq!(array, Int32Array::from([None, Some(2)]));
+}
+
+#[test]
+#[should_panic]
+fn and_validity_wrong_length() {
+    Int32Array::from_slice([1, 2]).and_validity(&Bitmap::from([true]));
+}
+
+#[test]
+fn from_vec_no_copy() {
+    let values = vec![1i32, 2, 3];
+    let ptr = values.as_ptr();
+    let array = PrimitiveArray::from_vec(values);
+    assert_eq!(array.values().as_ptr(), ptr);
+
+    let values = vec![1i32, 2, 3];
+    let ptr = values.as_ptr();
+    let array = PrimitiveArray::from_vec_with_validity(values, Some([true, false, true].into()));
+    assert_eq!(array.values().as_ptr(), ptr);
+    assert_eq!(array, Int32Array::from([Some(1), None, Some(3)]));
+}
+
+#[test]
+#[should_panic]
+fn from_vec_with_validity_wrong_length() {
+    PrimitiveArray::from_vec_with_validity(vec![1i32, 2], Some([true].into()));
+}
+
+#[test]
+fn iter_double_ended() {
+    let data = vec![
+        Some(1),
+        None,
+        Some(3),
+        Some(4),
+        None,
+        Some(6),
+        Some(7),
+        None,
+        Some(9),
+    ];
+    let array = Int32Array::from(data.clone()).sliced(1, 7);
+    let data = &data[1..8];
+
+    let forward = array.iter().map(|x| x.copied()).collect::<Vec<_>>();
+    assert_eq!(forward, data);
+    let mut backward = array.iter().rev().map(|x| x.copied()).collect::<Vec<_>>();
+    backward.reverse();
+    assert_eq!(backward, forward);
+
+    // both ends can be consumed alternately while the length remains exact
+    let mut iter = array.iter();
+    assert_eq!(iter.len(), 7);
+    assert_eq!(iter.next(), Some(None));
+    assert_eq!(iter.next_back(), Some(None));
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.next_back(), Some(Some(&7)));
+    assert_eq!(iter.next(), Some(Some(&3)));
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![Some(&4), None, Some(&6)]);
+}
+
+#[test]
+fn offset_and_values_buffer() {
+    let array = Int32Array::from_slice([1, 2, 3, 4, 5]);
+    assert_eq!(array.values_offset(), 0);
+
+    let sliced = array.sliced(1, 3).sliced(1, 2);
+    assert_eq!(sliced.values_offset(), 2);
+    assert_eq!(sliced.values().as_slice(), &[3, 4]);
+
+    let buffer = sliced.values_buffer();
+    assert_eq!(buffer.len(), 5);
+    assert_eq!(buffer.as_slice(), &[1, 2, 3, 4, 5]);
+    assert_eq!(
+        &buffer[sliced.values_offset()..sliced.values_offset() + sliced.len()],
+        sliced.values().as_slice()
+    );
+    // the data is shared
+    assert_eq!(buffer.as_ptr(), unsafe { sliced.values().as_ptr().sub(2) });
+}
+
+#[cfg(feature = "compute_sort")]
+#[test]
+fn search_sorted_nulls_last() {
+    use arrow2::compute::sort::SortOptions;
+
+    let array = Int32Array::from([Some(1), Some(3), Some(3), Some(5), None, None]);
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let search = |value| array.search_sorted(value, &options);
+    assert_eq!(search(0), 0);
+    assert_eq!(search(1), 0);
+    assert_eq!(search(2), 1);
+    assert_eq!(search(3), 1);
+    assert_eq!(search(4), 3);
+    // the nulls are not searched
+    assert_eq!(search(6), 4);
+    assert_eq!(search(i32::MAX), 4);
+}
+
+#[cfg(feature = "compute_sort")]
+#[test]
+fn search_sorted_nulls_first_descending() {
+    use arrow2::compute::sort::SortOptions;
+
+    let array = Int32Array::from([None, Some(5), Some(3), Some(1)]).sliced(1, 3);
+    let options = SortOptions {
+        descending: true,
+        nulls_first: true,
+    };
+    assert_eq!(array.search_sorted(6, &options), 0);
+    assert_eq!(array.search_sorted(3, &options), 1);
+    assert_eq!(array.search_sorted(0, &options), 3);
+
+    let array = Int32Array::from([None, None, Some(5), Some(3)]);
+    assert_eq!(array.search_sorted(4, &options), 3);
+    assert_eq!(array.search_sorted(i32::MIN, &options), 4);
+}
+
+#[cfg(feature = "compute_sort")]
+#[test]
+fn search_sorted_floats() {
+    use arrow2::compute::sort::{sort, SortOptions};
+
+    let mut options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    // searched in the order `sort` sorts them: -0.0 < 0.0 and NaNs last
+    let array = Float64Array::from_slice([f64::NAN, 1.0, -0.0, -f64::NAN, 0.0]);
+    let sorted = sort(&array, &options, None).unwrap();
+    let sorted = sorted.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(sorted.search_sorted(-0.0, &options), 0);
+    assert_eq!(sorted.search_sorted(0.0, &options), 1);
+    assert_eq!(sorted.search_sorted(2.0, &options), 3);
+    assert_eq!(sorted.search_sorted(f64::NAN, &options), 3);
+    assert_eq!(sorted.search_sorted(-f64::NAN, &options), 3);
+
+    options.descending = true;
+    let sorted = sort(&array, &options, None).unwrap();
+    let sorted = sorted.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(sorted.search_sorted(f64::NAN, &options), 0);
+    assert_eq!(sorted.search_sorted(1.0, &options), 2);
+    assert_eq!(sorted.search_sorted(-1.0, &options), 5);
+}
@@ -299,6 +299,26 @@ fn set_validity() {
     assert_eq!(a.validity(), Some(&MutableBitmap::from([false, true])));
 }
 
+#[test]
+fn apply_validity() {
+    let mut a = MutablePrimitiveArray::<i32>::from([Some(1), None, Some(3), Some(4)]);
+    a.apply_validity(&Bitmap::from([true, true, false, true]));
+    assert_eq!(
+        a,
+        MutablePrimitiveArray::<i32>::from([Some(1), None, None, Some(4)])
+    );
+
+    let mut a = MutablePrimitiveArray::<i32>::from_slice([1, 2]);
+    a.apply_validity(&Bitmap::from([false, true]));
+    assert_eq!(a, MutablePrimitiveArray::<i32>::from([None, Some(2)]));
+}
+
+#[test]
+#[should_panic]
+fn apply_validity_wrong_length() {
+    MutablePrimitiveArray::<i32>::from_slice([1, 2]).apply_validity(&Bitmap::from([true]));
+}
+
 #[test]
 fn set_values() {
     let mut a = MutablePrimitiveArray::<i32>::from_slice([1, 2]);
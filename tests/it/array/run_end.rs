@@ -0,0 +1,226 @@
+use arrow2::array::growable::make_growable;
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field, IntegerType};
+use arrow2::scalar::{new_scalar, PrimitiveScalar};
+
+fn array() -> Int32Array {
+    Int32Array::from([
+        Some(1),
+        Some(1),
+        Some(1),
+        None,
+        None,
+        Some(2),
+        Some(1),
+        Some(1),
+    ])
+}
+
+#[test]
+fn encode_decode() {
+    let array = array();
+    let encoded = RunEndEncodedArray::<i32>::encode(&array).unwrap();
+
+    assert_eq!(encoded.len(), 8);
+    assert_eq!(encoded.run_ends(), &Int32Array::from_slice([3, 5, 6, 8]));
+    assert_eq!(
+        encoded.values().as_ref(),
+        &Int32Array::from([Some(1), None, Some(2), Some(1)]) as &dyn Array
+    );
+    assert_eq!(encoded.decode().as_ref(), &array as &dyn Array);
+
+    let encoded =
+        RunEndEncodedArray::<i16>::encode(&Utf8Array::<i32>::from_slice(["a", "a", "b"])).unwrap();
+    assert_eq!(encoded.run_ends(), &Int16Array::from_slice([2, 3]));
+
+    let empty = RunEndEncodedArray::<i64>::encode(&Int32Array::from_slice([])).unwrap();
+    assert!(empty.is_empty());
+    assert_eq!(empty.decode().len(), 0);
+}
+
+#[test]
+fn value() {
+    let encoded = RunEndEncodedArray::<i32>::encode(&array()).unwrap();
+
+    assert_eq!(encoded.physical_index(0), 0);
+    assert_eq!(encoded.physical_index(2), 0);
+    assert_eq!(encoded.physical_index(3), 1);
+    assert_eq!(encoded.physical_index(7), 3);
+    assert!(!encoded.value(4).is_valid());
+
+    let value = encoded.value(5);
+    let value = value
+        .as_any()
+        .downcast_ref::<PrimitiveScalar<i32>>()
+        .unwrap();
+    assert_eq!(value.value(), &Some(2));
+}
+
+#[test]
+fn take() {
+    let array = array();
+    let encoded = RunEndEncodedArray::<i32>::encode(&array).unwrap();
+
+    let indices = UInt32Array::from([Some(0), Some(1), Some(6), None, Some(5), Some(5)]);
+    let taken = encoded.take(&indices).unwrap();
+
+    // 0 and 1 are in the same run, 6 is in a different run with the same value
+    assert_eq!(taken.run_ends(), &Int32Array::from_slice([2, 3, 4, 6]));
+    let expected = Int32Array::from([Some(1), Some(1), Some(1), None, Some(2), Some(2)]);
+    assert_eq!(taken.decode().as_ref(), &expected as &dyn Array);
+
+    assert!(encoded.take(&UInt32Array::from_slice([8])).is_err());
+}
+
+#[test]
+fn filter() {
+    let array = array();
+    let encoded = RunEndEncodedArray::<i32>::encode(&array).unwrap();
+
+    let mask = BooleanArray::from([
+        Some(true),
+        Some(false),
+        Some(true),
+        Some(false),
+        Some(false),
+        None,
+        Some(true),
+        Some(true),
+    ]);
+    let filtered = encoded.filter(&mask).unwrap();
+
+    assert_eq!(filtered.run_ends(), &Int32Array::from_slice([2, 4]));
+    let expected = Int32Array::from_slice([1, 1, 1, 1]);
+    assert_eq!(filtered.decode().as_ref(), &expected as &dyn Array);
+
+    assert!(encoded.filter(&BooleanArray::from_slice([true])).is_err());
+}
+
+#[test]
+fn try_new_errors() {
+    let values = Int32Array::from_slice([1, 2]).boxed();
+    assert!(
+        RunEndEncodedArray::try_from_run_ends(Int32Array::from_slice([2, 2]), values.clone())
+            .is_err()
+    );
+    assert!(
+        RunEndEncodedArray::try_from_run_ends(Int32Array::from_slice([0, 2]), values.clone())
+            .is_err()
+    );
+    assert!(
+        RunEndEncodedArray::try_from_run_ends(Int32Array::from_slice([2]), values.clone()).is_err()
+    );
+    assert!(RunEndEncodedArray::try_from_run_ends(
+        Int32Array::from([Some(1), None]),
+        values.clone()
+    )
+    .is_err());
+    assert!(RunEndEncodedArray::try_from_run_ends(Int32Array::from_slice([1, 3]), values).is_ok());
+}
+
+#[test]
+fn try_new_data_type() {
+    let values = Int32Array::from_slice([1, 2]).boxed();
+    let run_ends = Int16Array::from_slice([1, 3]);
+
+    let field = Box::new(Field::new("values", DataType::Int32, false));
+    let data_type = DataType::RunEndEncoded(IntegerType::Int16, field.clone());
+    let array =
+        RunEndEncodedArray::try_new(data_type.clone(), run_ends.clone(), values.clone()).unwrap();
+    assert_eq!(array.data_type(), &data_type);
+
+    // wrong run ends type
+    let data_type = DataType::RunEndEncoded(IntegerType::Int32, field);
+    assert!(RunEndEncodedArray::try_new(data_type, run_ends.clone(), values.clone()).is_err());
+    // wrong values type
+    let field = Box::new(Field::new("values", DataType::Int64, false));
+    let data_type = DataType::RunEndEncoded(IntegerType::Int16, field);
+    assert!(RunEndEncodedArray::try_new(data_type, run_ends.clone(), values.clone()).is_err());
+    assert!(RunEndEncodedArray::try_new(DataType::Int32, run_ends, values).is_err());
+}
+
+#[test]
+fn as_array() {
+    let array = array();
+    let encoded = RunEndEncodedArray::<i32>::encode(&array).unwrap().boxed();
+
+    assert_eq!(encoded.len(), 8);
+    assert_eq!(
+        encoded.data_type(),
+        &RunEndEncodedArray::<i32>::default_data_type(DataType::Int32)
+    );
+    // nulls are the values': the array itself has no validity
+    assert_eq!(encoded.validity(), None);
+    assert_eq!(encoded.null_count(), 0);
+    assert!(!encoded.is_null(3));
+    assert!(!new_scalar(encoded.as_ref(), 3).is_valid());
+    assert!(new_scalar(encoded.as_ref(), 5).is_valid());
+    assert_eq!(
+        format!("{encoded:?}"),
+        "RunEndEncodedArray[1, 1, 1, None, None, 2, 1, 1]"
+    );
+
+    // equality is logical
+    let other = RunEndEncodedArray::<i32>::try_from_run_ends(
+        Int32Array::from_slice([1, 3, 5, 6, 8]),
+        Int32Array::from([Some(1), Some(1), None, Some(2), Some(1)]).boxed(),
+    )
+    .unwrap();
+    assert_eq!(encoded.as_ref(), &other as &dyn Array);
+    assert_ne!(encoded.as_ref(), &other.sliced(0, 7) as &dyn Array);
+}
+
+#[test]
+fn slice() {
+    let array = array();
+    let encoded = RunEndEncodedArray::<i32>::encode(&array).unwrap();
+
+    let sliced = encoded.clone().sliced(2, 4);
+    assert_eq!(sliced.len(), 4);
+    assert_eq!(sliced.run_ends(), &Int32Array::from_slice([1, 3, 4]));
+    assert_eq!(
+        sliced.decode().as_ref(),
+        array.sliced(2, 4).boxed().as_ref()
+    );
+
+    let sliced = encoded.clone().sliced(3, 0);
+    assert!(sliced.is_empty());
+    assert_eq!(sliced.values().len(), 0);
+
+    let sliced = encoded.sliced(7, 1);
+    assert_eq!(sliced.run_ends(), &Int32Array::from_slice([1]));
+}
+
+#[test]
+fn new_empty_null() {
+    let data_type = RunEndEncodedArray::<i16>::default_data_type(DataType::Utf8);
+
+    let array = new_empty_array(data_type.clone());
+    assert_eq!(array.data_type(), &data_type);
+    assert!(array.is_empty());
+
+    let array = new_null_array(data_type.clone(), 3);
+    assert_eq!(array.data_type(), &data_type);
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.null_count(), 0);
+    assert!((0..3).all(|i| !new_scalar(array.as_ref(), i).is_valid()));
+}
+
+#[test]
+fn growable() {
+    let encoded = RunEndEncodedArray::<i32>::encode(&array()).unwrap();
+    let other = RunEndEncodedArray::<i32>::encode(&Int32Array::from_slice([2])).unwrap();
+
+    let mut growable = make_growable(&[&encoded, &other], false, 3);
+    growable.extend(0, 1, 4);
+    growable.extend(1, 0, 1);
+    growable.extend_validity(2);
+    let result = growable.as_box();
+    let result = result
+        .as_any()
+        .downcast_ref::<RunEndEncodedArray<i32>>()
+        .unwrap();
+    assert_eq!(result.run_ends(), &Int32Array::from_slice([2, 4, 5, 7]));
+    let expected = Int32Array::from([Some(1), Some(1), None, None, Some(2), None, None]);
+    assert_eq!(result.decode().as_ref(), &expected as &dyn Array);
+}
@@ -25,3 +25,67 @@ fn debug() {
         "StructArray[{b: false, c: 42}, {b: false, c: 28}, None, {b: true, c: 31}]"
     );
 }
+
+#[test]
+fn map_field() {
+    let boolean = BooleanArray::from_slice([false, false, true, true]).boxed();
+    let int = Int32Array::from_slice([42, 28, 19, 31]).boxed();
+
+    let fields = vec![
+        Field::new("b", DataType::Boolean, false),
+        Field::new("c", DataType::Int32, false),
+    ];
+    let validity = Some(Bitmap::from([true, true, false, true]));
+    let array = StructArray::new(
+        DataType::Struct(fields.clone()),
+        vec![boolean.clone(), int],
+        validity.clone(),
+    );
+
+    let doubled = array
+        .map_field(1, |c| {
+            let c = c.as_any().downcast_ref::<Int32Array>().unwrap();
+            Ok(Int32Array::from_iter(c.iter().map(|x| x.map(|x| x * 2))).boxed())
+        })
+        .unwrap();
+    assert_eq!(doubled.data_type(), &DataType::Struct(fields));
+    assert_eq!(doubled.validity(), validity.as_ref());
+    assert_eq!(doubled.values()[0], boolean);
+    assert_eq!(
+        doubled.values()[1].as_ref(),
+        &Int32Array::from_slice([84, 56, 38, 62]) as &dyn Array
+    );
+
+    let as_i64 = array
+        .map_field(1, |_| Ok(Int64Array::from_slice([1, 2, 3, 4]).boxed()))
+        .unwrap();
+    assert_eq!(as_i64.fields()[1], Field::new("c", DataType::Int64, false));
+
+    assert!(array.map_field(2, |c| Ok(c.to_boxed())).is_err());
+    assert!(array.map_field(1, |c| Ok(c.sliced(0, 1))).is_err());
+}
+
+#[test]
+fn map_field_extension() {
+    let fields = vec![Field::new("c", DataType::Int32, false)];
+    let extension = |fields| {
+        DataType::Extension(
+            "ext".to_string(),
+            Box::new(DataType::Struct(fields)),
+            Some("metadata".to_string()),
+        )
+    };
+    let array = StructArray::new(
+        extension(fields),
+        vec![Int32Array::from_slice([1, 2]).boxed()],
+        None,
+    );
+
+    let as_i64 = array
+        .map_field(0, |_| Ok(Int64Array::from_slice([1, 2]).boxed()))
+        .unwrap();
+    assert_eq!(
+        as_i64.data_type(),
+        &extension(vec![Field::new("c", DataType::Int64, false)])
+    );
+}
@@ -0,0 +1,57 @@
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, IntegerType};
+
+#[test]
+fn low_cardinality_to_dictionary() {
+    let mut array = MutableUtf8Array::<i32>::with_interning();
+    for i in 0..100 {
+        array.push(Some(["a", "bb", "ccc"][i % 3]));
+    }
+    array.push::<&str>(None);
+    assert_eq!(array.len(), 101);
+    assert_eq!(array.cardinality(), 3);
+
+    let result = array.finish(0.5).unwrap();
+
+    assert_eq!(
+        result.data_type(),
+        &DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false)
+    );
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(
+        result.values().as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "bb", "ccc"]) as &dyn Array
+    );
+    assert_eq!(result.keys().value(4), 1);
+    assert!(result.is_null(100));
+}
+
+#[test]
+fn high_cardinality_to_utf8() {
+    let mut array = MutableUtf8Array::<i64>::with_interning();
+    array.try_push(Some("a")).unwrap();
+    array.try_push(Some("b")).unwrap();
+    array.try_push(None::<&str>).unwrap();
+    array.try_push(Some("a")).unwrap();
+
+    // 2 distinct values in 4 rows
+    let result = array.finish(0.4).unwrap();
+
+    let expected = Utf8Array::<i64>::from([Some("a"), Some("b"), None, Some("a")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn empty() {
+    let array = MutableUtf8Array::<i32>::with_interning();
+    assert!(array.is_empty());
+
+    let result = array.finish(0.5).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Utf8Array::<i32>::new_empty(DataType::Utf8) as &dyn Array
+    );
+}
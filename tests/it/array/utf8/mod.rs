@@ -3,6 +3,7 @@ use arrow2::{
     offset::OffsetsBuffer,
 };
 
+mod interned;
 mod mutable;
 mod mutable_values;
 mod to_mutable;
@@ -113,6 +114,33 @@ fn try_from_trusted_len_iter() {
     );
 }
 
+#[test]
+fn try_from_iter() {
+    let a = Utf8Array::<i32>::try_from_iter(
+        vec![Some(b"a".as_ref()), None, Some("π".as_bytes())].into_iter(),
+    )
+    .unwrap();
+    assert_eq!(a, Utf8Array::<i32>::from([Some("a"), None, Some("π")]));
+}
+
+#[test]
+fn try_from_iter_not_utf8() {
+    let values = [Some(b"a".as_ref()), None, Some([0, 159, 146, 150].as_ref())];
+    let error = Utf8Array::<i32>::try_from_iter(values.into_iter()).unwrap_err();
+    assert!(error.to_string().contains("index 2"));
+}
+
+#[test]
+fn from_data_unchecked() {
+    let offsets = Buffer::from(vec![0i64, 2, 2, 7]);
+    let values = Buffer::from(b"hithere".to_vec());
+    let validity = Some(Bitmap::from([true, false, true]));
+    let a = unsafe { Utf8Array::<i64>::from_data_unchecked(offsets, values, validity) };
+
+    assert_eq!(a.data_type(), &DataType::LargeUtf8);
+    assert_eq!(a, Utf8Array::<i64>::from([Some("hi"), None, Some("there")]));
+}
+
 #[test]
 fn not_utf8() {
     let offsets = vec![0, 4].try_into().unwrap();
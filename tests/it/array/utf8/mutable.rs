@@ -1,6 +1,7 @@
 use arrow2::array::{MutableArray, MutableUtf8Array, TryExtendFromSelf, Utf8Array};
 use arrow2::bitmap::Bitmap;
 use arrow2::datatypes::DataType;
+use arrow2::error::Error;
 
 #[test]
 fn capacities() {
@@ -240,3 +241,20 @@ fn test_apply_validity_with_no_validity_inited() {
     assert!(array.is_valid(1));
     assert!(array.is_valid(2));
 }
+
+#[test]
+#[ignore] // allocates 2 GiB
+fn try_from_iter_offsets_overflow() {
+    // 2 * 2^30 bytes do not fit in `i32` offsets
+    let value = "a".repeat(1 << 30);
+    let result = MutableUtf8Array::<i32>::try_from_iter([Some(&value), Some(&value)]);
+    assert!(matches!(result, Err(Error::Overflow)));
+}
+
+#[test]
+#[ignore] // allocates 2 GiB
+#[should_panic(expected = "Overflow")]
+fn from_iter_offsets_overflow() {
+    let value = "a".repeat(1 << 30);
+    let _ = Utf8Array::<i32>::from_iter([Some(&value), Some(&value)]);
+}
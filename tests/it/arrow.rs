@@ -236,6 +236,17 @@ fn test_dictionary() {
     test_conversion(&dictionary);
 }
 
+#[test]
+fn test_run_end_encoded() {
+    let values = Utf8Array::<i32>::from([Some("a"), None, Some("b")]);
+    let array =
+        RunEndEncodedArray::try_from_run_ends(Int32Array::from_slice([2, 3, 6]), values.boxed())
+            .unwrap();
+
+    test_arrow_roundtrip(&array);
+    test_arrow_roundtrip(&array.sliced(1, 4));
+}
+
 #[test]
 fn test_fixed_size_binary() {
     let data = (0_u8..16).collect::<Vec<_>>();
@@ -1,4 +1,5 @@
 use arrow2::bitmap::Bitmap;
+use arrow2::buffer::Buffer;
 
 #[test]
 fn as_slice() {
@@ -65,3 +66,55 @@ fn from_arrow() {
     let back = NullBuffer::from(bitmap);
     assert_eq!(nulls, back);
 }
+
+#[test]
+fn from_bytes() {
+    let bytes: Buffer<u8> = vec![0b11111111, 0b00001101, 0b11110000].into();
+    let ptr = bytes.as_ptr();
+
+    let bitmap = Bitmap::from_bytes(bytes.clone().sliced(1, 2), 10).unwrap();
+    assert_eq!(bitmap.len(), 10);
+    assert!(bitmap.get_bit(0));
+    assert!(!bitmap.get_bit(1));
+    assert!(bitmap.get_bit(2));
+    assert!(!bitmap.get_bit(9));
+    assert_eq!(bitmap.unset_bits(), 7);
+    // the bytes are shared, not copied
+    let (slice, offset, _) = bitmap.as_slice();
+    assert_eq!(slice.as_ptr(), unsafe { ptr.add(1) });
+    assert_eq!(offset, 0);
+
+    assert!(Bitmap::from_bytes(bytes.sliced(1, 2), 17).is_err());
+}
+
+#[test]
+fn to_bool_vec() {
+    let values = (0..150)
+        .map(|x| x % 3 == 0 || x % 7 == 0)
+        .collect::<Vec<_>>();
+    let bitmap = Bitmap::from(values.as_slice());
+    assert_eq!(bitmap.to_bool_vec(), values);
+
+    // respects the offset
+    let sliced = bitmap.sliced(13, 130);
+    assert_eq!(sliced.to_bool_vec(), &values[13..143]);
+    assert_eq!(sliced.to_bool_vec(), sliced.iter().collect::<Vec<_>>());
+
+    assert_eq!(Bitmap::from(sliced.to_bool_vec()), sliced);
+    assert!(Bitmap::new().to_bool_vec().is_empty());
+}
+
+#[test]
+fn from_bool_slice() {
+    let values = (0..150)
+        .map(|x| x % 3 == 0 || x % 7 == 0)
+        .collect::<Vec<_>>();
+    let bitmap = Bitmap::from_bool_slice(&values);
+    assert_eq!(bitmap, Bitmap::from(values.as_slice()));
+    assert_eq!(bitmap.to_bool_vec(), values);
+
+    // round trip of a sliced bitmap
+    let sliced = bitmap.sliced(13, 130);
+    assert_eq!(Bitmap::from_bool_slice(&sliced.to_bool_vec()), sliced);
+    assert_eq!(Bitmap::from_bool_slice(&[]), Bitmap::new());
+}
@@ -44,6 +44,33 @@ fn from_vec() {
     assert_eq!(buffer.as_slice(), &[0, 1, 2]);
 }
 
+#[test]
+fn from_vec_into_mut() {
+    let data = vec![0i32, 1, 2];
+    let ptr = data.as_ptr();
+    let buffer = Buffer::from_vec(data);
+    assert_eq!(buffer.as_ptr(), ptr);
+
+    // uniquely owned: the same allocation is returned
+    let data = buffer.into_mut().right().unwrap();
+    assert_eq!(data.as_ptr(), ptr);
+    assert_eq!(data, vec![0, 1, 2]);
+
+    // shared: the buffer is returned
+    let buffer = Buffer::from_vec(data);
+    let shared = buffer.clone();
+    let buffer = buffer.into_mut().left().unwrap();
+    assert_eq!(buffer, shared);
+    drop(shared);
+    assert_eq!(buffer.into_mut().right().unwrap(), vec![0, 1, 2]);
+
+    // sliced: only the visible values are returned
+    let buffer = Buffer::from_vec(vec![0i32, 1, 2]).sliced(0, 2);
+    assert_eq!(buffer.into_mut().right().unwrap(), vec![0, 1]);
+    let buffer = Buffer::from_vec(vec![0i32, 1, 2]).sliced(1, 2);
+    assert_eq!(buffer.into_mut().left().unwrap().as_slice(), &[1, 2]);
+}
+
 #[test]
 #[cfg(feature = "arrow")]
 fn from_arrow() {
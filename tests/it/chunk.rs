@@ -0,0 +1,183 @@
+use arrow2::array::*;
+use arrow2::chunk::{chunk_equal_by_name, Chunk};
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::Error;
+
+fn chunk() -> Chunk<Box<dyn Array>> {
+    Chunk::new(vec![
+        Int32Array::from_slice([1, 2]).boxed(),
+        Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+        BooleanArray::from_slice([true, false]).boxed(),
+    ])
+}
+
+#[test]
+fn try_new() {
+    let chunk = Chunk::try_new(vec![
+        Int32Array::from_slice([1, 2]).boxed(),
+        Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+    ])
+    .unwrap();
+    assert_eq!(chunk.len(), 2);
+
+    let result = Chunk::try_new(vec![
+        Int32Array::from_slice([1, 2]).boxed(),
+        Utf8Array::<i32>::from_slice(["a"]).boxed(),
+    ]);
+    assert!(matches!(result, Err(Error::InvalidArgumentError(_))));
+
+    assert!(Chunk::<Box<dyn Array>>::try_new(vec![]).unwrap().is_empty());
+}
+
+#[test]
+#[should_panic]
+fn new_different_lengths() {
+    Chunk::new(vec![
+        Int32Array::from_slice([1, 2]).boxed(),
+        Int32Array::from_slice([1]).boxed(),
+    ]);
+}
+
+#[test]
+fn project() {
+    let chunk = chunk();
+
+    let projected = chunk.project(&[2, 0, 2]).unwrap();
+    assert_eq!(
+        projected.arrays(),
+        &[chunk[2].clone(), chunk[0].clone(), chunk[2].clone()]
+    );
+    assert_eq!(projected.len(), 2);
+
+    assert!(chunk.project(&[]).unwrap().is_empty());
+}
+
+#[test]
+fn project_out_of_bounds() {
+    assert!(chunk().project(&[0, 3]).is_err());
+}
+
+#[test]
+fn project_by_name() {
+    let chunk = chunk();
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, false),
+        Field::new("c", DataType::Boolean, false),
+    ]);
+
+    let projected = chunk.project_by_name(&schema, &["c", "a"]).unwrap();
+    assert_eq!(projected.arrays(), &[chunk[2].clone(), chunk[0].clone()]);
+
+    assert!(chunk.project_by_name(&schema, &["d"]).is_err());
+
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    assert!(chunk.project_by_name(&schema, &["a"]).is_err());
+}
+
+#[test]
+fn equal_by_name() {
+    let chunk = chunk();
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, false),
+        Field::new("c", DataType::Boolean, false),
+    ]);
+    let reordered = chunk.project(&[2, 0, 1]).unwrap();
+    let reordered_schema = Schema::from(vec![
+        schema.fields[2].clone(),
+        schema.fields[0].clone(),
+        schema.fields[1].clone(),
+    ]);
+
+    assert_eq!(
+        chunk_equal_by_name(&chunk, &schema, &reordered, &reordered_schema),
+        Ok(())
+    );
+    // by position, the columns differ
+    assert_eq!(
+        chunk_equal_by_name(&chunk, &schema, &reordered, &schema),
+        Err(r#"The chunks differ: unequal columns ["a", "b", "c"]"#.to_string())
+    );
+
+    // missing column
+    let projected = chunk.project(&[0, 1]).unwrap();
+    let projected_schema = Schema::from(schema.fields[..2].to_vec());
+    assert_eq!(
+        chunk_equal_by_name(&chunk, &schema, &projected, &projected_schema),
+        Err(r#"The chunks differ: missing columns ["c"]"#.to_string())
+    );
+    assert_eq!(
+        chunk_equal_by_name(&projected, &projected_schema, &chunk, &schema),
+        Err(r#"The chunks differ: extra columns ["c"]"#.to_string())
+    );
+
+    // extra column, under a different name
+    let renamed_schema = Schema::from(vec![
+        schema.fields[0].clone(),
+        schema.fields[1].clone(),
+        Field::new("d", DataType::Boolean, false),
+    ]);
+    assert_eq!(
+        chunk_equal_by_name(&chunk, &schema, &chunk, &renamed_schema),
+        Err(r#"The chunks differ: missing columns ["c"], extra columns ["d"]"#.to_string())
+    );
+
+    // a schema that does not match its chunk
+    assert!(chunk_equal_by_name(&chunk, &projected_schema, &chunk, &schema).is_err());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_map_chunks() {
+    use arrow2::chunk::par_map_chunks;
+
+    let chunks = (0..100)
+        .map(|i| Chunk::new(vec![Int32Array::from_slice([i, i + 1]).boxed()]))
+        .collect::<Vec<_>>();
+
+    let result = par_map_chunks(&chunks, |chunk| {
+        let array = chunk.arrays()[0]
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        let doubled = array.values().iter().map(|x| x * 2).collect::<Vec<_>>();
+        Ok(Chunk::new(vec![Int32Array::from_vec(doubled).boxed()]))
+    })
+    .unwrap();
+
+    assert_eq!(result.len(), 100);
+    result.iter().enumerate().for_each(|(i, chunk)| {
+        let i = i as i32;
+        assert_eq!(
+            chunk.arrays()[0].as_ref(),
+            &Int32Array::from_slice([2 * i, 2 * i + 2]) as &dyn Array
+        );
+    });
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_map_chunks_error() {
+    use arrow2::chunk::par_map_chunks;
+
+    let chunks = (0..10)
+        .map(|i| Chunk::new(vec![Int32Array::from_slice([i]).boxed()]))
+        .collect::<Vec<_>>();
+
+    let result = par_map_chunks::<_, Box<dyn Array>, _>(&chunks, |chunk| {
+        let array = chunk.arrays()[0]
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        match array.value(0) {
+            i if i >= 3 => Err(Error::InvalidArgumentError(format!("{i}"))),
+            _ => Ok(chunk.clone()),
+        }
+    });
+
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        Error::InvalidArgumentError("3".to_string()).to_string()
+    );
+}
@@ -0,0 +1,52 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::approx_count_distinct;
+
+fn assert_within(estimate: u64, expected: u64, relative_error: f64) {
+    let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+    assert!(
+        error <= relative_error,
+        "estimate {estimate} is not within {relative_error} of {expected}"
+    );
+}
+
+#[test]
+fn primitive() {
+    // 100_000 distinct values, each repeated twice, and nulls
+    let array = Int64Array::from_iter((0..200_000i64).map(|x| (x % 7 != 0).then_some(x / 2)));
+    let expected = (0..200_000i64)
+        .filter(|x| x % 7 != 0)
+        .map(|x| x / 2)
+        .collect::<std::collections::HashSet<_>>()
+        .len() as u64;
+
+    // the typical error is 1.6% at precision 12
+    assert_within(approx_count_distinct(&array, 12).unwrap(), expected, 0.05);
+    assert_within(approx_count_distinct(&array, 16).unwrap(), expected, 0.02);
+}
+
+#[test]
+fn utf8() {
+    let array =
+        Utf8Array::<i32>::from_iter_values((0..30_000).map(|x| format!("value-{}", x % 10_000)));
+
+    assert_within(approx_count_distinct(&array, 14).unwrap(), 10_000, 0.03);
+}
+
+#[test]
+fn small() {
+    let array = Utf8Array::<i64>::from([Some("a"), None, Some("b"), Some("a")]);
+    assert_eq!(approx_count_distinct(&array, 10).unwrap(), 2);
+
+    let array = Int32Array::new_empty(arrow2::datatypes::DataType::Int32);
+    assert_eq!(approx_count_distinct(&array, 10).unwrap(), 0);
+}
+
+#[test]
+fn errors() {
+    let array = Int32Array::from_slice([1]);
+    assert!(approx_count_distinct(&array, 3).is_err());
+    assert!(approx_count_distinct(&array, 19).is_err());
+
+    let array = Float32Array::from_slice([1.0]);
+    assert!(approx_count_distinct(&array, 12).is_err());
+}
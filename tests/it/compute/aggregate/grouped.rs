@@ -0,0 +1,104 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{collect, grouped_count, grouped_max, grouped_min, grouped_sum};
+use arrow2::datatypes::{DataType, TimeUnit};
+
+#[test]
+fn collect_interleaved() {
+    let values = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("d"), Some("e")]);
+    let group_ids = UInt32Array::from([Some(2), Some(0), Some(2), None, Some(0)]);
+
+    let result = collect(&values, &group_ids, 4, false).unwrap();
+
+    assert_eq!(result.len(), 4);
+    assert_eq!(
+        result.data_type(),
+        &ListArray::<i32>::default_datatype(DataType::Utf8)
+    );
+    let expected = [
+        Utf8Array::<i32>::from_slice(["b", "e"]),
+        Utf8Array::<i32>::new_empty(DataType::Utf8),
+        Utf8Array::<i32>::from([Some("a"), None]),
+        Utf8Array::<i32>::new_empty(DataType::Utf8),
+    ];
+    for (i, expected) in expected.iter().enumerate() {
+        assert_eq!(result.value(i).as_ref(), expected as &dyn Array);
+    }
+
+    let result = collect(&values, &group_ids, 4, true).unwrap();
+    assert_eq!(
+        result.value(2).as_ref(),
+        &Utf8Array::<i32>::from_slice(["a"]) as &dyn Array
+    );
+}
+
+#[test]
+fn collect_errors() {
+    let values = Int32Array::from_slice([1, 2]);
+
+    assert!(collect(&values, &UInt32Array::from_slice([0]), 1, false).is_err());
+    assert!(collect(&values, &UInt32Array::from_slice([0, 1]), 1, false).is_err());
+}
+
+#[test]
+fn grouped_three_groups() {
+    let values = Int32Array::from([Some(1), Some(5), None, Some(-2), Some(3), None]);
+    let group_ids = UInt32Array::from([Some(0), Some(1), Some(2), Some(0), Some(1), None]);
+
+    assert_eq!(
+        grouped_sum(&values, &group_ids, 3).unwrap(),
+        Int32Array::from([Some(-1), Some(8), None])
+    );
+    assert_eq!(
+        grouped_min(&values, &group_ids, 3).unwrap(),
+        Int32Array::from([Some(-2), Some(3), None])
+    );
+    assert_eq!(
+        grouped_max(&values, &group_ids, 3).unwrap(),
+        Int32Array::from([Some(1), Some(5), None])
+    );
+    assert_eq!(
+        grouped_count(&values, &group_ids, 3).unwrap(),
+        UInt64Array::from_slice([2, 2, 0])
+    );
+}
+
+#[test]
+fn grouped_sum_keeps_data_type() {
+    let values = Int64Array::from_slice([1, 2]).to(DataType::Duration(TimeUnit::Second));
+    let result = grouped_sum(&values, &UInt32Array::from_slice([0, 0]), 1).unwrap();
+    assert_eq!(result.data_type(), &DataType::Duration(TimeUnit::Second));
+    assert_eq!(result.value(0), 3);
+}
+
+#[test]
+fn grouped_errors() {
+    let values = Int32Array::from_slice([1, 2]);
+
+    assert!(grouped_sum(&values, &UInt32Array::from_slice([0, 3]), 3).is_err());
+    assert!(grouped_min(&values, &UInt32Array::from_slice([0]), 3).is_err());
+    assert!(grouped_max(&values, &UInt32Array::from_slice([0, 3]), 3).is_err());
+    assert!(grouped_count(&values, &UInt32Array::from_slice([0]), 3).is_err());
+}
+
+#[test]
+fn grouped_min_max_nans() {
+    // NaNs are the greatest values regardless of the order of the rows
+    let nan = f64::NAN;
+    let values = Float64Array::from_slice([nan, 1.0, 1.0, nan, nan, nan, -0.0, 0.0]);
+    let group_ids = UInt32Array::from_slice([0, 0, 1, 1, 2, 2, 3, 3]);
+    let bits = |array: Float64Array| {
+        array
+            .values_iter()
+            .map(|x| if x.is_nan() { nan } else { *x }.to_bits())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        bits(grouped_min(&values, &group_ids, 4).unwrap()),
+        [1.0, 1.0, nan, -0.0].map(f64::to_bits)
+    );
+    assert_eq!(
+        bits(grouped_max(&values, &group_ids, 4).unwrap()),
+        [nan, nan, nan, 0.0].map(f64::to_bits)
+    );
+}
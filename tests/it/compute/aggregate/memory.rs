@@ -30,3 +30,43 @@ fn fixed_size_list() {
     let a = FixedSizeListArray::new(data_type, values, None);
     assert_eq!(6 * std::mem::size_of::<f32>(), estimated_bytes_size(&a));
 }
+
+#[test]
+fn primitive_sliced() {
+    let a = Int32Array::from([Some(1), None, Some(3), Some(4), Some(5)]);
+    assert_eq!(5 * std::mem::size_of::<i32>() + 1, estimated_bytes_size(&a));
+
+    let a = a.sliced(1, 2);
+    assert_eq!(2 * std::mem::size_of::<i32>() + 1, estimated_bytes_size(&a));
+}
+
+#[test]
+fn utf8_sliced() {
+    let a = Utf8Array::<i32>::from_slice(["a", "bb", "ccc"]).sliced(1, 2);
+    assert_eq!(5 + 3 * std::mem::size_of::<i32>(), estimated_bytes_size(&a));
+}
+
+#[test]
+fn list() {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2)]),
+        Some(vec![Some(3)]),
+        Some(vec![Some(4), Some(5), Some(6)]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let a: ListArray<i32> = array.into();
+
+    // 6 values + 4 offsets
+    assert_eq!(
+        6 * std::mem::size_of::<i32>() + 4 * std::mem::size_of::<i32>(),
+        estimated_bytes_size(&a)
+    );
+
+    // only the 4 values and 3 offsets referenced by the slice are counted
+    let a = a.sliced(1, 2);
+    assert_eq!(
+        4 * std::mem::size_of::<i32>() + 3 * std::mem::size_of::<i32>(),
+        estimated_bytes_size(&a)
+    );
+}
@@ -1,6 +1,6 @@
 use arrow2::compute::aggregate::{
-    max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean, min_primitive,
-    min_string,
+    arg_max, arg_min, max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean,
+    min_primitive, min_string,
 };
 use arrow2::{array::*, datatypes::DataType};
 
@@ -237,3 +237,23 @@ fn test_max_not_lexi() {
     let out = max_primitive(&arr).unwrap();
     assert_eq!(out, maximum);
 }
+
+#[test]
+fn arg_min_max() {
+    let a = Int32Array::from([Some(5), None, Some(1), Some(9), Some(1), Some(9)]);
+    assert_eq!(arg_min(&a), Some(2));
+    assert_eq!(arg_max(&a), Some(3));
+
+    let a = Int32Array::from([None, Some(3), None]);
+    assert_eq!(arg_min(&a), Some(1));
+    assert_eq!(arg_max(&a), Some(1));
+
+    let a = Int32Array::from([None, None]);
+    assert_eq!(arg_min(&a), None);
+    assert_eq!(arg_max(&a), None);
+    assert_eq!(arg_min(&Int32Array::from_slice([])), None);
+
+    let a = Float64Array::from_slice([f64::NAN, 2.0, -1.0, f64::NAN]);
+    assert_eq!(arg_min(&a), Some(2));
+    assert_eq!(arg_max(&a), Some(1));
+}
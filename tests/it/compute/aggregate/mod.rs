@@ -1,3 +1,5 @@
+mod approx_distinct;
+mod grouped;
 mod memory;
 mod min_max;
 mod sum;
@@ -52,3 +52,35 @@ fn test_primitive_array_sum_large_64() {
         sum_primitive(&c)
     );
 }
+
+#[test]
+fn float_sum_is_independent_of_alignment() {
+    // values whose sum depends on the order of the additions
+    let values = (0..100)
+        .map(|i| {
+            if i % 3 == 0 {
+                1e16
+            } else {
+                1.0 + i as f64 / 7.0
+            }
+        })
+        .collect::<Vec<f64>>();
+    let array = Float64Array::from_slice(&values);
+
+    // f64 is summed in 8 lanes, then the lanes, then the remainder
+    let expected = |values: &[f64]| {
+        let mut chunks = values.chunks_exact(8);
+        let mut lanes = [0.0; 8];
+        chunks.by_ref().for_each(|chunk| {
+            lanes.iter_mut().zip(chunk).for_each(|(lane, v)| *lane += v);
+        });
+        let reduced = lanes.iter().fold(0.0, |acc, lane| acc + lane);
+        reduced + chunks.remainder().iter().sum::<f64>()
+    };
+
+    for offset in 0..8 {
+        let sliced = array.clone().sliced(offset, values.len() - offset);
+        let result = sum_primitive(&sliced).unwrap();
+        assert_eq!(result.to_bits(), expected(&values[offset..]).to_bits());
+    }
+}
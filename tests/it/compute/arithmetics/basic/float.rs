@@ -0,0 +1,50 @@
+use arrow2::array::*;
+use arrow2::compute::arithmetics::basic::*;
+
+#[test]
+fn test_sqrt_domain() {
+    let a = Float64Array::from(&[Some(9.0), None, Some(-4.0), Some(-0.0)]);
+
+    let result = sqrt(&a);
+    assert_eq!(result.value(0), 3.0);
+    assert!(result.is_null(1));
+    assert!(result.value(2).is_nan());
+    assert_eq!(result.value(3), 0.0);
+
+    let result = checked_sqrt(&a);
+    let expected = Float64Array::from(&[Some(3.0), None, None, Some(0.0)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_log_domain() {
+    let a = Float32Array::from(&[Some(1.0), None, Some(0.0), Some(-1.0), Some(1000.0)]);
+
+    let result = ln(&a);
+    assert_eq!(result.value(0), 0.0);
+    assert!(result.is_null(1));
+    assert_eq!(result.value(2), f32::NEG_INFINITY);
+    assert!(result.value(3).is_nan());
+
+    let result = checked_ln(&a);
+    assert_eq!(
+        result.validity().unwrap().iter().collect::<Vec<_>>(),
+        vec![true, false, false, false, true]
+    );
+
+    let result = checked_log10(&a);
+    let expected = Float32Array::from(&[Some(0.0), None, None, None, Some(3.0)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_exp() {
+    let a = Float64Array::from(&[Some(0.0), None, Some(1.0)]);
+    let result = exp(&a);
+    let expected = Float64Array::from(&[Some(1.0), None, Some(std::f64::consts::E)]);
+    assert_eq!(result, expected);
+
+    let result = ln(&result);
+    let expected = Float64Array::from(&[Some(0.0), None, Some(1.0)]);
+    assert_eq!(result, expected);
+}
@@ -1,6 +1,8 @@
 mod add;
 mod div;
+mod float;
 mod mul;
 mod pow;
 mod rem;
+mod sign;
 mod sub;
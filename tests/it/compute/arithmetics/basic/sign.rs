@@ -0,0 +1,62 @@
+use arrow2::array::*;
+use arrow2::compute::arithmetics::basic::*;
+
+#[test]
+fn test_abs() {
+    let a = Int32Array::from(&[Some(-1), None, Some(0), Some(i32::MAX), Some(i32::MIN + 1)]);
+    let result = abs(&a);
+    let expected = Int32Array::from(&[Some(1), None, Some(0), Some(i32::MAX), Some(i32::MAX)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_abs_overflow() {
+    let a = Int32Array::from(&[Some(-1), None, Some(i32::MIN)]);
+
+    let result = checked_abs(&a);
+    let expected = Int32Array::from(&[Some(1), None, None]);
+    assert_eq!(result, expected);
+
+    let result = wrapping_abs(&a);
+    let expected = Int32Array::from(&[Some(1), None, Some(i32::MIN)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_abs_float() {
+    let a = Float64Array::from(&[Some(-1.5), None, Some(-0.0), Some(f64::NEG_INFINITY)]);
+    let result = abs(&a);
+    let expected = Float64Array::from(&[Some(1.5), None, Some(0.0), Some(f64::INFINITY)]);
+    assert_eq!(result, expected);
+    assert!(result.value(2).is_sign_positive());
+
+    assert!(abs(&Float32Array::from_slice([f32::NAN])).value(0).is_nan());
+}
+
+#[test]
+fn test_negate() {
+    let a = Int8Array::from(&[Some(-1), None, Some(i8::MIN)]);
+    let result = checked_negate(&a);
+    let expected = Int8Array::from(&[Some(1), None, None]);
+    assert_eq!(result, expected);
+
+    let result = negate(&Float32Array::from_slice([0.0, -2.0]));
+    assert!(result.value(0).is_sign_negative());
+    assert_eq!(result.value(1), 2.0);
+}
+
+#[test]
+fn test_signum() {
+    let a = Int64Array::from(&[Some(-5), None, Some(0), Some(i64::MAX), Some(i64::MIN)]);
+    let result = signum(&a);
+    let expected = Int64Array::from(&[Some(-1), None, Some(0), Some(1), Some(-1)]);
+    assert_eq!(result, expected);
+
+    let a = Float64Array::from(&[Some(-2.5), None, Some(0.0), Some(-0.0), Some(3.0)]);
+    let result = signum(&a);
+    let expected = Float64Array::from(&[Some(-1.0), None, Some(1.0), Some(-1.0), Some(1.0)]);
+    assert_eq!(result, expected);
+    assert!(signum(&Float64Array::from_slice([f64::NAN]))
+        .value(0)
+        .is_nan());
+}
@@ -4,6 +4,7 @@ use arrow2::array::*;
 use arrow2::compute::arithmetics::decimal::{adaptive_add, add, checked_add, saturating_add};
 use arrow2::compute::arithmetics::{ArrayAdd, ArrayCheckedAdd, ArraySaturatingAdd};
 use arrow2::datatypes::DataType;
+use arrow2::error::Error;
 
 #[test]
 fn test_add_normal() {
@@ -179,3 +180,37 @@ fn test_add_adaptive() {
     assert_eq!(result, expected);
     assert_eq!(result.data_type(), &DataType::Decimal(7, 4));
 }
+
+#[test]
+fn test_add_adaptive_different_scales() {
+    // 12345678.90   -> 10, 2
+    //   123456.7891 -> 10, 4
+    // ---------------------
+    // 12469135.6891 -> 12, 4
+    let a = PrimitiveArray::from([Some(12345678_90i128), None, Some(-1_00)])
+        .to(DataType::Decimal(10, 2));
+    let b = PrimitiveArray::from([Some(123456_7891i128), Some(1_0000), Some(1)])
+        .to(DataType::Decimal(10, 4));
+    let result = adaptive_add(&a, &b).unwrap();
+
+    let expected = PrimitiveArray::from([Some(12469135_6891i128), None, Some(-9999)])
+        .to(DataType::Decimal(12, 4));
+
+    assert_eq!(result, expected);
+    assert_eq!(result.data_type(), &DataType::Decimal(12, 4));
+}
+
+#[test]
+fn test_add_adaptive_overflow() {
+    let a = PrimitiveArray::from([Some(i128::MAX / 10)]).to(DataType::Decimal(38, 2));
+    let b = PrimitiveArray::from([Some(1i128)]).to(DataType::Decimal(38, 4));
+    assert!(matches!(adaptive_add(&a, &b), Err(Error::Overflow)));
+
+    // overflows in null slots are ignored
+    let a = PrimitiveArray::from([None, Some(1i128)]).to(DataType::Decimal(38, 2));
+    let a = a.with_values(vec![i128::MAX / 10, 1].into());
+    let b = PrimitiveArray::from([Some(1i128), Some(1)]).to(DataType::Decimal(38, 4));
+    let result = adaptive_add(&a, &b).unwrap();
+    assert_eq!(result.validity(), a.validity());
+    assert_eq!(result.value(1), 101);
+}
@@ -4,6 +4,7 @@ use arrow2::array::*;
 use arrow2::compute::arithmetics::decimal::{adaptive_mul, checked_mul, mul, saturating_mul};
 use arrow2::compute::arithmetics::{ArrayCheckedMul, ArrayMul, ArraySaturatingMul};
 use arrow2::datatypes::DataType;
+use arrow2::error::Error;
 
 #[test]
 fn test_multiply_normal() {
@@ -230,3 +231,10 @@ fn test_multiply_adaptive() {
     assert_eq!(result, expected);
     assert_eq!(result.data_type(), &DataType::Decimal(12, 3));
 }
+
+#[test]
+fn test_multiply_adaptive_overflow() {
+    let a = PrimitiveArray::from([Some(i128::MAX / 100)]).to(DataType::Decimal(38, 2));
+    let b = PrimitiveArray::from([Some(1_000i128)]).to(DataType::Decimal(10, 2));
+    assert!(matches!(adaptive_mul(&a, &b), Err(Error::Overflow)));
+}
@@ -0,0 +1,75 @@
+use arrow2::array::{Int32Array, PrimitiveArray};
+use arrow2::compute::arity::{binary, try_binary, try_unary, unary, unary_null_on_error};
+use arrow2::datatypes::DataType;
+use arrow2::error::Error;
+
+#[test]
+fn test_unary() {
+    let a = Int32Array::from([Some(5), Some(6), None, Some(10)]);
+
+    let result = unary(&a, |x| x as i64 * 2, DataType::Int64);
+
+    assert_eq!(
+        result,
+        PrimitiveArray::<i64>::from([Some(10), Some(12), None, Some(20)])
+    )
+}
+
+#[test]
+fn test_unary_null_on_error() {
+    let op = |x: i32| u8::try_from(x).map_err(|_| Error::Overflow);
+
+    let a = Int32Array::from([Some(5), Some(-1), None, Some(256)]);
+    let result = unary_null_on_error(&a, op, DataType::UInt8);
+    assert_eq!(
+        result,
+        PrimitiveArray::<u8>::from([Some(5), None, None, None])
+    );
+}
+
+#[test]
+fn test_try_unary() {
+    let op = |x: i32| u8::try_from(x).map_err(|_| Error::Overflow);
+
+    // the error on the null slot is ignored
+    let a = Int32Array::from([Some(5), Some(6), None]).with_values(vec![5, 6, -1].into());
+    let result = try_unary(&a, op, DataType::UInt8).unwrap();
+    assert_eq!(result, PrimitiveArray::<u8>::from([Some(5), Some(6), None]));
+
+    let a = Int32Array::from([Some(5), Some(-1), None]);
+    assert!(matches!(
+        try_unary(&a, op, DataType::UInt8),
+        Err(Error::Overflow)
+    ));
+}
+
+#[test]
+fn test_binary() {
+    let a = Int32Array::from([Some(5), Some(6), None, Some(10)]);
+    let b = PrimitiveArray::<f32>::from([Some(0.5), None, Some(1.0), Some(2.0)]);
+
+    let result = binary(&a, &b, DataType::Float64, |a, b| a as f64 * b as f64);
+
+    assert_eq!(
+        result,
+        PrimitiveArray::<f64>::from([Some(2.5), None, None, Some(20.0)])
+    )
+}
+
+#[test]
+#[should_panic]
+fn test_binary_different_lengths() {
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int32Array::from_slice([1]);
+    binary(&a, &b, DataType::Int32, |a, b| a + b);
+}
+
+#[test]
+fn test_try_binary_different_lengths() {
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int32Array::from_slice([1]);
+    assert!(matches!(
+        try_binary(&a, &b, DataType::Int32, |a, b| Ok(a + b)),
+        Err(Error::InvalidArgumentError(_))
+    ));
+}
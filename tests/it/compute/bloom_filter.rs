@@ -0,0 +1,96 @@
+use arrow2::array::*;
+use arrow2::compute::bloom_filter::{bloom_filter, BloomFilter};
+
+#[test]
+fn no_false_negatives_and_fp_rate() {
+    let build = Int64Array::from_iter((0..10_000i64).map(|x| (x % 10 != 0).then_some(x)));
+    let filter = bloom_filter(&build, 0.01).unwrap();
+
+    let result = filter.probe(&build).unwrap();
+    assert_eq!(result.validity(), build.validity());
+    assert!(result.iter().flatten().all(|x| x));
+
+    let probe = Int64Array::from_vec((10_000..110_000i64).collect());
+    let positives = probe.len() - filter.probe(&probe).unwrap().values().unset_bits();
+    let fp_rate = positives as f64 / probe.len() as f64;
+    assert!(fp_rate < 0.02, "false positive rate {fp_rate}");
+}
+
+#[test]
+fn utf8() {
+    let build = Utf8Array::<i32>::from_iter_values((0..1_000).map(|x| format!("key-{x}")));
+    let filter = bloom_filter(&build, 0.05).unwrap();
+
+    let probe = Utf8Array::<i32>::from_iter_values((0..2_000).map(|x| format!("key-{x}")));
+    let result = filter.probe(&probe).unwrap();
+    assert!(result.values_iter().take(1_000).all(|x| x));
+
+    let positives = result.values_iter().skip(1_000).filter(|x| *x).count();
+    assert!(positives < 100, "{positives} false positives");
+}
+
+#[test]
+fn contains() {
+    let filter = bloom_filter(&Int64Array::from_slice([1, 2, 3]), 0.01).unwrap();
+    assert!(filter.contains(&1i64));
+    assert!(filter.contains(&3i64));
+
+    let filter = bloom_filter(&Utf8Array::<i32>::from_slice(["a", "b"]), 0.01).unwrap();
+    assert!(filter.contains("a"));
+    assert!(filter.contains(b"b".as_ref()));
+
+    let mut filter = BloomFilter::try_new(2, 0.01).unwrap();
+    filter.insert("c");
+    filter.insert(&true);
+    let probe = Utf8Array::<i32>::from_slice(["c"]);
+    assert_eq!(
+        filter.probe(&probe).unwrap(),
+        BooleanArray::from_slice([true])
+    );
+    assert_eq!(
+        filter.probe(&BooleanArray::from_slice([true])).unwrap(),
+        BooleanArray::from_slice([true])
+    );
+}
+
+#[test]
+fn serialization() {
+    let build = Utf8Array::<i64>::from_slice(["a", "b", "c"]);
+    let filter = bloom_filter(&build, 0.01).unwrap();
+
+    let bytes = filter.to_bytes();
+    let deserialized = BloomFilter::try_from_bytes(&bytes).unwrap();
+    assert_eq!(deserialized, filter);
+
+    assert!(BloomFilter::try_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(BloomFilter::try_from_bytes(&bytes[..8]).is_err());
+
+    // a number of bits whose number of words overflows is rejected
+    let mut bytes = bytes;
+    bytes[4..12].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(BloomFilter::try_from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn errors() {
+    let array = Int32Array::from_slice([1]);
+    assert!(bloom_filter(&array, 0.0).is_err());
+    assert!(bloom_filter(&array, 1.0).is_err());
+    assert!(bloom_filter(&Float32Array::from_slice([1.0]), 0.1).is_err());
+}
+
+#[test]
+fn stable_serialization() {
+    // the serialized filter must not change across platforms and versions
+    let filter = bloom_filter(&Int32Array::from_slice([1]), 0.5).unwrap();
+    assert_eq!(
+        filter.to_bytes(),
+        [44, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 126, 245, 171, 87, 189, 234, 213, 175]
+    );
+
+    let filter = bloom_filter(&Utf8Array::<i32>::from_slice(["a"]), 0.5).unwrap();
+    assert_eq!(
+        filter.to_bytes(),
+        [44, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 122, 189, 94, 235, 245, 90, 175, 215]
+    );
+}
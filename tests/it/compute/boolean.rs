@@ -447,3 +447,32 @@ fn test_any_all() {
     assert!(!any(&array));
     assert!(!all(&array));
 }
+
+#[test]
+fn true_false_count() {
+    let array = BooleanArray::from_slice([true, false, true, true]);
+    assert_eq!(true_count(&array), 3);
+    assert_eq!(false_count(&array), 1);
+
+    let values = (0..200).map(|i| match i % 3 {
+        0 => None,
+        1 => Some(true),
+        _ => Some(false),
+    });
+    let array = BooleanArray::from_iter(values);
+    assert_eq!(true_count(&array), 67);
+    assert_eq!(false_count(&array), 66);
+
+    // slicing offsets are not multiple of 8 nor of 64
+    let expected =
+        |array: &BooleanArray, value: bool| array.iter().filter(|x| *x == Some(value)).count();
+    for (offset, length) in [(1, 198), (3, 70), (65, 64), (13, 0)] {
+        let sliced = array.clone().sliced(offset, length);
+        assert_eq!(true_count(&sliced), expected(&sliced, true));
+        assert_eq!(false_count(&sliced), expected(&sliced, false));
+    }
+
+    let array = BooleanArray::from([Some(true), None, Some(false)]).sliced(1, 2);
+    assert_eq!(true_count(&array), 0);
+    assert_eq!(false_count(&array), 1);
+}
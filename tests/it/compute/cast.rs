@@ -1,5 +1,8 @@
 use arrow2::array::*;
-use arrow2::compute::cast::{can_cast_types, cast, CastOptions};
+use arrow2::bitmap::Bitmap;
+use arrow2::compute::cast::{
+    binary_to_fixed_size_binary, can_cast_types, cast, CastOptions, OverflowMode,
+};
 use arrow2::datatypes::*;
 use arrow2::types::{days_ms, months_days_ns, NativeType};
 
@@ -90,6 +93,62 @@ fn f32_as_u8_overflow() {
     assert_eq!(expected, b.as_ref());
 }
 
+#[test]
+fn i32_to_i8_overflow_mode() {
+    let array = Int32Array::from([Some(300), Some(-300), Some(1), None]);
+    let options = |overflow| CastOptions {
+        overflow,
+        ..Default::default()
+    };
+
+    let b = cast(&array, &DataType::Int8, options(OverflowMode::SetNull)).unwrap();
+    let expected = Int8Array::from([None, None, Some(1), None]);
+    assert_eq!(expected, b.as_ref());
+
+    let b = cast(&array, &DataType::Int8, options(OverflowMode::Error));
+    assert!(b.is_err());
+
+    let b = cast(&array, &DataType::Int8, options(OverflowMode::Saturate)).unwrap();
+    let expected = Int8Array::from([Some(127), Some(-128), Some(1), None]);
+    assert_eq!(expected, b.as_ref());
+}
+
+#[test]
+fn f64_to_u8_saturate() {
+    let array = Float64Array::from([Some(1.1), Some(5000.0), Some(-1.0), Some(f64::NAN), None]);
+    let options = CastOptions {
+        overflow: OverflowMode::Saturate,
+        ..Default::default()
+    };
+
+    let b = cast(&array, &DataType::UInt8, options).unwrap();
+    let expected = UInt8Array::from([Some(1), Some(255), Some(0), None, None]);
+    assert_eq!(expected, b.as_ref());
+
+    let options = CastOptions {
+        overflow: OverflowMode::Error,
+        ..Default::default()
+    };
+    assert!(cast(&array, &DataType::UInt8, options).is_err());
+}
+
+#[test]
+fn i32_overflow_error_ignores_nulls() {
+    // the value behind a null slot is not considered
+    let array = Int32Array::new(
+        DataType::Int32,
+        vec![1, 300].into(),
+        Some([true, false].into()),
+    );
+    let options = CastOptions {
+        overflow: OverflowMode::Error,
+        ..Default::default()
+    };
+
+    let b = cast(&array, &DataType::Int8, options).unwrap();
+    assert_eq!(Int8Array::from([Some(1), None]), b.as_ref());
+}
+
 #[test]
 fn i32_to_u8() {
     let array = Int32Array::from_slice([-5, 6, -7, 8, 100000000]);
@@ -227,6 +286,143 @@ fn binary_to_i32_partial() {
     assert_eq!(c, &expected);
 }
 
+#[test]
+fn utf8_to_large_utf8_and_back() {
+    let array = Utf8Array::<i32>::from([Some("hello"), None, Some("world"), Some("!")]);
+    let array = array.sliced(1, 3);
+
+    let b = cast(&array, &DataType::LargeUtf8, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+    let expected = Utf8Array::<i64>::from([None, Some("world"), Some("!")]);
+    assert_eq!(c, &expected);
+
+    let b = cast(c, &DataType::Utf8, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+    let expected = Utf8Array::<i32>::from([None, Some("world"), Some("!")]);
+    assert_eq!(c, &expected);
+}
+
+#[test]
+fn binary_to_large_binary_and_back() {
+    let array = BinaryArray::<i32>::from([Some(b"hello".as_ref()), None, Some(b"\xff\x00")]);
+    let array = array.sliced(1, 2);
+
+    let b = cast(&array, &DataType::LargeBinary, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+    let expected = BinaryArray::<i64>::from([None, Some(b"\xff\x00".as_ref())]);
+    assert_eq!(c, &expected);
+
+    let b = cast(c, &DataType::Binary, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+    let expected = BinaryArray::<i32>::from([None, Some(b"\xff\x00".as_ref())]);
+    assert_eq!(c, &expected);
+}
+
+#[test]
+fn binary_to_utf8_invalid() {
+    let array = BinaryArray::<i32>::from([
+        Some(b"hello".as_ref()),
+        None,
+        Some(&[0, 159, 146, 150]),
+        Some(b"world"),
+    ]);
+
+    // invalid values error by default
+    let error = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap_err();
+    assert!(error.to_string().contains("index 2"));
+    let error = cast(&array, &DataType::LargeUtf8, CastOptions::default()).unwrap_err();
+    assert!(error.to_string().contains("index 2"));
+    let large = cast(&array, &DataType::LargeBinary, CastOptions::default()).unwrap();
+    assert!(cast(large.as_ref(), &DataType::LargeUtf8, CastOptions::default()).is_err());
+
+    let options = CastOptions {
+        null_on_invalid_utf8: true,
+        ..Default::default()
+    };
+    let b = cast(&array, &DataType::Utf8, options).unwrap();
+    let c = b.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+    let expected = Utf8Array::<i32>::from([Some("hello"), None, None, Some("world")]);
+    assert_eq!(c, &expected);
+
+    let b = cast(&array, &DataType::LargeUtf8, options).unwrap();
+    let c = b.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+    let expected = Utf8Array::<i64>::from([Some("hello"), None, None, Some("world")]);
+    assert_eq!(c, &expected);
+}
+
+#[test]
+fn binary_to_utf8_valid() {
+    let array = BinaryArray::<i64>::from([Some(b"hello".as_ref()), None, Some(b"world")]);
+    let options = CastOptions::default();
+
+    let b = cast(&array, &DataType::Utf8, options).unwrap();
+    let c = b.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+    let expected = Utf8Array::<i32>::from([Some("hello"), None, Some("world")]);
+    assert_eq!(c, &expected);
+
+    let b = cast(&array, &DataType::LargeUtf8, options).unwrap();
+    let c = b.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+    // the buffers are shared
+    assert_eq!(c.values().as_ptr(), array.values().as_ptr());
+}
+
+#[test]
+fn utf8_to_binary() {
+    let array = Utf8Array::<i32>::from([Some("hello"), None, Some("world")]);
+
+    let b = cast(&array, &DataType::Binary, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+    assert_eq!(c.values().as_ptr(), array.values().as_ptr());
+    let expected = BinaryArray::<i32>::from([Some(b"hello".as_ref()), None, Some(b"world")]);
+    assert_eq!(c, &expected);
+
+    let b = cast(&array, &DataType::LargeBinary, CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+    let expected = BinaryArray::<i64>::from([Some(b"hello".as_ref()), None, Some(b"world")]);
+    assert_eq!(c, &expected);
+}
+
+#[test]
+fn fixed_size_binary_roundtrip() {
+    let uuids = [
+        Some([7u8; 16]),
+        None,
+        Some([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]),
+    ];
+    let array = FixedSizeBinaryArray::from(uuids);
+
+    let binary = cast(&array, &DataType::Binary, CastOptions::default()).unwrap();
+    let expected =
+        BinaryArray::<i32>::from_iter(uuids.iter().map(|x| x.as_ref().map(|x| x.as_ref())));
+    assert_eq!(binary.as_ref(), &expected as &dyn Array);
+
+    let data_type = DataType::FixedSizeBinary(16);
+    assert!(can_cast_types(&DataType::Binary, &data_type));
+    let result = cast(binary.as_ref(), &data_type, CastOptions::default()).unwrap();
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+
+    // items of the wrong length are cast to null
+    let binary = BinaryArray::<i64>::from([Some(b"ab".as_ref()), Some(b"abc"), None]);
+    let result = cast(
+        &binary,
+        &DataType::FixedSizeBinary(2),
+        CastOptions::default(),
+    )
+    .unwrap();
+    let expected = FixedSizeBinaryArray::from([Some(*b"ab"), None, None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn binary_to_fixed_size_binary_zero_copy() {
+    let binary = BinaryArray::<i32>::from_slice([b"ab", b"cd", b"ef"]).sliced(1, 2);
+    let result = binary_to_fixed_size_binary(&binary, 2).unwrap();
+    assert_eq!(result, FixedSizeBinaryArray::from_slice([*b"cd", *b"ef"]));
+    assert_eq!(result.values().as_ptr(), unsafe {
+        binary.values().as_ptr().add(2)
+    });
+}
+
 #[test]
 fn fixed_size_binary_to_binary() {
     let slice = [[0, 1], [2, 3]];
@@ -272,17 +468,44 @@ fn utf8_to_i32() {
 
 #[test]
 fn int32_to_decimal() {
-    // 10 and -10 can be represented with precision 1 and scale 0
+    // 10 and -10 can't be represented with precision 1 and scale 0
     let array = Int32Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None]);
 
     let b = cast(&array, &DataType::Decimal(1, 0), CastOptions::default()).unwrap();
     let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
 
-    let expected = Int128Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None])
-        .to(DataType::Decimal(1, 0));
+    let expected =
+        Int128Array::from(&[Some(2), None, Some(-2), None, None]).to(DataType::Decimal(1, 0));
     assert_eq!(c, &expected)
 }
 
+#[test]
+fn int64_to_decimal_roundtrip() {
+    let array = Int64Array::from(&[Some(1), Some(-123_456), None, Some(i64::MAX)]);
+
+    let b = cast(&array, &DataType::Decimal(20, 3), CastOptions::default()).unwrap();
+    let expected = Int128Array::from(&[Some(1_000), Some(-123_456_000), None, None])
+        .to(DataType::Decimal(20, 3));
+    assert_eq!(b.as_ref(), &expected as &dyn Array);
+
+    let c = cast(b.as_ref(), &DataType::Int64, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[Some(1), Some(-123_456), None, None]);
+    assert_eq!(c.as_ref(), &expected as &dyn Array);
+
+    let options = CastOptions {
+        overflow: OverflowMode::Error,
+        ..Default::default()
+    };
+    let result = cast(&array, &DataType::Decimal(20, 3), options);
+    assert!(matches!(result, Err(arrow2::error::Error::Overflow)));
+
+    let array = array.sliced(0, 3);
+    let b = cast(&array, &DataType::Decimal(20, 3), options).unwrap();
+    let expected =
+        Int128Array::from(&[Some(1_000), Some(-123_456_000), None]).to(DataType::Decimal(20, 3));
+    assert_eq!(b.as_ref(), &expected as &dyn Array);
+}
+
 #[test]
 fn float32_to_decimal() {
     let array = Float32Array::from(&[
@@ -313,17 +536,71 @@ fn float32_to_decimal() {
 
 #[test]
 fn int32_to_decimal_scaled() {
-    // 10 and -10 can't be represented with precision 1 and scale 1
+    // 2 and -2 can be represented with precision 2 and scale 1, 10 and -10 can't
     let array = Int32Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None]);
 
-    let b = cast(&array, &DataType::Decimal(1, 1), CastOptions::default()).unwrap();
+    let b = cast(&array, &DataType::Decimal(2, 1), CastOptions::default()).unwrap();
     let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
 
     let expected =
-        Int128Array::from(&[Some(20), None, Some(-20), None, None]).to(DataType::Decimal(1, 1));
+        Int128Array::from(&[Some(20), None, Some(-20), None, None]).to(DataType::Decimal(2, 1));
     assert_eq!(c, &expected)
 }
 
+#[test]
+fn integer_to_decimal_precision_bounds() {
+    let error = CastOptions {
+        overflow: OverflowMode::Error,
+        ..Default::default()
+    };
+    let cast_to = |values: &[i64], precision, scale| {
+        let array = Int64Array::from_slice(values);
+        let to_type = DataType::Decimal(precision, scale);
+        let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+        let errored = cast(&array, &to_type, error).is_err();
+        (result, errored)
+    };
+
+    let (result, errored) = cast_to(&[99, -99], 2, 0);
+    let expected = Int128Array::from_slice([99, -99]).to(DataType::Decimal(2, 0));
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    assert!(!errored);
+
+    for value in [100, -100, 500] {
+        let (result, errored) = cast_to(&[value], 2, 0);
+        assert_eq!(result.null_count(), 1);
+        assert!(errored);
+    }
+
+    // 1 is 1.00, which needs 3 digits
+    let (result, errored) = cast_to(&[0, 1], 2, 2);
+    let expected = Int128Array::from([Some(0), None]).to(DataType::Decimal(2, 2));
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    assert!(errored);
+
+    let (result, errored) = cast_to(&[i64::MAX, i64::MIN], 38, 18);
+    let expected = Int128Array::from_slice([
+        i64::MAX as i128 * 10_i128.pow(18),
+        i64::MIN as i128 * 10_i128.pow(18),
+    ])
+    .to(DataType::Decimal(38, 18));
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    assert!(!errored);
+
+    // 10^20 * 10^18 has 39 digits
+    let (result, errored) = cast_to(&[i64::MAX], 38, 20);
+    assert_eq!(result.null_count(), 1);
+    assert!(errored);
+}
+
+#[test]
+fn float_to_decimal_precision_bounds() {
+    let array = Float64Array::from_slice([99.0, 100.0, -100.0, 500.0]);
+    let result = cast(&array, &DataType::Decimal(2, 0), CastOptions::default()).unwrap();
+    let expected = Int128Array::from([Some(99), None, None, None]).to(DataType::Decimal(2, 0));
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
 #[test]
 fn decimal_to_decimal() {
     // increase scale and precision
@@ -433,6 +710,22 @@ fn bool_to_f64() {
     assert_eq!(c, &expected);
 }
 
+#[test]
+fn bool_to_i8_roundtrip() {
+    let array = BooleanArray::from(vec![Some(true), None, Some(false)]);
+    let b = cast(&array, &DataType::Int8, CastOptions::default()).unwrap();
+    let expected = Int8Array::from(&[Some(1), None, Some(0)]);
+    assert_eq!(b.as_ref(), &expected as &dyn Array);
+
+    let c = cast(b.as_ref(), &DataType::Boolean, CastOptions::default()).unwrap();
+    assert_eq!(c.as_ref(), &array as &dyn Array);
+
+    let array = Int8Array::from(&[Some(-3), Some(0), None, Some(7)]);
+    let c = cast(&array, &DataType::Boolean, CastOptions::default()).unwrap();
+    let expected = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+    assert_eq!(c.as_ref(), &expected as &dyn Array);
+}
+
 #[test]
 fn bool_to_utf8() {
     let array = BooleanArray::from(vec![Some(true), Some(false), None]);
@@ -633,11 +926,118 @@ fn timestamp_to_date64() {
     test_primitive_to_primitive(
         &[864000000005i64, 1545696000001],
         DataType::Timestamp(TimeUnit::Millisecond, Some(String::from("UTC"))),
-        &[864000000005i64, 1545696000001i64],
+        &[864000000000i64, 1545696000000i64],
         DataType::Date64,
     );
 }
 
+#[test]
+fn time32ms_to_time64ns_roundtrip() {
+    let array = Int32Array::from([Some(0), Some(1_500), None, Some(86_399_999)])
+        .to(DataType::Time32(TimeUnit::Millisecond));
+
+    let result = cast(
+        &array,
+        &DataType::Time64(TimeUnit::Nanosecond),
+        CastOptions::default(),
+    )
+    .unwrap();
+    let expected = Int64Array::from([Some(0), Some(1_500_000_000), None, Some(86_399_999_000_000)])
+        .to(DataType::Time64(TimeUnit::Nanosecond));
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let back = cast(
+        result.as_ref(),
+        &DataType::Time32(TimeUnit::Millisecond),
+        CastOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(back.as_ref(), &array as &dyn Array);
+}
+
+#[test]
+fn time_to_time32_overflow() {
+    let array = Int64Array::from([Some(i64::MAX), None]).to(DataType::Time64(TimeUnit::Nanosecond));
+    assert!(cast(
+        &array,
+        &DataType::Time32(TimeUnit::Millisecond),
+        CastOptions::default()
+    )
+    .is_err());
+
+    let array = Int32Array::from([Some(i32::MAX), None]).to(DataType::Time32(TimeUnit::Second));
+    assert!(cast(
+        &array,
+        &DataType::Time32(TimeUnit::Millisecond),
+        CastOptions::default()
+    )
+    .is_err());
+
+    // values under nulls are ignored
+    let array = Int32Array::new(
+        DataType::Time32(TimeUnit::Second),
+        vec![1, i32::MAX].into(),
+        Some([true, false].into()),
+    );
+    let result = cast(
+        &array,
+        &DataType::Time32(TimeUnit::Millisecond),
+        CastOptions::default(),
+    )
+    .unwrap();
+    let expected =
+        Int32Array::from([Some(1_000), None]).to(DataType::Time32(TimeUnit::Millisecond));
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn naive_timestamp_to_date_truncates() {
+    // one second before and after the epoch
+    test_primitive_to_primitive(
+        &[-1i64, 1],
+        DataType::Timestamp(TimeUnit::Second, None),
+        &[-1i32, 0],
+        DataType::Date32,
+    );
+    test_primitive_to_primitive(
+        &[-1i64, 1],
+        DataType::Timestamp(TimeUnit::Nanosecond, None),
+        &[-86_400_000i64, 0],
+        DataType::Date64,
+    );
+}
+
+#[test]
+fn timestamp_with_offset_to_date() {
+    // 2022-01-01T23:59:59+02:00, 2022-01-02T00:00:00+02:00, null
+    let array = Int64Array::from([Some(1641074399), Some(1641074400), None]).to(
+        DataType::Timestamp(TimeUnit::Second, Some("+02:00".to_string())),
+    );
+
+    let result = cast(&array, &DataType::Date32, CastOptions::default()).unwrap();
+    let expected = Int32Array::from([Some(18993), Some(18994), None]).to(DataType::Date32);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = cast(&array, &DataType::Date64, CastOptions::default()).unwrap();
+    let expected = Int64Array::from([Some(18993 * 86_400_000), Some(18994 * 86_400_000), None])
+        .to(DataType::Date64);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn timestamp_with_tz_to_date() {
+    // 2022-01-01T23:59:59.999-05:00, 2022-01-02T00:00:00-05:00
+    let array = Int64Array::from_slice([1641099599999, 1641099600000]).to(DataType::Timestamp(
+        TimeUnit::Millisecond,
+        Some("America/New_York".to_string()),
+    ));
+
+    let result = cast(&array, &DataType::Date32, CastOptions::default()).unwrap();
+    let expected = Int32Array::from_slice([18993, 18994]).to(DataType::Date32);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
 #[test]
 fn timestamp_to_i64() {
     test_primitive_to_primitive(
@@ -658,6 +1058,33 @@ fn timestamp_to_timestamp() {
     );
 }
 
+#[test]
+fn identity_does_not_copy() {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("bc")]);
+    let result = cast(&array, array.data_type(), CastOptions::default()).unwrap();
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+    assert_eq!(
+        result.as_utf8::<i32>().unwrap().values().as_ptr(),
+        array.values().as_ptr()
+    );
+}
+
+#[test]
+fn timestamp_timezone_relabel() {
+    let array = Int64Array::from([Some(1), None, Some(3)]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("+01:00".to_string()),
+    ));
+    let to_type = DataType::Timestamp(TimeUnit::Second, Some("UTC".to_string()));
+
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    assert_eq!(result.as_ref(), &array.clone().to(to_type) as &dyn Array);
+    assert_eq!(
+        result.as_primitive::<i64>().unwrap().values().as_ptr(),
+        array.values().as_ptr()
+    );
+}
+
 #[test]
 fn utf8_to_dict() {
     let array = Utf8Array::<i32>::from([Some("one"), None, Some("three"), Some("one")]);
@@ -757,6 +1184,84 @@ fn list_to_from_fixed_size_list() {
     assert_eq!(list, result.as_ref());
 }
 
+#[test]
+fn list_to_fixed_size_list_nulls_and_slices() {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2)]),
+        None,
+        Some(vec![Some(3), None]),
+    ];
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(data).unwrap();
+    let list: ListArray<i32> = list.into();
+
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+    let result = cast(&list, &data_type, CastOptions::default()).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .unwrap();
+    assert_eq!(result.len(), 3);
+    assert_eq!(result.validity(), Some(&[true, false, true].into()));
+    assert_eq!(
+        result.value(2).as_ref(),
+        &Int32Array::from([Some(3), None]) as &dyn Array
+    );
+
+    let sliced = list.sliced(2, 1);
+    let result = cast(&sliced, &data_type, CastOptions::default()).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(
+        result.value(0).as_ref(),
+        &Int32Array::from([Some(3), None]) as &dyn Array
+    );
+}
+
+#[test]
+fn list_to_fixed_size_list_null_of_other_length() {
+    // the null lists span 1 and 3 values
+    let values = Int32Array::from_slice([1, 2, 0, 3, 4, 0, 0, 0]);
+    let list = ListArray::<i32>::new(
+        ListArray::<i32>::default_datatype(DataType::Int32),
+        vec![0, 2, 3, 5, 8].try_into().unwrap(),
+        values.boxed(),
+        Some([true, false, true, false].into()),
+    );
+
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+    let result = cast(&list, &data_type, CastOptions::default()).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .unwrap();
+    assert_eq!(result.len(), 4);
+    assert_eq!(result.validity(), Some(&[true, false, true, false].into()));
+    assert_eq!(
+        result.values().as_ref(),
+        &Int32Array::from([Some(1), Some(2), None, None, Some(3), Some(4), None, None])
+            as &dyn Array
+    );
+}
+
+#[test]
+fn list_to_fixed_size_list_ragged() {
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+    for data in [
+        vec![Some(vec![Some(1i32)]), Some(vec![Some(2), Some(3)])],
+        vec![Some(vec![Some(1i32), Some(2)]), Some(vec![Some(3)])],
+        vec![Some(vec![Some(1i32), Some(2)]), Some(vec![])],
+    ] {
+        let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+        list.try_extend(data).unwrap();
+        let list: ListArray<i32> = list.into();
+        assert!(cast(&list, &data_type, CastOptions::default()).is_err());
+    }
+}
+
 #[test]
 fn timestamp_with_tz_to_utf8() {
     let tz = "-02:00".to_string();
@@ -842,6 +1347,31 @@ fn null_array_from_and_to_others() {
     typed_test!(Float16Array, Float16);
     typed_test!(Float32Array, Float32);
     typed_test!(Float64Array, Float64);
+
+    typed_test!(BooleanArray, Boolean);
+}
+
+#[test]
+fn null_array_to_typed() {
+    let array = NullArray::new(DataType::Null, 3);
+    let list_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+
+    for to_type in [DataType::Int32, DataType::Utf8, list_type] {
+        let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+        assert_eq!(result.data_type(), &to_type);
+        assert_eq!(result.len(), 3);
+        assert_eq!(result.validity(), Some(&Bitmap::new_zeroed(3)));
+    }
+
+    let result = cast(&array, &DataType::Int32, CastOptions::default()).unwrap();
+    let result = result.as_any().downcast_ref::<Int32Array>().unwrap();
+    assert_eq!(result.values().as_slice(), &[0, 0, 0]);
+
+    let list_type = DataType::List(Box::new(Field::new("item", DataType::Utf8, true)));
+    let result = cast(&array, &list_type, CastOptions::default()).unwrap();
+    let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(result.offsets().as_slice(), &[0, 0, 0, 0]);
+    assert!(result.values().is_empty());
 }
 
 #[test]
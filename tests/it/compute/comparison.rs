@@ -414,3 +414,101 @@ fn primitive_and_validity() {
     let expected = BooleanArray::from_slice([false, true]);
     assert_eq!(primitive::eq_and_validity(&a1, &a2), expected);
 }
+
+#[test]
+fn dictionary_eq_shared_values() {
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed();
+    let lhs = DictionaryArray::try_from_keys(
+        PrimitiveArray::from([Some(0i32), Some(1), None, Some(2)]),
+        values.clone(),
+    )
+    .unwrap();
+    let rhs = DictionaryArray::try_from_keys(
+        PrimitiveArray::from([Some(0i32), Some(2), Some(1), Some(2)]),
+        values,
+    )
+    .unwrap();
+
+    let result = comparison::dictionary::eq(&lhs, &rhs).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(false), None, Some(true)])
+    );
+}
+
+#[test]
+fn dictionary_eq_duplicated_values() {
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "a"]).boxed();
+    let lhs =
+        DictionaryArray::try_from_keys(PrimitiveArray::from_slice([0i32, 0, 1, 2]), values.clone())
+            .unwrap();
+    let rhs = DictionaryArray::try_from_keys(PrimitiveArray::from_slice([2i32, 0, 2, 0]), values)
+        .unwrap();
+
+    // different keys pointing to equal values are equal
+    let result = comparison::dictionary::eq(&lhs, &rhs).unwrap();
+    assert_eq!(result, BooleanArray::from_slice([true, true, false, true]));
+}
+
+#[test]
+fn dictionary_eq_different_values() {
+    let lhs = DictionaryArray::try_from_keys(
+        PrimitiveArray::from([Some(0i32), Some(1), None, Some(2), Some(1)]),
+        Utf8Array::<i32>::from([Some("a"), Some("b"), None]).boxed(),
+    )
+    .unwrap();
+    let rhs = DictionaryArray::try_from_keys(
+        PrimitiveArray::from([Some(1i32), Some(1), Some(0), Some(0), Some(0)]),
+        Utf8Array::<i32>::from_slice(["b", "a"]).boxed(),
+    )
+    .unwrap();
+
+    // a null value is a null slot, like a null key
+    let result = comparison::dictionary::eq(&lhs, &rhs).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(false), None, None, Some(true)])
+    );
+
+    assert!(comparison::dictionary::eq(&lhs, &rhs.sliced(0, 4)).is_err());
+
+    let rhs = DictionaryArray::try_from_keys(
+        PrimitiveArray::from_slice([0i32; 5]),
+        Int32Array::from_slice([1]).boxed(),
+    )
+    .unwrap();
+    assert!(comparison::dictionary::eq(&lhs, &rhs).is_err());
+}
+
+#[cfg(feature = "compute_cast")]
+#[test]
+fn compare_coerce_int32_gt_int64() {
+    let lhs = Int32Array::from([Some(1), Some(i32::MAX), None, Some(-1)]);
+    let rhs = Int64Array::from([Some(0), Some(i64::MAX), Some(1), None]);
+
+    let result = comparison::compare_coerce(&lhs, &rhs, comparison::gt).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(false), None, None])
+    );
+    // the order of the arguments is preserved
+    let result = comparison::compare_coerce(&rhs, &lhs, comparison::gt).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), Some(true), None, None])
+    );
+}
+
+#[cfg(feature = "compute_cast")]
+#[test]
+fn compare_coerce_errors() {
+    let lhs = UInt64Array::from_slice([1]);
+    let rhs = Int64Array::from_slice([1]);
+    assert!(comparison::compare_coerce(&lhs, &rhs, comparison::eq).is_err());
+
+    let rhs = Float64Array::from_slice([1.0]);
+    assert!(comparison::compare_coerce(&lhs, &rhs, comparison::eq).is_err());
+
+    let rhs = UInt64Array::from_slice([1, 2]);
+    assert!(comparison::compare_coerce(&lhs, &rhs, comparison::eq).is_err());
+}
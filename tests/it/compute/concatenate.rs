@@ -1,6 +1,9 @@
 use arrow2::array::*;
 use arrow2::compute::concatenate::concatenate;
-use arrow2::error::Result;
+use arrow2::datatypes::{DataType, IntegerType};
+use arrow2::error::{Error, Result};
+
+use super::{list_array, struct_array};
 
 #[test]
 fn empty_vec() {
@@ -115,3 +118,179 @@ fn boolean_primitive_arrays() -> Result<()> {
 
     Ok(())
 }
+
+fn ordered_dictionary(keys: &[i32], values: &[&str]) -> DictionaryArray<i32> {
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), true);
+    DictionaryArray::try_new(
+        data_type,
+        Int32Array::from_slice(keys),
+        Utf8Array::<i32>::from_slice(values).boxed(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn ordered_dictionaries_sorted_union() -> Result<()> {
+    let lhs = ordered_dictionary(&[0, 1, 0], &["a", "b"]);
+    let rhs = ordered_dictionary(&[1, 0], &["c", "d"]);
+
+    let result = concatenate(&[&lhs, &rhs])?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+
+    assert!(result.is_ordered());
+    assert_eq!(result.keys(), &Int32Array::from_slice([0, 1, 0, 3, 2]));
+    Ok(())
+}
+
+#[test]
+fn ordered_dictionaries_unsorted_union() -> Result<()> {
+    let lhs = ordered_dictionary(&[0, 1], &["c", "d"]);
+    let rhs = ordered_dictionary(&[1, 0], &["a", "b"]);
+
+    let result = concatenate(&[&lhs, &rhs])?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+
+    assert!(!result.is_ordered());
+    assert_eq!(
+        result.data_type(),
+        &DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false)
+    );
+    Ok(())
+}
+
+#[test]
+fn struct_arrays() -> Result<()> {
+    let lhs = struct_array(
+        Int32Array::from([Some(1), None]),
+        Utf8Array::from([Some("a"), Some("b")]),
+        Some([true, false].into()),
+    );
+    let rhs = struct_array(
+        Int32Array::from([Some(3), Some(4), None]),
+        Utf8Array::from([None, Some("d"), Some("e")]),
+        None,
+    );
+
+    let result = concatenate(&[&lhs, &rhs.clone().sliced(1, 2), &rhs])?;
+
+    let expected = struct_array(
+        Int32Array::from([Some(1), None, Some(4), None, Some(3), Some(4), None]),
+        Utf8Array::from([
+            Some("a"),
+            Some("b"),
+            Some("d"),
+            Some("e"),
+            None,
+            Some("d"),
+            Some("e"),
+        ]),
+        Some([true, false, true, true, true, true, true].into()),
+    );
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn list_arrays() -> Result<()> {
+    let list = list_array::<i32>;
+
+    let lhs = list(&[Some(vec![Some(1), None]), None, Some(vec![])]);
+    let rhs = list(&[Some(vec![Some(3)]), Some(vec![Some(4), Some(5)]), None]);
+
+    let result = concatenate(&[&lhs, &rhs.clone().sliced(1, 2), &rhs])?;
+
+    let expected = list(&[
+        Some(vec![Some(1), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(4), Some(5)]),
+        None,
+        Some(vec![Some(3)]),
+        Some(vec![Some(4), Some(5)]),
+        None,
+    ]);
+    assert_eq!(expected, result.as_ref());
+    let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(result.offsets().as_slice(), &[0, 2, 2, 2, 4, 4, 5, 7, 7]);
+    Ok(())
+}
+
+#[test]
+fn null_arrays() -> Result<()> {
+    let lhs = NullArray::new(DataType::Null, 3);
+    let rhs = NullArray::new(DataType::Null, 4).sliced(1, 2);
+
+    let result = concatenate(&[&lhs, &rhs])?;
+
+    assert_eq!(
+        result.as_ref(),
+        &NullArray::new(DataType::Null, 5) as &dyn Array
+    );
+    assert_eq!(result.null_count(), 5);
+    Ok(())
+}
+
+#[test]
+fn binary_offsets_overflow() {
+    // 2 x 1 GiB > i32::MAX bytes; the values are zeroed and never touched
+    let length = 1usize << 30;
+    let array = BinaryArray::<i32>::new(
+        DataType::Binary,
+        vec![0, length as i32].try_into().unwrap(),
+        vec![0u8; length].into(),
+        None,
+    );
+
+    assert!(matches!(
+        concatenate(&[&array, &array]),
+        Err(Error::Overflow)
+    ));
+    assert!(concatenate(&[&array, &array.clone().sliced(0, 0)]).is_ok());
+}
+
+#[test]
+fn utf8_offsets_overflow() {
+    let length = 1usize << 30;
+    // safety: zeroed bytes are valid utf8
+    let array = unsafe {
+        Utf8Array::<i32>::new_unchecked(
+            DataType::Utf8,
+            vec![0, length as i32].try_into().unwrap(),
+            vec![0u8; length].into(),
+            None,
+        )
+    };
+
+    assert!(matches!(
+        concatenate(&[&array, &array]),
+        Err(Error::Overflow)
+    ));
+}
+
+#[cfg(feature = "compute_cast")]
+#[test]
+fn coerce_integers() -> Result<()> {
+    use arrow2::compute::concatenate::concatenate_coerce;
+
+    let lhs = Int32Array::from([Some(1), None]);
+    let rhs = Int64Array::from([Some(i64::MAX)]);
+
+    // the strict version errors
+    assert!(concatenate(&[&lhs, &rhs]).is_err());
+
+    let result = concatenate_coerce(&[&lhs, &rhs, &NullArray::new(DataType::Null, 1)])?;
+    let expected = Int64Array::from([Some(1), None, Some(i64::MAX), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    assert!(concatenate_coerce(&[&lhs, &Utf8Array::<i32>::from_slice(["a"])]).is_err());
+    // no float holds every 64-bit integer exactly
+    assert!(concatenate_coerce(&[&rhs, &Float64Array::from_slice([1.0])]).is_err());
+    assert!(concatenate_coerce(&[]).is_err());
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use arrow2::array::*;
-use arrow2::compute::contains::contains;
+use arrow2::compute::contains::{contains, list_contains, list_contains_utf8};
+use arrow2::error::Error;
 
 // disable wrapping inside literal vectors used for test data and assertions
 #[rustfmt::skip::macros(vec)]
@@ -59,3 +60,54 @@ fn test_contains_binary() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_list_contains() {
+    let data = vec![
+        Some(vec![Some(1i32), None, Some(3)]),
+        None,
+        Some(vec![]),
+        Some(vec![None, Some(2)]),
+        Some(vec![Some(3)]),
+    ];
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(data).unwrap();
+    let list: ListArray<i32> = list.into();
+
+    let expected = BooleanArray::from(vec![Some(true), None, Some(false), Some(false), Some(true)]);
+    assert_eq!(list_contains(&list, 3).unwrap(), expected);
+
+    let list = list.sliced(3, 2);
+    let expected = BooleanArray::from(vec![Some(true), Some(false)]);
+    assert_eq!(list_contains(&list, 2).unwrap(), expected);
+
+    // the needle must be of the type of the values
+    assert!(matches!(
+        list_contains(&list, 2i64),
+        Err(Error::InvalidArgumentError(_))
+    ));
+}
+
+#[test]
+fn test_list_contains_utf8() {
+    let data = vec![
+        Some(vec![Some("a"), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some("b"), Some("a")]),
+    ];
+    let mut list = MutableListArray::<i64, MutableUtf8Array<i32>>::new();
+    list.try_extend(data).unwrap();
+    let list: ListArray<i64> = list.into();
+
+    let expected = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+    assert_eq!(list_contains_utf8::<_, i32>(&list, "a").unwrap(), expected);
+
+    let expected = BooleanArray::from(vec![Some(false), None, Some(false), Some(false)]);
+    assert_eq!(list_contains_utf8::<_, i32>(&list, "").unwrap(), expected);
+
+    assert!(matches!(
+        list_contains_utf8::<_, i64>(&list, "a"),
+        Err(Error::InvalidArgumentError(_))
+    ));
+}
@@ -1,6 +1,7 @@
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
 use arrow2::compute::filter::*;
+use arrow2::datatypes::DataType;
 
 #[test]
 fn array_slice() {
@@ -148,6 +149,24 @@ fn masked_true_values() {
     assert_eq!(expected, c.as_ref());
 }
 
+#[test]
+fn to_indices() {
+    let mask = BooleanArray::from([Some(true), None, Some(false), Some(true), Some(true)]);
+    let indices = filter_to_indices(&mask);
+    assert_eq!(indices, UInt32Array::from_slice([0, 3, 4]));
+
+    // taking the indices is equivalent to filtering
+    #[cfg(feature = "compute_take")]
+    {
+        let array = Int32Array::from_slice([1, 2, 3, 4, 5]);
+        let taken = arrow2::compute::take::take(&array, &indices).unwrap();
+        assert_eq!(taken, filter(&array, &mask).unwrap());
+    }
+
+    let mask = BooleanArray::from_slice([false, true, true, false]).sliced(1, 3);
+    assert_eq!(filter_to_indices(&mask), UInt32Array::from_slice([0, 1]));
+}
+
 /*
 #[test]
 fn dictionary_array() {
@@ -213,3 +232,170 @@ fn list_array() {
     assert_eq!(&make_array(expected), &result);
 }
 */
+
+#[test]
+fn struct_array() {
+    use super::struct_array;
+
+    let array = struct_array(
+        Int32Array::from([Some(1), None, Some(3), Some(4)]),
+        Utf8Array::from([Some("a"), Some("b"), None, Some("d")]),
+        Some([true, true, false, true].into()),
+    );
+    let mask = BooleanArray::from([Some(true), Some(true), Some(true), None]);
+    let result = filter(&array, &mask).unwrap();
+
+    let expected = struct_array(
+        Int32Array::from([Some(1), None, Some(3)]),
+        Utf8Array::from([Some("a"), Some("b"), None]),
+        Some([true, true, false].into()),
+    );
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn list_array() {
+    let list = super::list_array::<i64>;
+
+    let array = list(&[
+        Some(vec![Some(0), Some(1), Some(2)]),
+        Some(vec![Some(3), None, Some(5)]),
+        Some(vec![Some(6), Some(7)]),
+        None,
+    ]);
+    let mask = BooleanArray::from_slice([false, true, false, true]);
+    let result = filter(&array, &mask).unwrap();
+
+    let expected = list(&[Some(vec![Some(3), None, Some(5)]), None]);
+    assert_eq!(expected, result.as_ref());
+    let result = result.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+    assert_eq!(result.offsets().as_slice(), &[0, 3, 3]);
+    assert_eq!(result.values().len(), 3);
+}
+
+#[test]
+fn chunk_mixed_types() {
+    use arrow2::chunk::Chunk;
+    use arrow2::datatypes::DataType;
+
+    let a = Int32Array::from([Some(1), None, Some(3), Some(4)]);
+    let b = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("d")]);
+    let c = BooleanArray::from_slice([true, false, true, false]);
+    let chunk = Chunk::new(vec![a.boxed(), b.boxed(), c.boxed()]);
+
+    // the third slot is null (with a `true` value) and must be dropped
+    let mask = BooleanArray::new(
+        DataType::Boolean,
+        Bitmap::from([true, true, true, false]),
+        Some(Bitmap::from([true, true, false, true])),
+    );
+    let result = filter_chunk(&chunk, &mask).unwrap();
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(
+        result.arrays()[0].as_ref(),
+        &Int32Array::from([Some(1), None]) as &dyn Array
+    );
+    assert_eq!(
+        result.arrays()[1].as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b"]) as &dyn Array
+    );
+    assert_eq!(
+        result.arrays()[2].as_ref(),
+        &BooleanArray::from_slice([true, false]) as &dyn Array
+    );
+
+    let mask = BooleanArray::from_slice([false; 4]);
+    let result = filter_chunk(&chunk, &mask).unwrap();
+    assert_eq!(result.len(), 0);
+    assert_eq!(result.arrays().len(), 3);
+
+    let mask = BooleanArray::from_slice([true; 3]);
+    assert!(filter_chunk(&chunk, &mask).is_err());
+}
+
+#[test]
+fn null_array() {
+    let array = NullArray::new(DataType::Null, 4);
+    let mask = BooleanArray::from([Some(true), Some(false), None, Some(true)]);
+
+    let result = filter(&array, &mask).unwrap();
+
+    assert_eq!(
+        result.as_ref(),
+        &NullArray::new(DataType::Null, 2) as &dyn Array
+    );
+}
+
+#[test]
+fn fixed_size_binary() {
+    let array = FixedSizeBinaryArray::from([Some([1, 2]), None, Some([3, 4]), Some([5, 6])]);
+    let mask = BooleanArray::from_slice([true, true, false, true]);
+
+    let result = filter(&array, &mask).unwrap();
+
+    let expected = FixedSizeBinaryArray::from([Some([1, 2]), None, Some([5, 6])]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // sliced
+    let result = filter(
+        &array.sliced(1, 3),
+        &BooleanArray::from_slice([true, false, true]),
+    )
+    .unwrap();
+
+    let expected = FixedSizeBinaryArray::from([None, Some([5, 6])]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn fixed_size_list() {
+    let values = Int32Array::from([
+        Some(1),
+        Some(2),
+        Some(3),
+        None,
+        Some(5),
+        Some(6),
+        Some(7),
+        Some(8),
+    ]);
+    let data_type = DataType::FixedSizeList(
+        Box::new(arrow2::datatypes::Field::new("item", DataType::Int32, true)),
+        2,
+    );
+    let array = FixedSizeListArray::new(
+        data_type.clone(),
+        values.boxed(),
+        Some([true, false, true, true].into()),
+    );
+    let mask = BooleanArray::from_slice([true, true, false, true]);
+
+    let result = filter(&array, &mask).unwrap();
+
+    let expected = FixedSizeListArray::new(
+        data_type.clone(),
+        Int32Array::from([Some(1), Some(2), Some(3), None, Some(7), Some(8)]).boxed(),
+        Some([true, false, true].into()),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    let result = result
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .unwrap();
+    assert_eq!(result.values().len(), 6);
+
+    // sliced
+    let result = filter(
+        &array.sliced(2, 2),
+        &BooleanArray::from_slice([false, true]),
+    )
+    .unwrap();
+
+    let expected = FixedSizeListArray::new(
+        data_type,
+        Int32Array::from([Some(7), Some(8)]).boxed(),
+        None,
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
@@ -0,0 +1,59 @@
+use arrow2::array::{Array, Utf8Array};
+use arrow2::compute::json::json_extract;
+
+#[test]
+fn extract_paths() {
+    let array = Utf8Array::<i32>::from([
+        Some(r#"{"a": {"b": {"c": 1.5}}}"#),
+        Some(r#"{"a": {"b": {"d": 1}}}"#),
+        Some(r#"{"a": {"b": {"c": "x"}}}"#),
+        Some(r#"{"a": {"b": {"c": [true, null]}}}"#),
+        Some(r#"{"a": {"b": {"c": null}}}"#),
+        Some(r#"{"a": 1"#),
+        None,
+    ]);
+
+    let result = json_extract(&array, "a.b.c").unwrap();
+    let expected = Utf8Array::<i32>::from([
+        Some("1.5"),
+        None,
+        Some(r#""x""#),
+        Some("[true,null]"),
+        Some("null"),
+        None,
+        None,
+    ]);
+    assert_eq!(result, expected);
+
+    // missing paths, including paths through scalars
+    let result = json_extract(&array, "a.b.c.d").unwrap();
+    assert_eq!(result.null_count(), 7);
+    let result = json_extract(&array, "b").unwrap();
+    assert_eq!(result.null_count(), 7);
+
+    let result = json_extract(&array, "").unwrap();
+    assert_eq!(result.value(0), r#"{"a":{"b":{"c":1.5}}}"#);
+
+    assert!(json_extract(&array, "a..c").is_err());
+}
+
+#[test]
+fn extract_arrays_in_path() {
+    let array = Utf8Array::<i64>::from([
+        Some(r#"{"a": [{"b": 1}, {"b": 2}]}"#),
+        Some(r#"{"a": [{"b": 1}]}"#),
+        Some(r#"[{"a": [0, {"b": {"c": 3}}]}]"#),
+        Some(r#"{"a": {"1": {"b": 4}}}"#),
+    ]);
+
+    let result = json_extract(&array, "a.1.b").unwrap();
+    let expected = Utf8Array::<i64>::from([Some("2"), None, None, Some("4")]);
+    assert_eq!(result, expected);
+
+    let result = json_extract(&array, "0.a.1.b").unwrap();
+    let expected = Utf8Array::<i64>::from([None, None, Some(r#"{"c":3}"#), None]);
+    assert_eq!(result, expected);
+
+    let result = json_extract(&array, "a.-1").unwrap();
+    assert_eq!(result.null_count(), 4);
+}
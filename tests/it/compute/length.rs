@@ -76,6 +76,8 @@ fn consistency() {
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
+        List(Box::new(Field::new("item", Int32, true))),
+        LargeList(Box::new(Field::new("item", Utf8, true))),
     ];
 
     datatypes.into_iter().for_each(|d1| {
@@ -87,3 +89,30 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn list() {
+    let data = vec![
+        Some(vec![Some(1i32), None, Some(3)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(4)]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data.clone()).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let expected = Int32Array::from([Some(3), None, Some(0), Some(1)]);
+    assert_eq!(list_lengths(&array), expected);
+    assert_eq!(length(&array).unwrap().as_ref(), &expected as &dyn Array);
+    assert_eq!(
+        list_lengths(&array.sliced(1, 3)),
+        Int32Array::from([None, Some(0), Some(1)])
+    );
+
+    let mut array = MutableListArray::<i64, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i64> = array.into();
+    let expected = Int64Array::from([Some(3), None, Some(0), Some(1)]);
+    assert_eq!(length(&array).unwrap().as_ref(), &expected as &dyn Array);
+}
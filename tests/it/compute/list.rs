@@ -0,0 +1,42 @@
+use arrow2::array::*;
+use arrow2::compute::list::explode;
+
+fn list() -> ListArray<i32> {
+    let data = vec![
+        Some(vec![Some(1i32), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(4), Some(5), Some(6)]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+#[test]
+fn explode_basics() {
+    let (values, parents) = explode(&list(), false).unwrap();
+
+    let expected = Int32Array::from([Some(1), None, Some(4), Some(5), Some(6)]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(parents, UInt32Array::from_slice([0, 0, 3, 3, 3]));
+}
+
+#[test]
+fn explode_preserve_empty() {
+    let (values, parents) = explode(&list(), true).unwrap();
+
+    let expected = Int32Array::from([Some(1), None, None, None, Some(4), Some(5), Some(6)]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(parents, UInt32Array::from_slice([0, 0, 1, 2, 3, 3, 3]));
+}
+
+#[test]
+fn explode_sliced() {
+    let list = list().sliced(1, 3);
+    let (values, parents) = explode(&list, false).unwrap();
+
+    let expected = Int32Array::from_slice([4, 5, 6]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(parents, UInt32Array::from_slice([2, 2, 2]));
+}
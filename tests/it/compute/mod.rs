@@ -4,6 +4,8 @@ mod aggregate;
 mod arithmetics;
 #[cfg(feature = "compute_bitwise")]
 mod bitwise;
+#[cfg(feature = "compute_bloom_filter")]
+mod bloom_filter;
 #[cfg(feature = "compute_boolean")]
 mod boolean;
 #[cfg(feature = "compute_boolean_kleene")]
@@ -22,12 +24,16 @@ mod filter;
 mod hash;
 #[cfg(feature = "compute_if_then_else")]
 mod if_then_else;
+#[cfg(feature = "compute_json")]
+mod json;
 #[cfg(feature = "compute_length")]
 mod length;
 #[cfg(feature = "compute_like")]
 mod like;
 #[cfg(feature = "compute_limit")]
 mod limit;
+#[cfg(feature = "compute_list")]
+mod list;
 #[cfg(feature = "compute_merge_sort")]
 mod merge_sort;
 #[cfg(feature = "compute_partition")]
@@ -47,4 +53,26 @@ mod utf8;
 #[cfg(feature = "compute_window")]
 mod window;
 
+mod arity;
 mod arity_assign;
+
+#[cfg(any(feature = "compute_concatenate", feature = "compute_filter"))]
+use arrow2::{array::*, bitmap::Bitmap, datatypes::*, offset::Offset};
+
+/// Returns a [`StructArray`] with fields `a: Int32` and `b: Utf8`.
+#[cfg(any(feature = "compute_concatenate", feature = "compute_filter"))]
+fn struct_array(a: Int32Array, b: Utf8Array<i32>, validity: Option<Bitmap>) -> StructArray {
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    StructArray::new(data_type, vec![a.boxed(), b.boxed()], validity)
+}
+
+/// Returns a [`ListArray`] of `Int32` with `values`.
+#[cfg(any(feature = "compute_concatenate", feature = "compute_filter"))]
+fn list_array<O: Offset>(values: &[Option<Vec<Option<i32>>>]) -> ListArray<O> {
+    let mut array = MutableListArray::<O, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(values.to_vec()).unwrap();
+    array.into()
+}
@@ -179,6 +179,76 @@ fn test_nans() {
     );
 }
 
+#[test]
+fn total_order_floats() {
+    let nan = f64::NAN;
+    // a NaN with the sign bit set, e.g. the result of `0.0 / 0.0` on x86
+    let negative_nan = -f64::NAN;
+    let array = Float64Array::from_slice([nan, 1.0, -0.0, negative_nan, 0.0, nan]);
+
+    let bits = |options| {
+        let result = sort(&array, &options, None).unwrap();
+        let result = result.as_any().downcast_ref::<Float64Array>().unwrap();
+        result
+            .values_iter()
+            .map(|x| if x.is_nan() { f64::NAN } else { *x }.to_bits())
+            .collect::<Vec<_>>()
+    };
+    let expected = [-0.0, 0.0, 1.0, nan, nan, nan].map(f64::to_bits);
+
+    let ascending = bits(SortOptions {
+        descending: false,
+        nulls_first: true,
+    });
+    assert_eq!(ascending, expected);
+
+    let mut expected = expected;
+    expected.reverse();
+    let descending = bits(SortOptions {
+        descending: true,
+        nulls_first: true,
+    });
+    assert_eq!(descending, expected);
+
+    let array = Float32Array::from_slice([f32::NAN, 1.0, -f32::NAN, -0.0, 0.0]);
+    let result = sort_to_indices::<i32>(&array, &SortOptions::default(), None).unwrap();
+    assert_eq!(&result.values()[..3], &[3, 4, 1]);
+}
+
+#[test]
+fn total_order_floats_lexsort() {
+    let array = Float64Array::from_slice([f64::NAN, -f64::NAN, 1.0, -0.0, 0.0]);
+    // NaNs are equal to each other, so only their positions are compared
+    let bits = |indices: &PrimitiveArray<i32>| {
+        indices
+            .values_iter()
+            .map(|i| array.value(*i as usize))
+            .map(|x| if x.is_nan() { f64::NAN } else { x }.to_bits())
+            .collect::<Vec<_>>()
+    };
+
+    for descending in [false, true] {
+        let options = SortOptions {
+            descending,
+            nulls_first: true,
+        };
+        let sorted = sort_to_indices::<i32>(&array, &options, None).unwrap();
+        let columns = [
+            SortColumn {
+                values: &array,
+                options: Some(options),
+            },
+            // a second column so that lexsort compares via `build_compare`
+            SortColumn {
+                values: &Int32Array::from_slice([0, 0, 0, 0, 0]),
+                options: None,
+            },
+        ];
+        let lexsorted = lexsort_to_indices::<i32>(&columns, None).unwrap();
+        assert_eq!(bits(&sorted), bits(&lexsorted));
+    }
+}
+
 #[test]
 fn to_indices_strings() {
     to_indices_string_arrays(
@@ -600,3 +670,67 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn sort_equals_take_indices() {
+    use arrow2::compute::take::take;
+
+    let strings = Utf8Array::<i64>::from([Some("b"), None, Some("a"), Some("c"), None, Some("a")]);
+    let binaries =
+        BinaryArray::<i32>::from([Some(b"b"), None, Some(b"a"), Some(b"c"), None, Some(b"a")]);
+    let numbers = Int32Array::from([Some(2), None, Some(1), Some(3), None, Some(1)]);
+    let sliced = Utf8Array::<i32>::from([Some("z"), Some("b"), Some("a"), None]).sliced(1, 3);
+    let arrays: [&dyn Array; 4] = [&strings, &binaries, &numbers, &sliced];
+
+    for array in arrays {
+        for (descending, nulls_first) in
+            [(false, false), (false, true), (true, false), (true, true)]
+        {
+            let options = SortOptions {
+                descending,
+                nulls_first,
+            };
+            for limit in [None, Some(0), Some(1), Some(2), Some(3), Some(10)] {
+                let result = sort(array, &options, limit).unwrap();
+                let indices = sort_to_indices::<u32>(array, &options, limit).unwrap();
+                let expected = take(array, &indices).unwrap();
+                assert_eq!(result, expected, "{options:?} {limit:?}");
+            }
+        }
+    }
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let result = sort(&strings, &options, Some(4)).unwrap();
+    let expected = Utf8Array::<i64>::from([Some("c"), Some("b"), Some("a"), Some("a")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn to_indices_stable() {
+    let array = Int32Array::from([Some(2), None, Some(1), Some(2), None, Some(1), Some(2)]);
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+    let result = sort_to_indices_stable::<u32>(&array, &options, None).unwrap();
+    assert_eq!(result, UInt32Array::from_slice([1, 4, 2, 5, 0, 3, 6]));
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let result = sort_to_indices_stable::<u32>(&array, &options, Some(5)).unwrap();
+    assert_eq!(result, UInt32Array::from_slice([0, 3, 6, 2, 5]));
+
+    let mut dictionary = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    dictionary
+        .try_extend([Some("b"), Some("a"), Some("b"), Some("a")])
+        .unwrap();
+    let dictionary: DictionaryArray<i32> = dictionary.into();
+    let result = sort_to_indices_stable::<u32>(&dictionary, &options, None).unwrap();
+    assert_eq!(result, UInt32Array::from_slice([0, 2, 1, 3]));
+}
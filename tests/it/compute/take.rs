@@ -60,6 +60,37 @@ fn test_take_primitive_null_values() {
     .unwrap();
 }
 
+#[test]
+fn test_take_primitive_consecutive_indices() {
+    let values = Int32Array::from_slice([0, 1, 2, 3, 4, 5, 6, 7]);
+    let indices = Int32Array::from_slice([2, 3, 4, 0, 6, 7, 7, 1]);
+    let result = take(&values, &indices).unwrap();
+    let expected = Int32Array::from_slice([2, 3, 4, 0, 6, 7, 7, 1]);
+    assert_eq!(expected, result.as_ref());
+
+    let values = Int32Array::from([Some(0), None, Some(2), Some(3), None]);
+    let indices = Int32Array::from_slice([1, 2, 3, 0, 4]);
+    let result = take(&values, &indices).unwrap();
+    let expected = Int32Array::from([None, Some(2), Some(3), Some(0), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn test_take_utf8_consecutive_indices() {
+    // sliced, so that the offsets do not start at zero
+    let values = Utf8Array::<i32>::from_slice(["x", "a", "bb", "", "ccc", "d"]).sliced(1, 5);
+    let indices = Int32Array::from_slice([0, 1, 2, 3, 4, 1, 2]);
+    let result = take(&values, &indices).unwrap();
+    let expected = Utf8Array::<i32>::from_slice(["a", "bb", "", "ccc", "d", "bb", ""]);
+    assert_eq!(expected, result.as_ref());
+
+    let values = BinaryArray::<i64>::from([Some(b"a".as_ref()), None, Some(b"cc"), Some(b"d")]);
+    let indices = Int32Array::from_slice([1, 2, 3, 0]);
+    let result = take(&values, &indices).unwrap();
+    let expected = BinaryArray::<i64>::from([None, Some(b"cc".as_ref()), Some(b"d"), Some(b"a")]);
+    assert_eq!(expected, result.as_ref());
+}
+
 fn create_test_struct() -> StructArray {
     let boolean = BooleanArray::from_slice([true, false, false, true]);
     let int = Int32Array::from_slice([42, 28, 19, 31]);
@@ -324,3 +355,167 @@ fn test_nested() {
 
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn take_safe_out_of_range() -> Result<()> {
+    use arrow2::compute::take::take_safe;
+
+    let values = Utf8Array::<i32>::from([Some("a"), None, Some("c")]);
+    let indices = Int32Array::from([Some(2), Some(3), None, Some(-1), Some(0), Some(100)]);
+    let result = take_safe(&values, &indices)?;
+    let expected = Utf8Array::<i32>::from([Some("c"), None, None, None, Some("a"), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // in range indices are equal to `take`
+    let indices = UInt32Array::from([Some(1), None, Some(0)]);
+    assert_eq!(take_safe(&values, &indices)?, take(&values, &indices)?);
+
+    let values = Int32Array::from_slice([]);
+    let result = take_safe(&values, &indices)?;
+    assert_eq!(
+        result.as_ref(),
+        &Int32Array::new_null(DataType::Int32, 3) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn take_chunk_sorted() -> Result<()> {
+    use arrow2::chunk::Chunk;
+    use arrow2::compute::sort::{lexsort_to_indices, SortColumn};
+    use arrow2::compute::take::take_chunk;
+
+    let a = Int32Array::from([Some(3), None, Some(1), Some(2)]);
+    let b = Utf8Array::<i32>::from_slice(["c", "null", "a", "b"]);
+    let chunk = Chunk::new(vec![a.clone().boxed(), b.boxed()]);
+
+    let indices = lexsort_to_indices::<i32>(
+        &[SortColumn {
+            values: &a,
+            options: None,
+        }],
+        None,
+    )?;
+    let sorted = take_chunk(&chunk, &indices)?;
+
+    assert_eq!(
+        sorted.arrays()[0].as_ref(),
+        &Int32Array::from([None, Some(1), Some(2), Some(3)]) as &dyn Array
+    );
+    assert_eq!(
+        sorted.arrays()[1].as_ref(),
+        &Utf8Array::<i32>::from_slice(["null", "a", "b", "c"]) as &dyn Array
+    );
+
+    let indices = Int32Array::from([Some(0), None]);
+    let taken = take_chunk(&chunk, &indices)?;
+    assert_eq!(taken.len(), 2);
+    assert_eq!(taken.arrays()[1].null_count(), 1);
+
+    let indices = Int32Array::from_slice([0, 4]);
+    assert!(take_chunk(&chunk, &indices).is_err());
+    Ok(())
+}
+
+#[test]
+fn null_array() {
+    let array = NullArray::new(DataType::Null, 3);
+    let indices = Int32Array::from([Some(2), None, Some(0), Some(0)]);
+
+    let result = take(&array, &indices).unwrap();
+
+    assert_eq!(
+        result.as_ref(),
+        &NullArray::new(DataType::Null, 4) as &dyn Array
+    );
+}
+
+#[test]
+fn map_permutation() {
+    let fields = vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ];
+    let entries = |keys: Vec<&str>, values: Vec<Option<i32>>| {
+        StructArray::new(
+            DataType::Struct(fields.clone()),
+            vec![
+                Utf8Array::<i32>::from_slice(keys).boxed(),
+                Int32Array::from(values).boxed(),
+            ],
+            None,
+        )
+        .boxed()
+    };
+    let data_type = DataType::Map(
+        Box::new(Field::new(
+            "entries",
+            DataType::Struct(fields.clone()),
+            false,
+        )),
+        true,
+    );
+
+    // [{a: 1, b: 2}, null, {}, {c: null}]
+    let array = MapArray::new(
+        data_type.clone(),
+        vec![0, 2, 2, 2, 3].try_into().unwrap(),
+        entries(vec!["a", "b", "c"], vec![Some(1), Some(2), None]),
+        Some([true, false, true, true].into()),
+    );
+    let indices = Int32Array::from([Some(3), Some(0), None, Some(1), Some(2)]);
+
+    let result = take(&array, &indices).unwrap();
+
+    // [{c: null}, {a: 1, b: 2}, null, null, {}]
+    let expected = MapArray::new(
+        data_type.clone(),
+        vec![0, 1, 3, 3, 3, 3].try_into().unwrap(),
+        entries(vec!["c", "a", "b"], vec![None, Some(1), Some(2)]),
+        Some([true, true, false, false, true].into()),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    assert_eq!(result.data_type(), &data_type);
+    assert!(can_take(&data_type));
+}
+
+#[test]
+fn identity_shares_buffers() {
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "a"]).boxed();
+    let array =
+        DictionaryArray::try_from_keys(Int32Array::from([Some(0), None, Some(1)]), values).unwrap();
+    let indices = Int32Array::from_slice([0, 1, 2]);
+
+    let result = take(&array, &indices).unwrap();
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+    let result = result.as_dictionary::<i32>().unwrap();
+    assert_eq!(
+        result.keys().values().as_ptr(),
+        array.keys().values().as_ptr()
+    );
+    assert_eq!(
+        result.values().as_utf8::<i32>().unwrap().values().as_ptr(),
+        array.values().as_utf8::<i32>().unwrap().values().as_ptr()
+    );
+}
+
+#[test]
+fn contiguous_range_is_sliced() {
+    let array = Int32Array::from([Some(0), None, Some(2), Some(3)]);
+
+    let indices = UInt8Array::from_slice([1, 2, 3]);
+    let result = take(&array, &indices).unwrap();
+    assert_eq!(result.as_ref(), &array.clone().sliced(1, 3) as &dyn Array);
+    assert_eq!(
+        result.as_primitive::<i32>().unwrap().values().as_ptr(),
+        array.values()[1..].as_ptr()
+    );
+
+    // null indices are not a range
+    let indices = UInt8Array::from([Some(1), None, Some(3)]);
+    let result = take(&array, &indices).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Int32Array::from([None, None, Some(3)]) as &dyn Array
+    );
+}
@@ -372,3 +372,80 @@ fn consistency_check<O: arrow2::types::NativeType>(
         }
     });
 }
+
+#[test]
+fn with_timezone_keeps_instants() {
+    let array = Int64Array::from([Some(1_000), None]);
+
+    let result = with_timezone(&array, TimeUnit::Millisecond, None, "+05:30").unwrap();
+
+    assert_eq!(result.values(), array.values());
+    assert_eq!(result.validity(), array.validity());
+    assert_eq!(
+        result.data_type(),
+        &DataType::Timestamp(TimeUnit::Millisecond, Some("+05:30".to_string()))
+    );
+    assert!(with_timezone(&array, TimeUnit::Millisecond, Some("+05:30"), "invalid").is_err());
+}
+
+#[test]
+fn convert_timezone_offsets() {
+    // 1970-01-01T01:00:00+01:00, null
+    let array = Int64Array::from([Some(0), None]);
+
+    let result = convert_timezone(&array, TimeUnit::Second, Some("+01:00"), "+02:00").unwrap();
+
+    // 1970-01-01T01:00:00+02:00
+    let expected = Int64Array::from([Some(-3600), None]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("+02:00".to_string()),
+    ));
+    assert_eq!(result, expected);
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn timezone_dst_transitions() {
+    let timestamp = |date: (i32, u32, u32), time: (u32, u32)| {
+        chrono::NaiveDate::from_ymd_opt(date.0, date.1, date.2)
+            .unwrap()
+            .and_hms_opt(time.0, time.1, 0)
+            .unwrap()
+            .timestamp()
+    };
+    let hour = 60 * 60;
+    let tz = "America/New_York";
+
+    // naive wall-clock times around the 2021-03-14 (02:00 -> 03:00) and
+    // 2021-11-07 (02:00 -> 01:00) transitions
+    let array = Int64Array::from_slice([
+        timestamp((2021, 3, 14), (1, 30)),
+        timestamp((2021, 3, 14), (2, 30)),
+        timestamp((2021, 3, 14), (3, 30)),
+        timestamp((2021, 11, 7), (1, 30)),
+    ]);
+
+    let result = convert_timezone(&array, TimeUnit::Second, None, tz).unwrap();
+
+    let expected = Int64Array::from([
+        // EST
+        Some(timestamp((2021, 3, 14), (1, 30)) + 5 * hour),
+        // skipped
+        None,
+        // EDT
+        Some(timestamp((2021, 3, 14), (3, 30)) + 4 * hour),
+        // ambiguous: the earliest (EDT)
+        Some(timestamp((2021, 11, 7), (1, 30)) + 4 * hour),
+    ])
+    .to(DataType::Timestamp(TimeUnit::Second, Some(tz.to_string())));
+    assert_eq!(result, expected);
+
+    // converting back to UTC recovers the wall-clock times, while `with_timezone` keeps the instants
+    let back = convert_timezone(&result, TimeUnit::Second, Some(tz), "UTC").unwrap();
+    assert_eq!(back.value(0), array.value(0));
+    assert_eq!(back.value(2), array.value(2));
+    assert_eq!(back.value(3), array.value(3));
+
+    let same = with_timezone(&result, TimeUnit::Second, Some(tz), "UTC").unwrap();
+    assert_eq!(same.values(), result.values());
+}
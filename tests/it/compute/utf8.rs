@@ -369,3 +369,154 @@ fn consistency_upper() {
         }
     });
 }
+
+#[test]
+fn concat_str_nulls() {
+    let a = Utf8Array::<i64>::from([Some("a"), Some("b"), None, None, Some("")]);
+    let b = Utf8Array::<i64>::from([Some("1"), None, Some("3"), None, Some("")]);
+    let c = Utf8Array::<i64>::from_slice(["_", "x", "y", "z", "w", ""]).sliced(1, 5);
+
+    let result = concat_str(&[&a, &b, &c], ", ", false).unwrap();
+    let expected = Utf8Array::<i64>::from([Some("a, 1, x"), None, None, None, Some(", , ")]);
+    assert_eq!(result, expected);
+
+    let result = concat_str(&[&a, &b, &c], ", ", true).unwrap();
+    let expected = Utf8Array::<i64>::from([
+        Some("a, 1, x"),
+        Some("b, y"),
+        Some("3, z"),
+        Some("w"),
+        Some(", , "),
+    ]);
+    assert_eq!(result, expected);
+
+    let result = concat_str(&[&a, &b], "", true).unwrap();
+    let expected = Utf8Array::<i64>::from([Some("a1"), Some("b"), Some("3"), None, Some("")]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn concat_str_errors() {
+    let a = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let b = Utf8Array::<i32>::from_slice(["a"]);
+    assert!(concat_str(&[&a, &b], "-", false).is_err());
+    assert!(concat_str::<i32>(&[], "-", false).is_err());
+    assert_eq!(concat_str(&[&a], "-", false).unwrap(), a);
+}
+
+#[test]
+fn replace_overlapping() {
+    let array = Utf8Array::<i32>::from([Some("aaaa"), None, Some("abababa"), Some(""), Some("b")]);
+
+    let result = replace(&array, "aa", "x");
+    let expected = Utf8Array::<i32>::from([Some("xx"), None, Some("abababa"), Some(""), Some("b")]);
+    assert_eq!(result, expected);
+
+    let result = replace(&array, "aba", "-");
+    let expected = Utf8Array::<i32>::from([Some("aaaa"), None, Some("-b-"), Some(""), Some("b")]);
+    assert_eq!(result, expected);
+
+    // an empty pattern is a no-op
+    assert_eq!(replace(&array, "", "x"), array);
+    // replacing with an empty string removes the pattern
+    let result = replace(&array, "a", "");
+    let expected = Utf8Array::<i32>::from([Some(""), None, Some("bbb"), Some(""), Some("b")]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn replace_multi_byte() {
+    let array = Utf8Array::<i64>::from_slice(["€ und €", "naïve", "日本語"]).sliced(1, 2);
+
+    let result = replace(&array, "ï", "i");
+    assert_eq!(result, Utf8Array::<i64>::from_slice(["naive", "日本語"]));
+
+    let result = replace(&array, "本", "€€");
+    assert_eq!(result, Utf8Array::<i64>::from_slice(["naïve", "日€€語"]));
+
+    let array = Utf8Array::<i64>::from_slice(["€ und €"]);
+    let result = replace(&array, "€", "EUR");
+    assert_eq!(result, Utf8Array::<i64>::from_slice(["EUR und EUR"]));
+}
+
+#[test]
+fn starts_ends_with_contains() {
+    let array = Utf8Array::<i32>::from([Some("arrow"), None, Some("narrow"), Some(""), Some("ar")]);
+
+    let result = starts_with(&array, "ar");
+    let expected = BooleanArray::from([Some(true), None, Some(false), Some(false), Some(true)]);
+    assert_eq!(result, expected);
+
+    let result = ends_with(&array, "row");
+    let expected = BooleanArray::from([Some(true), None, Some(true), Some(false), Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = contains(&array, "rr");
+    let expected = BooleanArray::from([Some(true), None, Some(true), Some(false), Some(false)]);
+    assert_eq!(result, expected);
+
+    // an empty pattern matches all non-null values
+    let result = contains(&array, "");
+    let expected = BooleanArray::from([Some(true), None, Some(true), Some(true), Some(true)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn starts_ends_with_contains_array() {
+    let lhs = Utf8Array::<i64>::from([Some("arrow"), None, Some("narrow"), Some("ab"), Some("a")]);
+    let rhs = Utf8Array::<i64>::from([Some("ar"), Some("x"), None, Some("b"), Some("ab")]);
+
+    let result = starts_with_array(&lhs, &rhs).unwrap();
+    let expected = BooleanArray::from([Some(true), None, None, Some(false), Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = ends_with_array(&lhs, &rhs).unwrap();
+    let expected = BooleanArray::from([Some(false), None, None, Some(true), Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = contains_array(&lhs, &rhs).unwrap();
+    let expected = BooleanArray::from([Some(true), None, None, Some(true), Some(false)]);
+    assert_eq!(result, expected);
+
+    assert!(contains_array(&lhs, &rhs.sliced(0, 2)).is_err());
+}
+
+#[test]
+fn pad_multi_byte() {
+    let array = Utf8Array::<i32>::from([Some("ab"), None, Some("日本"), Some(""), Some("naïve")]);
+
+    let result = lpad(&array, 4, '€', false);
+    let expected = Utf8Array::<i32>::from([
+        Some("€€ab"),
+        None,
+        Some("€€日本"),
+        Some("€€€€"),
+        Some("naïve"),
+    ]);
+    assert_eq!(result, expected);
+
+    let result = rpad(&array, 3, '.', false);
+    let expected =
+        Utf8Array::<i32>::from([Some("ab."), None, Some("日本."), Some("..."), Some("naïve")]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn pad_truncate() {
+    let array = Utf8Array::<i64>::from([Some("naïve"), None, Some("日本語"), Some("abc")]);
+
+    let result = lpad(&array, 3, ' ', true);
+    let expected = Utf8Array::<i64>::from([Some("naï"), None, Some("日本語"), Some("abc")]);
+    assert_eq!(result, expected);
+
+    let result = rpad(&array, 2, ' ', true);
+    let expected = Utf8Array::<i64>::from([Some("na"), None, Some("日本"), Some("ab")]);
+    assert_eq!(result, expected);
+
+    let result = rpad(&array, 0, ' ', true);
+    let expected = Utf8Array::<i64>::from([Some(""), None, Some(""), Some("")]);
+    assert_eq!(result, expected);
+
+    let result = rpad(&array, 2, ' ', false);
+    assert_eq!(result, array);
+}
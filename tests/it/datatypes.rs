@@ -0,0 +1,135 @@
+use arrow2::datatypes::{coerce_types, DataType, Field, Metadata, Schema};
+
+#[test]
+fn merge_overlapping() {
+    let lhs = Schema::from(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, false),
+    ]);
+    let rhs = Schema::from(vec![
+        Field::new("b", DataType::Utf8, true),
+        Field::new("a", DataType::Int64, false),
+    ]);
+
+    let merged = lhs.merge(&rhs).unwrap();
+    assert_eq!(
+        merged,
+        Schema::from(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Utf8, true),
+        ])
+    );
+}
+
+#[test]
+fn merge_disjoint() {
+    let lhs = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    let rhs = Schema::from(vec![
+        Field::new("b", DataType::Float64, true),
+        Field::new("c", DataType::Null, true),
+    ]);
+
+    let merged = lhs.merge(&rhs).unwrap();
+    assert_eq!(
+        merged,
+        Schema::from(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Float64, true),
+            Field::new("c", DataType::Null, true),
+        ])
+    );
+}
+
+#[test]
+fn merge_null() {
+    let lhs = Schema::from(vec![Field::new("a", DataType::Null, true)]);
+    let rhs = Schema::from(vec![Field::new("a", DataType::Utf8, false)]);
+
+    let merged = lhs.merge(&rhs).unwrap();
+    assert_eq!(
+        merged,
+        Schema::from(vec![Field::new("a", DataType::Utf8, true)])
+    );
+}
+
+#[test]
+fn merge_metadata() {
+    let lhs_metadata = Metadata::from([
+        ("key".to_string(), "lhs".to_string()),
+        ("lhs".to_string(), "1".to_string()),
+    ]);
+    let rhs_metadata = Metadata::from([
+        ("key".to_string(), "rhs".to_string()),
+        ("rhs".to_string(), "2".to_string()),
+    ]);
+    let lhs = Schema::from(vec![]).with_metadata(lhs_metadata);
+    let rhs = Schema::from(vec![]).with_metadata(rhs_metadata);
+
+    let merged = lhs.merge(&rhs).unwrap();
+    assert_eq!(
+        merged.metadata,
+        Metadata::from([
+            ("key".to_string(), "lhs".to_string()),
+            ("lhs".to_string(), "1".to_string()),
+            ("rhs".to_string(), "2".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn merge_incompatible() {
+    let lhs = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    let rhs = Schema::from(vec![Field::new("a", DataType::Utf8, false)]);
+
+    assert!(lhs.merge(&rhs).is_err());
+}
+
+#[test]
+fn merge_int64_float64() {
+    // merging is lossless: no float holds every 64-bit integer exactly
+    let lhs = Schema::from(vec![Field::new("a", DataType::Int64, false)]);
+    let rhs = Schema::from(vec![Field::new("a", DataType::Float64, false)]);
+    assert!(lhs.merge(&rhs).is_err());
+
+    let rhs = Schema::from(vec![Field::new("a", DataType::Float32, false)]);
+    let lhs = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    assert_eq!(
+        lhs.merge(&rhs).unwrap(),
+        Schema::from(vec![Field::new("a", DataType::Float64, false)])
+    );
+}
+
+#[test]
+fn coerce() {
+    use DataType::*;
+    let cases = [
+        (Int32, Int32, Some(Int32)),
+        (Null, Utf8, Some(Utf8)),
+        (Int8, Int64, Some(Int64)),
+        (UInt16, UInt8, Some(UInt16)),
+        (Int8, UInt8, Some(Int16)),
+        (UInt32, Int16, Some(Int64)),
+        (UInt64, Int64, None),
+        (Int8, Float16, Some(Float16)),
+        (Int16, Float32, Some(Float32)),
+        (Int32, Float32, Some(Float64)),
+        (UInt32, Float16, Some(Float64)),
+        (UInt64, Float32, None),
+        (Int64, Float64, None),
+        (Float32, Float64, Some(Float64)),
+        (Decimal(10, 2), Decimal(5, 4), Some(Decimal(12, 4))),
+        (Decimal(38, 0), Decimal(38, 10), Some(Decimal256(48, 10))),
+        (Decimal(5, 2), Decimal256(5, 2), Some(Decimal256(5, 2))),
+        (Int32, Decimal(5, 2), Some(Decimal(12, 2))),
+        (Decimal(76, 0), Decimal(10, 10), None),
+        (Float64, Decimal(5, 2), None),
+        (Utf8, LargeUtf8, Some(LargeUtf8)),
+        (LargeBinary, Binary, Some(LargeBinary)),
+        (Int32, Utf8, None),
+        (Boolean, Int8, None),
+    ];
+    for (lhs, rhs, expected) in cases {
+        assert_eq!(coerce_types(&lhs, &rhs), expected, "{lhs:?} and {rhs:?}");
+        assert_eq!(coerce_types(&rhs, &lhs), expected, "{rhs:?} and {lhs:?}");
+    }
+}
@@ -1,6 +1,6 @@
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
-use arrow2::datatypes::{DataType, Field, TimeUnit};
+use arrow2::datatypes::{DataType, Field, IntegerType, TimeUnit};
 use arrow2::{error::Result, ffi};
 use std::collections::BTreeMap;
 
@@ -308,6 +308,22 @@ fn dict() -> Result<()> {
     test_round_trip(array)
 }
 
+#[test]
+fn run_end_encoded() -> Result<()> {
+    let array = Int32Array::from([Some(1), Some(1), None, None, Some(2), Some(1)]);
+    let array = RunEndEncodedArray::<i32>::encode(&array)?;
+
+    test_round_trip(array)
+}
+
+#[test]
+fn run_end_encoded_schema() -> Result<()> {
+    let values = Field::new("values", DataType::Utf8, true);
+    let data_type = DataType::RunEndEncoded(IntegerType::Int16, Box::new(values));
+
+    test_round_trip_schema(Field::new("a", data_type, true))
+}
+
 #[test]
 fn schema() -> Result<()> {
     let field = Field::new(
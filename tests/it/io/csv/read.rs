@@ -3,6 +3,7 @@ use proptest::prelude::*;
 use std::io::Cursor;
 
 use arrow2::array::*;
+use arrow2::chunk::Chunk;
 use arrow2::datatypes::*;
 use arrow2::error::Result;
 use arrow2::io::csv::read::*;
@@ -52,6 +53,129 @@ fn read() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn batch_reader() -> Result<()> {
+    let mut data = "id,text,value\n".to_string();
+    for i in 0..100 {
+        data.push_str(&format!("{i},\"line {i}\nwith, newline\",{}.5\n", i * 2));
+    }
+
+    let read = |data: &str| {
+        ReaderBuilder::new()
+            .buffer_capacity(16)
+            .from_reader(Cursor::new(data.to_string()))
+    };
+
+    let mut reader = read(&data);
+    let (fields, _) = infer_schema(&mut reader, None, true, &infer)?;
+    let mut rows = vec![ByteRecord::default(); 200];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+    let expected = deserialize_batch(&rows[..rows_read], &fields, None, 0, deserialize_column)?;
+
+    let mut reader = read(&data);
+    let _ = infer_schema(&mut reader, None, true, &infer)?;
+    let batches = CsvBatchReader::new(reader, fields, 7).collect::<Result<Vec<_>>>()?;
+
+    assert_eq!(batches.len(), 15);
+    assert!(batches.iter().all(|batch| batch.len() <= 7));
+    for (column, expected) in expected.arrays().iter().enumerate() {
+        let arrays = batches
+            .iter()
+            .map(|batch| batch.arrays()[column].as_ref())
+            .collect::<Vec<_>>();
+        let result = arrow2::compute::concatenate::concatenate(&arrays)?;
+        assert_eq!(expected, &result);
+    }
+    Ok(())
+}
+
+#[test]
+fn batch_reader_projection() -> Result<()> {
+    let data = "a,b\n1,x\n2,y\n3,z";
+    let mut reader = ReaderBuilder::new().from_reader(Cursor::new(data));
+    let (fields, _) = infer_schema(&mut reader, None, true, &infer)?;
+
+    let mut batches = CsvBatchReader::new(reader, fields, 2).with_projection(vec![1]);
+
+    let batch = batches.next().unwrap()?;
+    assert_eq!(batch.arrays().len(), 1);
+    assert_eq!(
+        batch.arrays()[0].as_ref(),
+        &Utf8Array::<i32>::from_slice(["x", "y"]) as &dyn Array
+    );
+    assert_eq!(batches.position().byte(), "a,b\n1,x\n2,y\n".len() as u64);
+
+    let batch = batches.next().unwrap()?;
+    assert_eq!(batch.len(), 1);
+    assert!(batches.next().is_none());
+    Ok(())
+}
+
+#[cfg(feature = "io_csv_read_parallel")]
+#[test]
+fn parallel() -> Result<()> {
+    let mut data = "id,text,value\n".to_string();
+    for i in 0..100 {
+        data.push_str(&format!(
+            "{i},\"line \"\"{i}\"\"\n\nwith, newline\",{}.5\n",
+            i * 2
+        ));
+    }
+
+    let mut reader = ReaderBuilder::new().from_reader(Cursor::new(&data));
+    let (fields, _) = infer_schema(&mut reader, None, true, &infer)?;
+    let start = reader.position().byte() as usize;
+    let mut rows = vec![ByteRecord::default(); 200];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+    let expected = deserialize_batch(&rows[..rows_read], &fields, None, 0, deserialize_column)?;
+
+    for n_chunks in [1, 2, 7, 100, 1000] {
+        let result = read_parallel(
+            &data.as_bytes()[start..],
+            &fields,
+            None,
+            n_chunks,
+            b'"',
+            ReaderBuilder::new,
+        )?;
+        assert_eq!(expected, result);
+    }
+
+    let result = read_parallel(
+        &data.as_bytes()[start..],
+        &fields,
+        Some(&[2, 0]),
+        3,
+        b'"',
+        ReaderBuilder::new,
+    )?;
+    assert_eq!(result.arrays()[0], expected.arrays()[2]);
+    assert_eq!(result.arrays()[1], expected.arrays()[0]);
+
+    let result = read_parallel(&[], &fields, None, 3, b'"', ReaderBuilder::new)?;
+    assert_eq!(result.len(), 0);
+
+    // a custom quote character is used to split and read the records
+    let data = "0,'a\nb',1.5\n1,'c',2.5\n2,'d\ne',3.5\n";
+    let expected = Chunk::new(vec![
+        Int64Array::from_slice([0, 1, 2]).boxed(),
+        Utf8Array::<i32>::from_slice(["a\nb", "c", "d\ne"]).boxed(),
+        Float64Array::from_slice([1.5, 2.5, 3.5]).boxed(),
+    ]);
+    for n_chunks in [1, 2, 3] {
+        let result = read_parallel(
+            data.as_bytes(),
+            &fields,
+            None,
+            n_chunks,
+            b'\'',
+            ReaderBuilder::new,
+        )?;
+        assert_eq!(expected, result);
+    }
+    Ok(())
+}
+
 #[test]
 fn infer_basics() -> Result<()> {
     let file = Cursor::new("1,2,3\na,b,c\na,,c");
@@ -443,3 +443,144 @@ fn serialize_vec() -> Result<()> {
     );
     Ok(())
 }
+
+#[cfg(feature = "io_csv_read")]
+#[test]
+fn write_batches() -> Result<()> {
+    use arrow2::io::csv::read;
+
+    let batches = [
+        (vec![1, 2], vec!["a", "b;c"]),
+        (vec![3], vec!["d \"e\""]),
+        (vec![4, 5, 6], vec!["f", "g\nh", "i"]),
+    ];
+    let options = SerializeOptions {
+        delimiter: b';',
+        quote: b'\'',
+        ..Default::default()
+    };
+    let names = vec!["int".to_string(), "str".to_string()];
+    let mut writer = CsvWriter::new(vec![], names, options);
+    for (ints, strs) in &batches {
+        let chunk = Chunk::new(vec![
+            Int64Array::from_slice(ints).boxed(),
+            Utf8Array::<i32>::from_slice(strs).boxed(),
+        ]);
+        writer.write(&chunk)?;
+    }
+    writer.finish()?;
+    let data = writer.into_inner();
+
+    let mut reader = read::ReaderBuilder::new()
+        .delimiter(b';')
+        .quote(b'\'')
+        .from_reader(Cursor::new(data));
+    let (fields, _) = read::infer_schema(&mut reader, None, true, &read::infer)?;
+    assert_eq!(fields[0].name, "int");
+    assert_eq!(fields[1].name, "str");
+
+    let mut rows = vec![read::ByteRecord::default(); 10];
+    let rows_read = read::read_rows(&mut reader, 0, &mut rows)?;
+    let result = read::deserialize_batch(
+        &rows[..rows_read],
+        &fields,
+        None,
+        0,
+        read::deserialize_column,
+    )?;
+
+    let expected = Chunk::new(vec![
+        Int64Array::from_slice([1, 2, 3, 4, 5, 6]).boxed(),
+        Utf8Array::<i32>::from_slice(["a", "b;c", "d \"e\"", "f", "g\nh", "i"]).boxed(),
+    ]);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn write_batches_header_only() -> Result<()> {
+    let mut writer = CsvWriter::new(vec![], vec!["a".to_string()], Default::default());
+    writer.finish()?;
+    assert_eq!(writer.into_inner(), b"a\n");
+
+    let mut writer =
+        CsvWriter::new(vec![], vec!["a".to_string()], Default::default()).with_header(false);
+    let chunk = Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()]);
+    writer.write(&chunk)?;
+    writer.write(&chunk)?;
+    assert_eq!(writer.into_inner(), b"1\n2\n1\n2\n");
+
+    let mut writer = CsvWriter::new(vec![], vec![], Default::default());
+    assert!(writer.write(&chunk).is_err());
+    Ok(())
+}
+
+#[test]
+fn write_with_schema() -> Result<()> {
+    let chunk = Chunk::new(vec![
+        // days since epoch
+        Int32Array::from([Some(0), Some(19000), None]).boxed(),
+        // seconds since epoch
+        Int64Array::from([Some(0), Some(1_641_000_000), Some(86_399)]).boxed(),
+        Float64Array::from([Some(1.0), Some(2.12345), None]).boxed(),
+    ]);
+    let schema = Schema::from(vec![
+        Field::new("date", DataType::Date32, true),
+        Field::new(
+            "timestamp",
+            DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string())),
+            true,
+        ),
+        Field::new("float", DataType::Float64, true),
+    ]);
+    let options = SerializeOptions {
+        date32_format: Some("%d/%m/%Y".to_string()),
+        timestamp_format: Some("%Y-%m-%dT%H:%M%z".to_string()),
+        float_precision: Some(2),
+        ..Default::default()
+    };
+
+    let mut writer = vec![];
+    write_chunk_with_schema(&mut writer, &chunk, &schema, &options)?;
+    let expected = "01/01/1970,1970-01-01T01:00+0100,1.00\n\
+                    08/01/2022,2022-01-01T02:20+0100,2.12\n\
+                    ,1970-01-02T00:59+0100,\n";
+    assert_eq!(String::from_utf8(writer).unwrap(), expected);
+
+    let mut writer = CsvWriter::from_schema(vec![], &schema, options.clone());
+    writer.write(&chunk)?;
+    let result = String::from_utf8(writer.into_inner()).unwrap();
+    assert_eq!(result, format!("date,timestamp,float\n{expected}"));
+
+    // the physical type of a column must match its declared data type
+    let schema = Schema::from(vec![
+        Field::new("date", DataType::Date64, true),
+        Field::new("timestamp", DataType::Int64, true),
+        Field::new("float", DataType::Float64, true),
+    ]);
+    assert!(write_chunk_with_schema(&mut vec![], &chunk, &schema, &options).is_err());
+    Ok(())
+}
+
+#[test]
+fn write_with_schema_dictionary() -> Result<()> {
+    let mut array = MutableDictionaryArray::<u32, MutableUtf8Array<i32>>::new();
+    array.try_extend([Some("a"), None, Some("a")])?;
+    let array: DictionaryArray<u32> = array.into();
+    let chunk = Chunk::new(vec![array.boxed()]);
+    let options = SerializeOptions::default();
+
+    let dictionary = |values| DataType::Dictionary(IntegerType::UInt32, Box::new(values), false);
+    let schema = Schema::from(vec![Field::new("a", dictionary(DataType::Utf8), true)]);
+    let mut writer = vec![];
+    write_chunk_with_schema(&mut writer, &chunk, &schema, &options)?;
+    assert_eq!(String::from_utf8(writer).unwrap(), "a\n\na\n");
+
+    // the values of a dictionary must match the declared values
+    let schema = Schema::from(vec![Field::new("a", dictionary(DataType::LargeUtf8), true)]);
+    assert!(matches!(
+        write_chunk_with_schema(&mut vec![], &chunk, &schema, &options),
+        Err(arrow2::error::Error::InvalidArgumentError(_))
+    ));
+    Ok(())
+}
@@ -10,7 +10,7 @@ use super::ipc::read_gzip_json;
 
 fn round_trip(schema: Schema, chunk: Chunk<Box<dyn Array>>) -> Result<(), Error> {
     let fields = default_ipc_fields(&schema.fields);
-    let serialized = serialize_schema(&schema, Some(&fields));
+    let serialized = serialize_schema(&schema, Some(&fields))?;
     let (result, ipc_schema) = deserialize_schemas(&serialized.data_header)?;
     assert_eq!(schema, result);
 
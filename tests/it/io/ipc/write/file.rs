@@ -387,3 +387,125 @@ fn write_decimal256() -> Result<()> {
     let columns = Chunk::try_new(vec![array])?;
     round_trip(columns, schema, None, None)
 }
+
+#[test]
+fn write_delta_dictionary() -> Result<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed();
+    let first =
+        DictionaryArray::try_from_keys(PrimitiveArray::from_slice([0i32, 1]), values.sliced(0, 2))?;
+    let second = DictionaryArray::try_from_keys(PrimitiveArray::from_slice([2i32, 0]), values)?;
+    let schema = Schema::from(vec![Field::new("a", first.data_type().clone(), true)]);
+    let batches = vec![
+        Chunk::try_new(vec![first.boxed()])?,
+        Chunk::try_new(vec![second.boxed()])?,
+    ];
+
+    // an appended dictionary is not a replacement, and is thus allowed in files
+    let result = write(&batches, &schema, None, None)?;
+    let mut reader = Cursor::new(result);
+    let metadata = read_file_metadata(&mut reader)?;
+    let reader = FileReader::new(reader, metadata, None, None);
+
+    // dictionaries of files are read before any batch: all batches share the final dictionary
+    let expected = Utf8Array::<i32>::from_slice(["a", "b", "c", "a"]);
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    let result = batches
+        .iter()
+        .flat_map(|chunk| {
+            let array = chunk.arrays()[0]
+                .as_any()
+                .downcast_ref::<DictionaryArray<i32>>()
+                .unwrap();
+            array
+                .iter_typed::<Utf8Array<i32>>()
+                .unwrap()
+                .collect::<Vec<_>>()
+        })
+        .collect::<Utf8Array<i32>>();
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+fn assert_aligned(compression: Option<Compression>) -> Result<()> {
+    use arrow_format::ipc::planus::ReadAsRoot;
+
+    let (schema, chunk) = arrow2::chunk![
+        "a" => Int8Array::from([Some(1), None, Some(3)]),
+        "b" => Utf8Array::<i32>::from([Some("aaa"), Some("b"), None]),
+        "c" => BooleanArray::from([Some(true), None, Some(false)]),
+    ];
+    let chunk = chunk.into_arrays();
+    let batches = vec![Chunk::new(chunk.clone()), Chunk::new(chunk)];
+    let result = write(&batches, &schema, None, compression)?;
+
+    let metadata = read_file_metadata(&mut Cursor::new(&result))?;
+    assert_eq!(metadata.blocks.len(), 2);
+    for block in metadata.blocks {
+        assert_eq!(block.offset % 8, 0);
+        let start = block.offset as usize;
+        // skip the continuation marker and the length of the message
+        let length = i32::from_le_bytes(result[start + 4..start + 8].try_into().unwrap());
+        let message =
+            arrow_format::ipc::MessageRef::read_as_root(&result[start + 8..][..length as usize])
+                .unwrap();
+        let batch = match message.header().unwrap().unwrap() {
+            arrow_format::ipc::MessageHeaderRef::RecordBatch(batch) => batch,
+            _ => panic!("expected a record batch"),
+        };
+        let body = start + block.meta_data_length as usize;
+        assert_eq!(body % 8, 0);
+        for buffer in batch.buffers().unwrap().unwrap() {
+            assert_eq!(buffer.offset() % 64, 0);
+            assert_eq!((body + buffer.offset() as usize) % 8, 0);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn write_aligned_buffers() -> Result<()> {
+    assert_aligned(None)
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_aligned_compressed_buffers() -> Result<()> {
+    assert_aligned(Some(Compression::LZ4))
+}
+
+#[test]
+fn chunk_ipc_bytes_round_trip() -> Result<()> {
+    use arrow2::io::ipc::read::chunk_from_ipc_bytes;
+
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i64>>::new();
+    list.try_extend(vec![Some(vec![Some(1), None]), None, Some(vec![])])?;
+    let list: ListArray<i32> = list.into();
+    let dictionary = DictionaryArray::try_from_keys(
+        Int8Array::from([Some(1), None, Some(0)]),
+        Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+    )?;
+    let columns = vec![
+        Int32Array::from([Some(1), None, Some(3)]).boxed(),
+        Utf8Array::<i64>::from([Some("x"), Some(""), None]).boxed(),
+        BooleanArray::from([Some(true), Some(false), None]).boxed(),
+        dictionary.boxed(),
+        list.boxed(),
+    ];
+    let schema = Schema::from(
+        columns
+            .iter()
+            .enumerate()
+            .map(|(i, array)| Field::new(format!("c{i}"), array.data_type().clone(), true))
+            .collect::<Vec<_>>(),
+    );
+    let chunk = Chunk::try_new(columns)?;
+
+    let bytes = chunk_to_ipc_bytes(&chunk, &schema)?;
+    let (result_schema, chunks) = chunk_from_ipc_bytes(&bytes)?;
+
+    assert_eq!(result_schema, schema);
+    assert_eq!(chunks, vec![chunk]);
+
+    assert!(chunk_from_ipc_bytes(&bytes[..bytes.len() - 1]).is_err());
+    Ok(())
+}
@@ -1,8 +1,8 @@
 use std::io::Cursor;
 
-use arrow2::array::Array;
+use arrow2::array::{Array, DictionaryArray, PrimitiveArray, Utf8Array};
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::Schema;
+use arrow2::datatypes::{Field, Schema};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::read_stream_metadata;
 use arrow2::io::ipc::read::StreamReader;
@@ -146,3 +146,40 @@ fn write_100_custom_metadata() {
 fn write_100_decimal() {
     test_file("1.0.0-littleendian", "generated_decimal");
 }
+
+fn dictionary_chunk(keys: &[i32], values: &[&str]) -> Chunk<Box<dyn Array>> {
+    let keys = PrimitiveArray::from_slice(keys);
+    let values = Utf8Array::<i32>::from_slice(values).boxed();
+    Chunk::new(vec![DictionaryArray::try_from_keys(keys, values)
+        .unwrap()
+        .boxed()])
+}
+
+#[test]
+fn write_delta_dictionary() {
+    let batches = vec![
+        dictionary_chunk(&[0, 1], &["a", "b"]),
+        // appended values are written as a delta dictionary
+        dictionary_chunk(&[2, 0], &["a", "b", "c"]),
+        dictionary_chunk(&[3, 3, 1], &["a", "b", "c", "d"]),
+        // other values replace the dictionary
+        dictionary_chunk(&[0], &["e"]),
+    ];
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        batches[0].arrays()[0].data_type().clone(),
+        true,
+    )]);
+
+    let result = write_(&schema, None, &batches);
+
+    let mut reader = Cursor::new(result);
+    let metadata = read_stream_metadata(&mut reader).unwrap();
+    let reader = StreamReader::new(reader, metadata, None);
+
+    let result = reader
+        .map(|x| x.map(|x| x.unwrap()))
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(result, batches);
+}
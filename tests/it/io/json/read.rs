@@ -193,6 +193,111 @@ fn read_json_records_with_schema() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn infer_max_depth() -> Result<()> {
+    let data = br#"[
+        {"a": {"b": {"c": [1, 2.5e3, "x\"y"]}}, "d": [[1]]},
+        {"a": {"b": null}, "d": [[2, 3]]}
+    ]"#;
+    let json = json_deserializer::parse(data)?;
+
+    // the outer array, the records, and their values
+    let options = read::InferOptions {
+        max_depth: Some(3),
+        ..Default::default()
+    };
+    let data_type = read::infer_with_options(&json, &options)?;
+    let a = DataType::Struct(vec![Field::new("b", DataType::Utf8, true)]);
+    let d = DataType::List(Box::new(Field::new("item", DataType::Utf8, true)));
+    let record = DataType::Struct(vec![Field::new("a", a, true), Field::new("d", d, true)]);
+    assert_eq!(
+        data_type,
+        DataType::List(Box::new(Field::new("item", record, true)))
+    );
+
+    let records = read::deserialize(&json, data_type)?;
+    let records = records.as_any().downcast_ref::<StructArray>().unwrap();
+    let a = records.values()[0]
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    assert_eq!(
+        a.values()[0].as_ref(),
+        &Utf8Array::<i32>::from([Some(r#"{"c":[1,2.5e3,"x\"y"]}"#), None]) as &dyn Array
+    );
+    let d = records.values()[1]
+        .as_any()
+        .downcast_ref::<ListArray<i32>>()
+        .unwrap();
+    assert_eq!(
+        d.values().as_ref(),
+        &Utf8Array::<i32>::from_slice(["[1]", "[2,3]"]) as &dyn Array
+    );
+
+    // the values of records count from the records
+    let options = read::InferOptions {
+        max_depth: Some(1),
+        ..Default::default()
+    };
+    let schema = read::infer_records_schema_with_options(&json, &options)?;
+    let a = DataType::Struct(vec![Field::new("b", DataType::Utf8, true)]);
+    let d = DataType::List(Box::new(Field::new("item", DataType::Utf8, true)));
+    assert_eq!(
+        schema,
+        Schema::from(vec![Field::new("a", a, true), Field::new("d", d, true)])
+    );
+
+    let options = read::InferOptions {
+        max_depth: Some(0),
+        ..Default::default()
+    };
+    assert_eq!(read::infer_with_options(&json, &options)?, DataType::Utf8);
+    Ok(())
+}
+
+#[test]
+fn infer_max_records() -> Result<()> {
+    let data = br#"[{"a": 1}, {"a": 2, "b": "x"}, {"a": 1.5, "c": true}]"#;
+    let json = json_deserializer::parse(data)?;
+
+    let options = read::InferOptions {
+        max_records: Some(2),
+        ..Default::default()
+    };
+    let schema = read::infer_records_schema_with_options(&json, &options)?;
+    assert_eq!(
+        schema,
+        Schema::from(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ])
+    );
+
+    let schema = read::infer_records_schema_with_options(&json, &Default::default())?;
+    assert_eq!(
+        schema,
+        Schema::from(vec![
+            Field::new("a", DataType::Float64, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Boolean, true),
+        ])
+    );
+
+    let data_type = read::infer_with_options(&json, &options)?;
+    assert_eq!(
+        data_type,
+        DataType::List(Box::new(Field::new(
+            "item",
+            DataType::Struct(vec![
+                Field::new("a", DataType::Int64, true),
+                Field::new("b", DataType::Utf8, true),
+            ]),
+            true
+        )))
+    );
+    Ok(())
+}
+
 #[test]
 fn deserialize_timestamp_string_ns() -> Result<()> {
     let data = br#"["2023-04-07T12:23:34.000000001Z"]"#;
@@ -1701,6 +1701,87 @@ fn arrow_type() -> Result<()> {
     assert_roundtrip(schema, chunk, None)
 }
 
+/// Tests that the arrow schema is written to the `ARROW:schema` key and preferred over the
+/// parquet schema when read, so that timezones survive a roundtrip.
+#[test]
+fn arrow_schema_metadata() -> Result<()> {
+    let array = PrimitiveArray::<i64>::from([Some(1), None, Some(3)])
+        .to(DataType::Timestamp(
+            TimeUnit::Microsecond,
+            Some("UTC".to_string()),
+        ))
+        .boxed();
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let chunk = Chunk::new(vec![array]);
+
+    let data = integration_write(&schema, &[chunk.clone()])?;
+
+    let metadata = p_read::read_metadata(&mut Cursor::new(&data))?;
+    assert!(metadata
+        .key_value_metadata()
+        .as_ref()
+        .unwrap()
+        .iter()
+        .any(|x| x.key == "ARROW:schema"));
+
+    let (new_schema, new_chunks) = integration_read(&data, None)?;
+    assert_eq!(new_schema, schema);
+    assert_eq!(new_chunks, vec![chunk]);
+    Ok(())
+}
+
+#[test]
+fn read_from_byte_ranges() -> Result<()> {
+    let a = Int64Array::from([Some(1), None, Some(3)]).boxed();
+    let b = StructArray::new(
+        DataType::Struct(vec![
+            Field::new("c", DataType::Utf8, true),
+            Field::new("d", DataType::Int32, true),
+        ]),
+        vec![
+            Utf8Array::<i32>::from([Some("a"), Some("b"), None]).boxed(),
+            Int32Array::from([Some(1), Some(2), None]).boxed(),
+        ],
+        None,
+    )
+    .boxed();
+    let schema = Schema::from(vec![
+        Field::new("a", a.data_type().clone(), true),
+        Field::new("b", b.data_type().clone(), true),
+    ]);
+    let chunk = Chunk::new(vec![a, b]);
+    let data = integration_write(&schema, &[chunk.clone(), chunk.clone()])?;
+
+    let metadata = p_read::read_metadata(&mut Cursor::new(&data))?;
+    assert_eq!(metadata.row_groups.len(), 2);
+
+    for row_group in &metadata.row_groups {
+        let ranges = p_read::get_field_byte_ranges(row_group.columns(), "b");
+        assert_eq!(ranges.len(), 2);
+
+        // fetch the ranges as e.g. an object store would
+        let columns = p_read::get_field_columns(row_group.columns(), "b")
+            .into_iter()
+            .zip(ranges)
+            .map(|(meta, (start, length))| {
+                let start = start as usize;
+                (meta, data[start..start + length as usize].to_vec())
+            })
+            .collect();
+
+        let mut arrays = p_read::to_deserializer(
+            columns,
+            schema.fields[1].clone(),
+            row_group.num_rows(),
+            None,
+            None,
+        )?;
+        assert_eq!(arrays.next().unwrap()?.as_ref(), chunk.arrays()[1].as_ref());
+        assert!(arrays.next().is_none());
+    }
+    Ok(())
+}
+
 fn data<T: NativeType, I: Iterator<Item = T>>(
     mut iter: I,
     inner_is_nullable: bool,
@@ -1910,6 +1991,63 @@ fn list_int_nullable() -> Result<()> {
     assert_array_roundtrip(true, array.into_box(), None)
 }
 
+#[test]
+fn list_int_and_struct() -> Result<()> {
+    let data = vec![
+        Some(vec![Some(1), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(2), Some(3), Some(4)]),
+    ];
+    let mut list = MutableListArray::<i32, _>::new_with_field(
+        MutablePrimitiveArray::<i64>::new(),
+        "item",
+        true,
+    );
+    list.try_extend(data).unwrap();
+    let list = list.into_box();
+
+    let a = Int32Array::from_slice([1, 2, 3, 4]).boxed();
+    let b = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("d")]).boxed();
+    let a_field = Field::new("a", a.data_type().clone(), true);
+    let b_field = Field::new("b", b.data_type().clone(), true);
+    let struct_ = StructArray::new(
+        DataType::Struct(vec![a_field, b_field]),
+        vec![a, b],
+        Some([true, true, false, true].into()),
+    )
+    .boxed();
+
+    let schema = Schema::from(vec![
+        Field::new("list", list.data_type().clone(), true),
+        Field::new("struct", struct_.data_type().clone(), true),
+    ]);
+    let chunk = Chunk::try_new(vec![list, struct_])?;
+    assert_roundtrip(schema, chunk, None)
+}
+
+#[test]
+fn list_struct_null_with_valid_values() -> Result<()> {
+    let a = Int32Array::from_slice([1, 2, 3, 4]).boxed();
+    let struct_ = StructArray::new(
+        DataType::Struct(vec![Field::new("a", a.data_type().clone(), true)]),
+        vec![a],
+        Some([true, false, true, true].into()),
+    )
+    .boxed();
+    let list = ListArray::<i32>::new(
+        DataType::List(Box::new(Field::new(
+            "item",
+            struct_.data_type().clone(),
+            true,
+        ))),
+        vec![0, 2, 4].try_into().unwrap(),
+        struct_,
+        None,
+    );
+    assert_array_roundtrip(true, list.boxed(), None)
+}
+
 #[test]
 fn limit() -> Result<()> {
     let (schema, chunk) = generic_data()?;
@@ -3,7 +3,7 @@ use arrow2::{
     bitmap::Bitmap,
     buffer::Buffer,
     chunk::Chunk,
-    datatypes::{DataType, Field, TimeUnit, UnionMode},
+    datatypes::{DataType, Field, Schema, TimeUnit, UnionMode},
     error::Result,
     io::print::*,
 };
@@ -379,3 +379,74 @@ fn write_union() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn pretty_format_basics() -> Result<()> {
+    let a = Int32Array::from(vec![Some(1), None, Some(100)]);
+    let b = Utf8Array::<i32>::from(vec![
+        Some("a"),
+        None,
+        Some("a very long string that does not fit in a cell"),
+    ]);
+    let c = BooleanArray::from(vec![Some(true), Some(false), None]);
+    let chunk = Chunk::try_new(vec![a.boxed(), b.boxed(), c.boxed()])?;
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Boolean, true),
+    ]);
+
+    let table = pretty_format(&chunk, &schema);
+
+    let expected = vec![
+        "+------+----------------------------------+-------+",
+        "| a    | b                                | c     |",
+        "+------+----------------------------------+-------+",
+        "| 1    | a                                | true  |",
+        "| null | null                             | false |",
+        "| 100  | a very long string that does no… | null  |",
+        "+------+----------------------------------+-------+",
+    ];
+
+    let actual: Vec<&str> = table.lines().collect();
+
+    assert_eq!(expected, actual, "Actual result:\n{table}");
+
+    Ok(())
+}
+
+#[test]
+fn pretty_format_max_rows() -> Result<()> {
+    let a = Int32Array::from_vec((0..MAX_ROWS as i32 + 5).collect());
+    let b = ListArray::<i32>::new(
+        ListArray::<i32>::default_datatype(DataType::Int32),
+        vec![0, 2].try_into().unwrap(),
+        Int32Array::from_slice([1, 2]).boxed(),
+        None,
+    );
+    let chunk = Chunk::try_new(vec![b.clone().boxed()])?;
+    let schema = Schema::from(vec![Field::new("b", b.data_type().clone(), true)]);
+    let table = pretty_format(&chunk, &schema);
+    assert_eq!(
+        table.lines().collect::<Vec<_>>(),
+        vec![
+            "+--------+",
+            "| b      |",
+            "+--------+",
+            "| [1, 2] |",
+            "+--------+"
+        ],
+    );
+
+    let chunk = Chunk::try_new(vec![a.boxed()])?;
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    let table = pretty_format(&chunk, &schema);
+    let lines = table.lines().collect::<Vec<_>>();
+
+    // header (3 lines) + rows + ellipsis row + footer
+    assert_eq!(lines.len(), 3 + MAX_ROWS + 2);
+    assert_eq!(lines[3 + MAX_ROWS - 1], "| 19 |");
+    assert_eq!(lines[3 + MAX_ROWS], "| …  |");
+
+    Ok(())
+}
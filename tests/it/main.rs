@@ -7,7 +7,10 @@ mod arrow;
 
 mod bitmap;
 mod buffer;
+mod chunk;
+mod datatypes;
 mod ffi;
+mod offsets;
 mod scalar;
 mod temporal_conversions;
 mod types;
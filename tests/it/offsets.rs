@@ -0,0 +1,52 @@
+use arrow2::error::Error;
+use arrow2::offset::{Offsets, OffsetsBuffer};
+
+#[test]
+fn try_push_usize_overflow() {
+    let mut offsets = Offsets::<i32>::new();
+    offsets.try_push_usize(i32::MAX as usize).unwrap();
+    assert!(matches!(offsets.try_push_usize(1), Err(Error::Overflow)));
+    assert_eq!(offsets.as_slice(), &[0, i32::MAX]);
+
+    let mut offsets = Offsets::<i64>::new();
+    offsets.try_push_usize(i32::MAX as usize).unwrap();
+    offsets.try_push_usize(1).unwrap();
+    assert_eq!(
+        offsets.as_slice(),
+        &[0, i32::MAX as i64, i32::MAX as i64 + 1]
+    );
+}
+
+#[test]
+fn try_extend_from_lengths_overflow() {
+    let mut offsets = Offsets::<i32>::new();
+    offsets.try_push_usize(1).unwrap();
+
+    let lengths = [i32::MAX as usize - 2, 1, 1];
+    let result = offsets.try_extend_from_lengths(lengths.into_iter());
+    assert!(matches!(result, Err(Error::Overflow)));
+    // no overflown offsets are kept
+    assert_eq!(offsets.as_slice(), &[0, 1]);
+
+    let result = Offsets::<i32>::try_from_lengths([usize::MAX, 1].into_iter());
+    assert!(matches!(result, Err(Error::Overflow)));
+
+    let offsets = Offsets::<i64>::try_from_lengths(lengths.into_iter()).unwrap();
+    assert_eq!(offsets.last(), &(i32::MAX as i64));
+}
+
+#[test]
+fn try_extend_from_slice_sliced() {
+    let other: OffsetsBuffer<i32> = vec![0, i32::MAX - 5, i32::MAX - 2].try_into().unwrap();
+
+    let mut offsets = Offsets::<i32>::new();
+    offsets.try_push_usize(i32::MAX as usize - 10).unwrap();
+    // only the 3 bytes of the second slot are added
+    offsets.try_extend_from_slice(&other, 1, 1).unwrap();
+    assert_eq!(offsets.as_slice(), &[0, i32::MAX - 10, i32::MAX - 7]);
+
+    assert!(matches!(
+        offsets.try_extend_from_slice(&other, 0, 1),
+        Err(Error::Overflow)
+    ));
+}
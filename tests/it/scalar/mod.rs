@@ -9,8 +9,94 @@ mod primitive;
 mod struct_;
 mod utf8;
 
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::scalar::{
+    get, new_scalar, to_array, BooleanScalar, NullScalar, PrimitiveScalar, Scalar, Utf8Scalar,
+};
+
 // check that `PartialEq` can be derived
 #[derive(PartialEq)]
 struct A {
     array: Box<dyn arrow2::scalar::Scalar>,
 }
+
+fn round_trip(array: &dyn Array) {
+    for i in 0..array.len() {
+        let scalar = new_scalar(array, i);
+        let result = to_array(scalar.as_ref(), 1).unwrap();
+        assert_eq!(result.as_ref(), array.sliced(i, 1).as_ref());
+        assert_eq!(new_scalar(result.as_ref(), 0), scalar);
+    }
+}
+
+#[test]
+fn round_trip_array_scalar_array() {
+    round_trip(&NullArray::new(DataType::Null, 2));
+    round_trip(&BooleanArray::from([Some(true), None, Some(false)]));
+    round_trip(&Int32Array::from([Some(1), None, Some(3)]));
+    round_trip(&Int64Array::from([Some(1), None]).to(DataType::Date64));
+    round_trip(&Float64Array::from([Some(1.5), None]));
+    round_trip(&Utf8Array::<i32>::from([Some("a"), None, Some("")]));
+    round_trip(&Utf8Array::<i64>::from([Some("a"), None]));
+    round_trip(&BinaryArray::<i32>::from([Some(b"a".as_ref()), None]));
+    round_trip(&BinaryArray::<i64>::from([Some(b"a".as_ref()), None]));
+}
+
+#[test]
+fn to_array_repeats() {
+    let scalar = new_scalar(&Int32Array::from_slice([7]), 0);
+    let array = to_array(scalar.as_ref(), 3).unwrap();
+    assert_eq!(
+        array.as_ref(),
+        &Int32Array::from_slice([7, 7, 7]) as &dyn Array
+    );
+
+    let scalar = new_scalar(&Utf8Array::<i32>::from([None::<&str>]), 0);
+    let array = to_array(scalar.as_ref(), 2).unwrap();
+    assert_eq!(array.null_count(), 2);
+}
+
+#[test]
+fn to_array_unsupported() {
+    let data_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+    let array = new_null_array(data_type, 1);
+    let scalar: Box<dyn Scalar> = new_scalar(array.as_ref(), 0);
+    assert!(to_array(scalar.as_ref(), 1).is_err());
+}
+
+#[test]
+fn get_scalar() {
+    let array = Int32Array::from([Some(1), None]);
+    assert_eq!(
+        get(&array, 0).as_ref(),
+        &PrimitiveScalar::from(Some(1i32)) as &dyn Scalar
+    );
+    let scalar = get(&array, 1);
+    assert!(!scalar.is_valid());
+    assert_eq!(scalar.data_type(), &DataType::Int32);
+
+    let array = Utf8Array::<i32>::from([Some("a"), None]);
+    assert_eq!(
+        get(&array, 0).as_ref(),
+        &Utf8Scalar::<i32>::from(Some("a")) as &dyn Scalar
+    );
+    assert!(!get(&array, 1).is_valid());
+
+    let array = BooleanArray::from([Some(false), None]);
+    assert_eq!(
+        get(&array, 0).as_ref(),
+        &BooleanScalar::from(Some(false)) as &dyn Scalar
+    );
+    assert!(!get(&array, 1).is_valid());
+
+    let array = NullArray::new(DataType::Null, 1);
+    assert_eq!(get(&array, 0).as_ref(), &NullScalar::new() as &dyn Scalar);
+}
+
+#[test]
+#[should_panic(expected = "index 2 is out of bounds for an array of length 2")]
+fn get_scalar_out_of_bounds() {
+    let array = Int32Array::from([Some(1), None]);
+    get(&array, 2);
+}